@@ -1,7 +1,7 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use alloy::primitives::Address;
-use hyperliquid_sdk_rs::providers::InfoProvider;
+use hyperliquid_sdk_rs::{providers::InfoProvider, types::CandleInterval};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -82,7 +82,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match info
         .candles("SOL")
-        .interval("15m")
+        .interval(CandleInterval::FifteenMinutes)
         .time_range(one_hour_ago, now)
         .send()
         .await
@@ -61,7 +61,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\nOrderBuilder created successfully!");
 
     // Example 3: Create bulk orders with mixed tracking
-    let orders_with_ids = vec![
+    let orders_with_ids = [
         (
             OrderRequest::limit(0, true, "44900.0", "0.01", TIF_GTC),
             Some(Uuid::new_v4()),
@@ -100,17 +100,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         vault_address
     );
 
-    let agent_address: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f8fA49".parse()?;
+    // An agent (API wallet) signs with its own key and trades for its master account
+    let master_address: Address = "0x742d35Cc6634C0532925a3b844Bc9e7595f8fA49".parse()?;
     let agent_signer = private_key.parse::<PrivateKeySigner>()?;
-    let _agent_exchange = ExchangeProvider::testnet_agent(
-        AlloySigner {
-            inner: agent_signer,
-        },
-        agent_address,
-    );
+    let _agent_exchange = ExchangeProvider::testnet(AlloySigner {
+        inner: agent_signer,
+    })
+    .with_account_address(master_address);
     println!(
-        "Agent ExchangeProvider created for address: {}",
-        agent_address
+        "Agent ExchangeProvider created for account: {}",
+        master_address
     );
 
     // ==================== Phase 1 Account Management ====================
@@ -160,7 +159,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("      60,       // duration in minutes");
     println!("      true      // randomize execution timing");
     println!("  ).await");
-    println!("");
+    println!();
     println!("  // Cancel a TWAP order");
     println!("  exchange.twap_cancel(0, twap_id).await");
 
@@ -169,7 +168,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  let signer1: Address = \"0x...\".parse()?;");
     println!("  let signer2: Address = \"0x...\".parse()?;");
     println!("  let signer3: Address = \"0x...\".parse()?;");
-    println!("");
+    println!();
     println!("  // Convert to multi-sig requiring 2 of 3 signers");
     println!("  exchange.convert_to_multi_sig_user(");
     println!("      vec![(signer1, 1), (signer2, 1), (signer3, 1)],");
@@ -182,7 +181,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  exchange.multi_sig(");
     println!("      multi_sig_user,");
     println!("      inner_action_json,");
-    println!("      signatures  // Vec<(r, s, v)>");
+    println!("      signatures,  // Vec<(r, s, v)>");
+    println!("      nonce        // nonce the signatures were made with");
     println!("  ).await");
 
     // Agent DEX abstraction example
@@ -318,7 +318,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("      \"1000000000000000000\",  // wei");
     println!("      false                      // is_undelegate = false means delegate");
     println!("  ).await");
-    println!("");
+    println!();
     println!("  // Undelegate tokens from validator");
     println!("  exchange.token_delegate(");
     println!("      validator,");
@@ -169,27 +169,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("\nExample workflow for a USD transfer:");
     println!("```");
-    println!("// 1. Create the inner action (as JSON)");
-    println!("let inner_action = serde_json::json!({{");
-    println!("    \"type\": \"usdSend\",");
-    println!("    \"destination\": \"0x...\",");
-    println!("    \"amount\": \"1000.0\",");
-    println!("    \"time\": current_timestamp");
-    println!("}});");
-    println!("");
-    println!("// 2. Each signer signs the action hash");
-    println!("// (This happens off-chain, typically via a UI or script)");
-    println!("let signatures = vec![");
-    println!("    (r1, s1, v1),  // Signer 1's signature");
-    println!("    (r2, s2, v2),  // Signer 2's signature");
-    println!("];");
-    println!("");
-    println!("// 3. One signer submits the multi-sig transaction");
-    println!("exchange.multi_sig(");
-    println!("    multi_sig_address,");
-    println!("    inner_action,");
-    println!("    signatures,");
-    println!(").await");
+    println!("// 1. The submitting signer creates a session for the inner action");
+    println!("let action = UsdSend {{ time: exchange.next_nonce(), ... }};");
+    println!("let session = exchange");
+    println!("    .multi_sig_user_session(multi_sig_address, &action)");
+    println!("    .await?;");
+    println!("session.save(\"session.json\")?;");
+    println!();
+    println!("// 2. Each co-signer loads the file and signs on their own machine");
+    println!("let mut session = MultiSigSession::load(\"session.json\")?;");
+    println!("session.sign(&co_signer).await?;");
+    println!("session.save(\"session.json\")?;");
+    println!();
+    println!("// 3. The submitting signer sends it once the threshold is met");
+    println!("let session = MultiSigSession::load(\"session.json\")?;");
+    println!("assert!(session.is_ready());");
+    println!("exchange.submit_multi_sig(&session).await");
     println!("```");
 
     // ==================== Part 6: Multi-Sig Security Best Practices ====================
@@ -221,7 +216,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("\n=== Multi-Sig Example Complete ===\n");
     println!("Key APIs demonstrated:");
-    println!("  Exchange: convert_to_multi_sig_user(), multi_sig()");
+    println!("  Exchange: convert_to_multi_sig_user(), multi_sig_session(), submit_multi_sig()");
     println!("  Info: user_to_multi_sig_signers(), user_role()");
 
     Ok(())
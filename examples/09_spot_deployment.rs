@@ -185,25 +185,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n--- Part 4: Token Economics Planning ---\n");
 
     println!("Before deploying, plan your tokenomics:");
-    println!("");
+    println!();
     println!("1. TOTAL SUPPLY");
     println!("   - Fixed supply vs inflationary");
     println!("   - Distribution schedule");
-    println!("");
+    println!();
     println!("2. INITIAL DISTRIBUTION");
     println!("   - Team allocation (consider vesting)");
     println!("   - Community allocation");
     println!("   - Liquidity pool allocation");
-    println!("");
+    println!();
     println!("3. DECIMALS");
     println!("   - wei_decimals: On-chain precision (usually 18)");
     println!("   - sz_decimals: Trading UI precision (4-8 typical)");
-    println!("");
+    println!();
     println!("4. LIQUIDITY");
     println!("   - Hyperliquidity parameters");
     println!("   - Initial price discovery");
     println!("   - Order depth");
-    println!("");
+    println!();
     println!("5. FREEZE CONTROLS");
     println!("   - Compliance requirements");
     println!("   - Emergency controls");
@@ -220,14 +220,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  [ ] Initial distribution amounts calculated");
     println!("  [ ] Hyperliquidity parameters planned");
     println!("  [ ] Max supply determined");
-    println!("");
+    println!();
     println!("Deployment steps:");
     println!("  [ ] 1. Register token");
     println!("  [ ] 2. User genesis for all initial holders");
     println!("  [ ] 3. Genesis to finalize");
     println!("  [ ] 4. Register spot pair");
     println!("  [ ] 5. Register hyperliquidity");
-    println!("");
+    println!();
     println!("Post-deployment:");
     println!("  [ ] Verify token appears in spot metadata");
     println!("  [ ] Test trading with small amounts");
@@ -238,23 +238,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("\n=== Spot Deployment Example Complete ===\n");
     println!("Key APIs for spot deployment:");
-    println!("");
+    println!();
     println!("Registration:");
     println!("  - spot_deploy_register_token(name, sz_dec, wei_dec, gas, full_name)");
     println!("  - spot_deploy_user_genesis(token, [(user, wei)], existing)");
     println!("  - spot_deploy_genesis(token, max_supply, no_hyperliquidity)");
-    println!("");
+    println!();
     println!("Trading Setup:");
     println!("  - spot_deploy_register_spot(base, quote)");
     println!("  - spot_deploy_register_hyperliquidity(spot, px, sz, n, levels)");
-    println!("");
+    println!();
     println!("Token Controls:");
     println!("  - spot_deploy_enable_freeze_privilege(token)");
     println!("  - spot_deploy_freeze_user(token, user, freeze)");
     println!("  - spot_deploy_revoke_freeze_privilege(token)");
     println!("  - spot_deploy_set_deployer_trading_fee_share(token, share)");
     println!("  - spot_deploy_enable_quote_token(token)");
-    println!("");
+    println!();
     println!("Query APIs:");
     println!("  - spot_deploy_state(user)");
     println!("  - spot_pair_deploy_auction_status(base, quote)");
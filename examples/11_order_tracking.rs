@@ -2,7 +2,7 @@
 //!
 //! The order tracking system provides:
 //! - Automatic client order ID (CLOID) generation
-//! - Order state tracking (pending, submitted, failed)
+//! - Order state tracking from submission through fills and cancels
 //! - Order lookup by CLOID
 //! - Status queries for monitoring
 //!
@@ -133,7 +133,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n--- Part 4: Querying Tracked Orders ---\n");
 
     println!("Available query methods:");
-    println!("");
+    println!();
 
     println!("1. Get a specific order by CLOID:");
     println!("   let order = exchange.get_tracked_order(&cloid);");
@@ -165,27 +165,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n--- Part 5: Order Status Lifecycle ---\n");
 
     println!("Orders go through these states:");
-    println!("");
+    println!();
     println!("  Pending    -> Order created, not yet sent to exchange");
     println!("      |");
     println!("      v");
-    println!("  Submitted  -> Order sent and accepted by exchange");
+    println!("  Submitted  -> Order sent, outcome not known yet");
     println!("      |");
     println!("      v");
-    println!("  (Exchange manages the rest: open, filled, cancelled)");
-    println!("");
+    println!("  Resting -> PartiallyFilled -> Filled");
+    println!("      |");
+    println!("      +-> Canceled(status) / Rejected(reason) / Triggered");
+    println!();
     println!("  OR");
-    println!("");
+    println!();
     println!("  Pending");
     println!("      |");
     println!("      v");
     println!("  Failed(reason) -> Order submission failed");
 
-    println!("\nThe tracking system captures the submission phase.");
-    println!("For order fill status, use the Info API:");
-    println!("  info.order_status(user, oid).await");
-    println!("  info.open_orders(user).await");
-    println!("  info.user_fills(user).await");
+    println!("\nThe exchange response sets the first status after submission.");
+    println!("Following the WebSocket keeps orders moving after that:");
+    println!("  let handle = exchange.follow_order_updates(&ws).await?;");
+    println!("  let done = exchange.wait_for_order(&cloid).await;");
+    println!(
+        "  let mut transitions = exchange.order_tracker().unwrap().transitions(cloid);"
+    );
 
     // ==================== Part 6: Practical Patterns ====================
 
@@ -216,13 +220,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("```");
     println!("let cloid = Uuid::new_v4();");
     println!("exchange.place_order_with_cloid(order, cloid).await?;");
-    println!("");
+    println!();
     println!("// Later, check status");
     println!("if let Some(tracked) = exchange.get_tracked_order(&cloid) {{");
     println!("    match &tracked.status {{");
-    println!("        OrderStatus::Submitted => println!(\"Order confirmed!\"),");
+    println!("        OrderStatus::Resting => println!(\"Order on the book\"),");
+    println!(
+        "        OrderStatus::Filled => println!(\"Filled at {{:?}}\", tracked.avg_px),"
+    );
     println!("        OrderStatus::Failed(e) => println!(\"Order failed: {{}}\", e),");
-    println!("        OrderStatus::Pending => println!(\"Still pending...\"),");
+    println!("        other => println!(\"Status: {{:?}}\", other),");
     println!("    }}");
     println!("}}");
     println!("```");
@@ -248,11 +255,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Key APIs:");
     println!("  Setup:");
     println!("    - ExchangeProvider::testnet(signer).with_order_tracking()");
-    println!("");
+    println!();
     println!("  Submission:");
     println!("    - place_order(&order) - auto-generates CLOID if tracking enabled");
     println!("    - place_order_with_cloid(order, cloid) - use specific CLOID");
-    println!("");
+    println!();
     println!("  Queries:");
     println!("    - get_tracked_order(&cloid) -> Option<TrackedOrder>");
     println!("    - get_all_tracked_orders() -> Vec<TrackedOrder>");
@@ -260,7 +267,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("    - get_submitted_orders() -> Vec<TrackedOrder>");
     println!("    - get_failed_orders() -> Vec<TrackedOrder>");
     println!("    - tracked_order_count() -> usize");
-    println!("");
+    println!();
     println!("  Cleanup:");
     println!("    - clear_tracked_orders()");
 
@@ -1,23 +1,98 @@
 // ==================== Network Configuration ====================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Network to connect to.
+///
+/// `Mainnet` and `Testnet` point at the public Hyperliquid API. `Custom` lets
+/// every provider target any other endpoint (a non-validating node, an
+/// internal proxy, a local mock server) while signing for an explicit chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Network {
     Mainnet,
     Testnet,
+    Custom {
+        /// Base HTTP URL, e.g. `http://127.0.0.1:3001` (without `/info` or `/exchange`)
+        api_url: String,
+        /// WebSocket URL, e.g. `ws://127.0.0.1:3001/ws`
+        ws_url: String,
+        /// Chain ID used in the `signatureChainId` of user-signed actions
+        chain_id: u64,
+        /// Agent source used when signing L1 actions (`"a"` mainnet, `"b"` testnet)
+        agent_source: String,
+    },
 }
 
 impl Network {
-    pub fn api_url(&self) -> &'static str {
+    /// Create a custom network that signs exactly like `base`.
+    ///
+    /// Useful for pointing at a proxy or local node in front of mainnet or testnet.
+    pub fn custom(
+        api_url: impl Into<String>,
+        ws_url: impl Into<String>,
+        base: &Network,
+    ) -> Self {
+        Network::Custom {
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            ws_url: ws_url.into(),
+            chain_id: base.chain_id(),
+            agent_source: base.agent_source().to_string(),
+        }
+    }
+
+    pub fn api_url(&self) -> &str {
         match self {
             Network::Mainnet => "https://api.hyperliquid.xyz",
             Network::Testnet => "https://api.hyperliquid-testnet.xyz",
+            Network::Custom { api_url, .. } => api_url,
         }
     }
 
-    pub fn ws_url(&self) -> &'static str {
+    pub fn ws_url(&self) -> &str {
         match self {
             Network::Mainnet => "wss://api.hyperliquid.xyz/ws",
             Network::Testnet => "wss://api.hyperliquid-testnet.xyz/ws",
+            Network::Custom { ws_url, .. } => ws_url,
+        }
+    }
+
+    /// Full URL of the `/info` endpoint.
+    pub fn info_url(&self) -> String {
+        format!("{}/info", self.api_url())
+    }
+
+    /// Full URL of the `/exchange` endpoint.
+    pub fn exchange_url(&self) -> String {
+        format!("{}/exchange", self.api_url())
+    }
+
+    /// Chain ID used for user-signed (EIP-712) actions.
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            Network::Mainnet => CHAIN_ID_MAINNET,
+            Network::Testnet => CHAIN_ID_TESTNET,
+            Network::Custom { chain_id, .. } => *chain_id,
+        }
+    }
+
+    /// Agent source used when signing L1 actions.
+    pub fn agent_source(&self) -> &str {
+        match self {
+            Network::Mainnet => AGENT_SOURCE_MAINNET,
+            Network::Testnet => AGENT_SOURCE_TESTNET,
+            Network::Custom { agent_source, .. } => agent_source,
+        }
+    }
+
+    /// Whether actions are signed for mainnet.
+    pub fn is_mainnet(&self) -> bool {
+        self.agent_source() == AGENT_SOURCE_MAINNET
+    }
+
+    /// Value of the `hyperliquidChain` field in user-signed actions.
+    pub fn hyperliquid_chain(&self) -> &'static str {
+        if self.is_mainnet() {
+            "Mainnet"
+        } else {
+            "Testnet"
         }
     }
 }
@@ -78,3 +153,22 @@ pub const TIF_ALO: &str = "Alo";
 
 pub const TPSL_TP: &str = "tp";
 pub const TPSL_SL: &str = "sl";
+
+/// Default slippage bound for market orders (5%)
+pub const DEFAULT_SLIPPAGE: f64 = 0.05;
+
+// ==================== Asset Constants ====================
+
+/// Offset added to a spot pair index to form its asset id
+pub const SPOT_ASSET_OFFSET: u32 = 10_000;
+/// Base of builder-deployed perp DEX asset ids: `100000 + 10000 * dex + index`
+pub const PERP_DEX_ASSET_OFFSET: u32 = 100_000;
+/// Asset ids reserved for each builder-deployed perp DEX
+pub const PERP_DEX_ASSET_STRIDE: u32 = 10_000;
+
+/// Maximum price decimals for perpetuals, before subtracting `szDecimals`
+pub const MAX_DECIMALS_PERP: u32 = 6;
+/// Maximum price decimals for spot pairs, before subtracting `szDecimals`
+pub const MAX_DECIMALS_SPOT: u32 = 8;
+/// Maximum significant figures in a price (integer prices are always allowed)
+pub const MAX_PRICE_SIGNIFICANT_FIGURES: u32 = 5;
@@ -38,9 +38,120 @@ pub enum HyperliquidError {
     #[error("Serialization error: {0}")]
     Serialize(String),
 
+    #[error("storage error: {0}")]
+    Storage(String),
+
     #[error("unauthorized: {0}")]
     Unauthorized(String),
 
     #[error("invalid request: {0}")]
     InvalidRequest(String),
+
+    #[error("rejected by exchange: {message}")]
+    Rejected {
+        reason: RejectReason,
+        message: String,
+    },
+}
+
+impl HyperliquidError {
+    /// Error for a rejection message returned by the exchange
+    pub fn rejected(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::Rejected {
+            reason: RejectReason::from_message(&message),
+            message,
+        }
+    }
+
+    /// Whether sending the same request again later may succeed
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Rejected { reason, .. } => reason.is_retryable(),
+            Self::RateLimited { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+/// Why the exchange rejected a request or order, parsed from its message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RejectReason {
+    /// Not enough margin or spot balance
+    InsufficientMargin,
+    /// Post-only (ALO) order would have crossed the book
+    PostOnlyWouldCross,
+    /// Reduce-only order would increase the position
+    ReduceOnlyWouldIncrease,
+    /// IOC order found nothing to match against
+    IocCouldNotMatch,
+    /// Price off the tick size or otherwise invalid
+    InvalidPrice,
+    /// Order value below the minimum notional
+    MinNotional,
+    /// Open order limit reached
+    TooManyOrders,
+    /// Address-based request limit reached
+    RateLimited,
+    /// Nonce already used or outside the accepted window
+    InvalidNonce,
+    /// Asset id or coin not listed
+    UnknownAsset,
+    /// Vault address not registered
+    VaultNotFound,
+    /// Signer is neither the user nor one of its API wallets
+    UnknownAccount,
+    /// Daily limit of scheduled cancel triggers reached
+    ScheduleCancelLimit,
+    /// Message not recognized
+    Other,
+}
+
+impl RejectReason {
+    /// Classify a rejection message.
+    pub fn from_message(message: &str) -> Self {
+        let message = message.to_ascii_lowercase();
+        let has = |needle: &str| message.contains(needle);
+
+        if has("insufficient margin") || has("insufficient spot balance") {
+            Self::InsufficientMargin
+        } else if has("post only") {
+            Self::PostOnlyWouldCross
+        } else if has("reduce only") {
+            Self::ReduceOnlyWouldIncrease
+        } else if has("could not immediately match") {
+            Self::IocCouldNotMatch
+        } else if has("tick size") || has("invalid price") {
+            Self::InvalidPrice
+        } else if has("minimum value") {
+            Self::MinNotional
+        } else if has("too many cumulative requests") || has("rate limit") {
+            Self::RateLimited
+        } else if has("trigger") && has("cancel") {
+            Self::ScheduleCancelLimit
+        } else if has("too many") && has("orders") || has("open orders limit") {
+            Self::TooManyOrders
+        } else if has("nonce") {
+            Self::InvalidNonce
+        } else if has("vault") && (has("not registered") || has("does not exist")) {
+            Self::VaultNotFound
+        } else if has("asset") && (has("unknown") || has("invalid") || has("not found")) {
+            Self::UnknownAsset
+        } else if has("does not exist") {
+            Self::UnknownAccount
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Whether sending the same request again later may succeed.
+    ///
+    /// True for rate limits, stale nonces (a retry signs a fresh one) and IOC
+    /// orders that found no liquidity.
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::RateLimited | Self::InvalidNonce | Self::IocCouldNotMatch
+        )
+    }
 }
@@ -0,0 +1,577 @@
+//! Live mirror of an account's positions, margin, balances and open orders.
+//!
+//! [`AccountState`] bootstraps from the info endpoints (`clearinghouseState`,
+//! `openOrders`, `spotClearinghouseState`) and then applies `clearinghouseState`
+//! and `openOrders` WebSocket messages as they arrive. Every change is
+//! published as a complete [`AccountSnapshot`], so readers never see a
+//! half-applied update. After a dropped connection the snapshot is marked
+//! unsynced and refetched from the info endpoints once the socket is back.
+//!
+//! Spot balances have no feed here and are re-read every
+//! [`spot_refresh_interval`](AccountStateConfig::spot_refresh_interval).
+//!
+//! Amounts keep the exchange's exact strings as [`Num`]; call
+//! [`Num::to_f64`] (or `to_decimal` with the `decimal` feature) to compute
+//! with them.
+//!
+//! # Example
+//! ```ignore
+//! let ws = ManagedWsProvider::connect_with_defaults(Network::Mainnet).await?;
+//! let account = AccountState::start(InfoProvider::mainnet(), ws.clone(), user).await?;
+//! ws.start_reading().await?;
+//!
+//! let mut changes = account.subscribe();
+//! while changes.changed().await.is_ok() {
+//!     let snapshot = changes.borrow().clone();
+//!     println!("{} positions, {} withdrawable", snapshot.positions.len(), snapshot.withdrawable);
+//! }
+//! ```
+
+use std::{sync::Arc, time::Duration};
+
+use alloy::primitives::Address;
+use tokio::{
+    sync::{watch, Notify},
+    task::JoinHandle,
+};
+
+use crate::{
+    errors::HyperliquidError,
+    providers::{
+        info::InfoProvider, websocket::ManagedWsProvider,
+        ws_channel::SubscriptionReceiver,
+    },
+    types::{
+        decimal::Num,
+        info_types::{
+            AssetPosition, Leverage, MarginSummary, OpenOrdersResponse,
+            UserStateResponse, UserTokenBalanceResponse,
+        },
+        ws::{
+            AssetPositionWs, BasicOrder, ClearinghouseStateWsData, MarginSummaryWs,
+            Message, Subscription,
+        },
+    },
+    utils::now_ms,
+};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// How often a pending resync checks whether the socket is back
+const RESYNC_POLL: Duration = Duration::from_millis(250);
+
+/// Configuration for [`AccountState`]
+#[derive(Clone, Debug)]
+pub struct AccountStateConfig {
+    /// How often spot balances are re-read; zero disables periodic refreshes
+    pub spot_refresh_interval: Duration,
+    /// Delay before retrying a failed resync
+    pub resync_retry_delay: Duration,
+}
+
+impl Default for AccountStateConfig {
+    fn default() -> Self {
+        Self {
+            spot_refresh_interval: Duration::from_secs(30),
+            resync_retry_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Leverage setting of a position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PositionLeverage {
+    pub value: u32,
+    /// Cross margin if true, isolated otherwise
+    pub is_cross: bool,
+}
+
+/// An open perp position
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub coin: String,
+    /// Signed size; negative for shorts
+    pub szi: Num,
+    pub entry_px: Option<Num>,
+    pub position_value: Num,
+    pub unrealized_pnl: Num,
+    pub return_on_equity: Num,
+    pub liquidation_px: Option<Num>,
+    pub margin_used: Num,
+    /// Leverage, when the update carried it
+    pub leverage: Option<PositionLeverage>,
+}
+
+/// Margin totals of an account
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AccountMargin {
+    pub account_value: Num,
+    pub total_margin_used: Num,
+    pub total_ntl_pos: Num,
+    pub total_raw_usd: Num,
+}
+
+/// Spot token balance
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpotBalance {
+    pub coin: String,
+    pub total: Num,
+    /// Amount held by open orders
+    pub hold: Num,
+    pub entry_ntl: Num,
+}
+
+/// A resting order
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenOrder {
+    pub coin: String,
+    pub is_buy: bool,
+    pub limit_px: Num,
+    /// Remaining size
+    pub sz: Num,
+    pub orig_sz: Option<Num>,
+    pub oid: u64,
+    pub cloid: Option<String>,
+    pub timestamp: u64,
+}
+
+/// Consistent view of an account at one point in time
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AccountSnapshot {
+    pub user: Address,
+    pub positions: Vec<Position>,
+    /// Totals across cross and isolated positions
+    pub margin: AccountMargin,
+    /// Totals of cross positions only
+    pub cross_margin: AccountMargin,
+    pub withdrawable: Num,
+    pub spot_balances: Vec<SpotBalance>,
+    pub open_orders: Vec<OpenOrder>,
+    /// False until bootstrapped, and from a disconnect until the resync after it
+    pub synced: bool,
+    /// When the snapshot last changed (ms since epoch)
+    pub updated_at: u64,
+}
+
+impl AccountSnapshot {
+    /// Empty, unsynced snapshot for `user`
+    pub fn new(user: Address) -> Self {
+        Self {
+            user,
+            ..Self::default()
+        }
+    }
+
+    /// Position in `coin`, if one is open
+    pub fn position(&self, coin: &str) -> Option<&Position> {
+        self.positions.iter().find(|position| position.coin == coin)
+    }
+
+    /// Spot balance of `coin`, if any
+    pub fn spot_balance(&self, coin: &str) -> Option<&SpotBalance> {
+        self.spot_balances
+            .iter()
+            .find(|balance| balance.coin == coin)
+    }
+
+    /// Margin used by isolated positions, as an `f64`
+    pub fn isolated_margin_used(&self) -> f64 {
+        self.positions
+            .iter()
+            .filter(|position| {
+                position.leverage.is_some_and(|leverage| !leverage.is_cross)
+            })
+            .map(|position| position.margin_used.to_f64())
+            .sum()
+    }
+
+    /// Replace positions and margin with a `clearinghouseState` info response.
+    pub fn apply_user_state(&mut self, state: &UserStateResponse) -> Result<()> {
+        let positions = state
+            .asset_positions
+            .iter()
+            .map(position_from_info)
+            .collect::<Result<Vec<_>>>()?;
+        self.set_margin(
+            positions,
+            margin_from_info(&state.margin_summary)?,
+            margin_from_info(&state.cross_margin_summary)?,
+            parse(&state.withdrawable)?,
+        );
+        Ok(())
+    }
+
+    /// Replace open orders with an `openOrders` info response.
+    pub fn apply_open_orders(&mut self, orders: &[OpenOrdersResponse]) -> Result<()> {
+        self.open_orders = orders
+            .iter()
+            .map(|order| {
+                Ok(OpenOrder {
+                    coin: order.coin.clone(),
+                    is_buy: order.side == "B",
+                    limit_px: parse(&order.limit_px)?,
+                    sz: parse(&order.sz)?,
+                    orig_sz: order.orig_sz.as_ref().map(parse).transpose()?,
+                    oid: order.oid,
+                    cloid: order.cloid.clone(),
+                    timestamp: order.timestamp,
+                })
+            })
+            .collect::<Result<_>>()?;
+        self.touch();
+        Ok(())
+    }
+
+    /// Replace spot balances with a `spotClearinghouseState` info response.
+    pub fn apply_spot_balances(
+        &mut self,
+        balances: &UserTokenBalanceResponse,
+    ) -> Result<()> {
+        self.spot_balances = balances
+            .balances
+            .iter()
+            .map(|balance| {
+                Ok(SpotBalance {
+                    coin: balance.coin.clone(),
+                    total: parse(&balance.total)?,
+                    hold: parse(&balance.hold)?,
+                    entry_ntl: parse(&balance.entry_ntl)?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        self.touch();
+        Ok(())
+    }
+
+    /// Apply a `clearinghouseState`, `openOrders` or `webData3` message for
+    /// this snapshot's user, returning whether anything was applied.
+    ///
+    /// A message that fails to parse leaves the snapshot untouched.
+    pub fn apply_message(&mut self, message: &Message) -> Result<bool> {
+        match message {
+            Message::ClearinghouseState(state) if state.data.user == self.user => {
+                self.apply_clearinghouse(&state.data)?;
+            }
+            Message::OpenOrders(orders) if orders.data.user == self.user => {
+                self.open_orders = open_orders_from_ws(&orders.data.orders)?;
+                self.touch();
+            }
+            Message::WebData3(data) if data.data.user == self.user => {
+                let mut next = self.clone();
+                if let Some(state) = &data.data.clearinghouse_state {
+                    next.apply_clearinghouse(state)?;
+                }
+                if let Some(orders) = &data.data.open_orders {
+                    next.open_orders = open_orders_from_ws(orders)?;
+                    next.touch();
+                }
+                *self = next;
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn apply_clearinghouse(&mut self, state: &ClearinghouseStateWsData) -> Result<()> {
+        let positions = state
+            .asset_positions
+            .iter()
+            .map(position_from_ws)
+            .collect::<Result<Vec<_>>>()?;
+        self.set_margin(
+            positions,
+            margin_from_ws(&state.margin_summary)?,
+            margin_from_ws(&state.cross_margin_summary)?,
+            parse(&state.withdrawable)?,
+        );
+        Ok(())
+    }
+
+    fn set_margin(
+        &mut self,
+        positions: Vec<Position>,
+        margin: AccountMargin,
+        cross_margin: AccountMargin,
+        withdrawable: Num,
+    ) {
+        self.positions = positions;
+        self.margin = margin;
+        self.cross_margin = cross_margin;
+        self.withdrawable = withdrawable;
+        self.touch();
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = now_ms();
+    }
+}
+
+/// Background service keeping an [`AccountSnapshot`] up to date.
+///
+/// Dropping it stops the updates.
+pub struct AccountState {
+    snapshot: watch::Receiver<AccountSnapshot>,
+    resync: Arc<Notify>,
+    handle: JoinHandle<()>,
+}
+
+impl AccountState {
+    /// Bootstrap `user`'s state and follow it on `ws` with the default
+    /// configuration.
+    ///
+    /// Call [`ManagedWsProvider::start_reading`] if the provider is not
+    /// reading yet.
+    pub async fn start(
+        info: InfoProvider,
+        ws: Arc<ManagedWsProvider>,
+        user: Address,
+    ) -> Result<Self> {
+        Self::start_with_config(info, ws, user, AccountStateConfig::default()).await
+    }
+
+    /// Bootstrap `user`'s state and follow it on `ws`.
+    pub async fn start_with_config(
+        info: InfoProvider,
+        ws: Arc<ManagedWsProvider>,
+        user: Address,
+        config: AccountStateConfig,
+    ) -> Result<Self> {
+        // Subscribe first so no update falls between the fetch and the feed
+        let (_, state_rx) = ws
+            .subscribe(Subscription::ClearinghouseState { user })
+            .await?;
+        let (_, orders_rx) = ws.subscribe(Subscription::OpenOrders { user }).await?;
+        let disconnections = ws.disconnections();
+
+        let snapshot = fetch(&info, user).await?;
+        let (tx, rx) = watch::channel(snapshot);
+        let resync = Arc::new(Notify::new());
+        let task = Follow {
+            info,
+            ws,
+            config,
+            tx,
+            resync: resync.clone(),
+        };
+        let handle = tokio::spawn(task.run(state_rx, orders_rx, disconnections));
+
+        Ok(Self {
+            snapshot: rx,
+            resync,
+            handle,
+        })
+    }
+
+    /// Latest snapshot
+    pub fn snapshot(&self) -> AccountSnapshot {
+        self.snapshot.borrow().clone()
+    }
+
+    /// Watch snapshot changes
+    pub fn subscribe(&self) -> watch::Receiver<AccountSnapshot> {
+        self.snapshot.clone()
+    }
+
+    /// Refetch everything from the info endpoints in the background
+    pub fn resync(&self) {
+        self.resync.notify_one();
+    }
+}
+
+impl Drop for AccountState {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+struct Follow {
+    info: InfoProvider,
+    ws: Arc<ManagedWsProvider>,
+    config: AccountStateConfig,
+    tx: watch::Sender<AccountSnapshot>,
+    resync: Arc<Notify>,
+}
+
+impl Follow {
+    async fn run(
+        self,
+        mut state_rx: SubscriptionReceiver,
+        mut orders_rx: SubscriptionReceiver,
+        mut disconnections: watch::Receiver<u64>,
+    ) {
+        let refresh_spot = self.config.spot_refresh_interval > Duration::ZERO;
+        let mut spot_interval = tokio::time::interval(if refresh_spot {
+            self.config.spot_refresh_interval
+        } else {
+            Duration::from_secs(3600)
+        });
+        spot_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        spot_interval.reset();
+        let mut resync_poll = tokio::time::interval(RESYNC_POLL);
+        let mut resync_pending = false;
+        let user = self.tx.borrow().user;
+
+        loop {
+            tokio::select! {
+                changed = disconnections.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                    // Anything still queued predates the disconnect
+                    while state_rx.try_recv().is_ok() {}
+                    while orders_rx.try_recv().is_ok() {}
+                    self.tx.send_modify(|snapshot| snapshot.synced = false);
+                    resync_pending = true;
+                }
+                _ = self.resync.notified() => resync_pending = true,
+                _ = resync_poll.tick(), if resync_pending => {
+                    if !self.ws.is_connected().await {
+                        continue;
+                    }
+                    match fetch(&self.info, user).await {
+                        Ok(snapshot) => {
+                            self.tx.send_replace(snapshot);
+                            resync_pending = false;
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to resync account state: {}", e);
+                            tokio::time::sleep(self.config.resync_retry_delay).await;
+                        }
+                    }
+                }
+                _ = spot_interval.tick(), if refresh_spot => {
+                    match self.info.user_token_balances(user).await {
+                        Ok(balances) => self.modify(|snapshot| snapshot.apply_spot_balances(&balances)),
+                        Err(e) => tracing::warn!("Failed to refresh spot balances: {}", e),
+                    }
+                }
+                message = state_rx.recv() => match message {
+                    Some(message) => self.apply(&message),
+                    None => break,
+                },
+                message = orders_rx.recv() => match message {
+                    Some(message) => self.apply(&message),
+                    None => break,
+                },
+            }
+        }
+    }
+
+    fn apply(&self, message: &Message) {
+        self.modify(|snapshot| snapshot.apply_message(message).map(|_| ()));
+    }
+
+    /// Apply `change` to a copy and publish it if it succeeded and changed
+    /// more than the update time
+    fn modify(&self, change: impl FnOnce(&mut AccountSnapshot) -> Result<()>) {
+        let current = self.tx.borrow().clone();
+        let mut next = current.clone();
+        if let Err(e) = change(&mut next) {
+            tracing::warn!("Ignoring account update: {}", e);
+            return;
+        }
+        let unchanged = AccountSnapshot {
+            updated_at: current.updated_at,
+            ..next.clone()
+        } == current;
+        if !unchanged {
+            self.tx.send_replace(next);
+        }
+    }
+}
+
+/// Full snapshot from the info endpoints
+async fn fetch(info: &InfoProvider, user: Address) -> Result<AccountSnapshot> {
+    let (state, orders, balances) = tokio::try_join!(
+        info.user_state(user),
+        info.open_orders(user),
+        info.user_token_balances(user),
+    )?;
+
+    let mut snapshot = AccountSnapshot::new(user);
+    snapshot.apply_user_state(&state)?;
+    snapshot.apply_open_orders(&orders)?;
+    snapshot.apply_spot_balances(&balances)?;
+    snapshot.synced = true;
+    Ok(snapshot)
+}
+
+fn position_from_info(asset: &AssetPosition) -> Result<Position> {
+    let position = &asset.position;
+    Ok(Position {
+        coin: position.coin.clone(),
+        szi: parse(&position.szi)?,
+        entry_px: position.entry_px.as_ref().map(parse).transpose()?,
+        position_value: parse(&position.position_value)?,
+        unrealized_pnl: parse(&position.unrealized_pnl)?,
+        return_on_equity: parse(&position.return_on_equity)?,
+        liquidation_px: position.liquidation_px.as_ref().map(parse).transpose()?,
+        margin_used: parse(&position.margin_used)?,
+        leverage: Some(leverage(&position.leverage)),
+    })
+}
+
+fn position_from_ws(asset: &AssetPositionWs) -> Result<Position> {
+    let position = &asset.position;
+    Ok(Position {
+        coin: position.coin.clone(),
+        szi: parse(&position.szi)?,
+        entry_px: position.entry_px.as_ref().map(parse).transpose()?,
+        position_value: parse(&position.position_value)?,
+        unrealized_pnl: parse(&position.unrealized_pnl)?,
+        return_on_equity: parse(&position.return_on_equity)?,
+        liquidation_px: position.liquidation_px.as_ref().map(parse).transpose()?,
+        margin_used: parse(&position.margin_used)?,
+        leverage: position.leverage.as_ref().map(leverage),
+    })
+}
+
+fn leverage(leverage: &Leverage) -> PositionLeverage {
+    PositionLeverage {
+        value: leverage.value,
+        is_cross: leverage.type_string == "cross",
+    }
+}
+
+fn margin_from_info(summary: &MarginSummary) -> Result<AccountMargin> {
+    Ok(AccountMargin {
+        account_value: parse(&summary.account_value)?,
+        total_margin_used: parse(&summary.total_margin_used)?,
+        total_ntl_pos: parse(&summary.total_ntl_pos)?,
+        total_raw_usd: parse(&summary.total_raw_usd)?,
+    })
+}
+
+fn margin_from_ws(summary: &MarginSummaryWs) -> Result<AccountMargin> {
+    Ok(AccountMargin {
+        account_value: parse(&summary.account_value)?,
+        total_margin_used: parse(&summary.total_margin_used)?,
+        total_ntl_pos: parse(&summary.total_ntl_pos)?,
+        total_raw_usd: parse(&summary.total_raw_usd)?,
+    })
+}
+
+fn open_orders_from_ws(orders: &[BasicOrder]) -> Result<Vec<OpenOrder>> {
+    orders
+        .iter()
+        .map(|order| {
+            Ok(OpenOrder {
+                coin: order.coin.clone(),
+                is_buy: order.side == "B",
+                limit_px: parse(&order.limit_px)?,
+                sz: parse(&order.sz)?,
+                orig_sz: Some(parse(&order.orig_sz)?),
+                oid: order.oid,
+                cloid: order.cloid.clone(),
+                timestamp: order.timestamp,
+            })
+        })
+        .collect()
+}
+
+/// `value` if it is a number
+fn parse(value: &Num) -> Result<Num> {
+    value.parse::<f64>().map(|_| value.clone()).map_err(|_| {
+        HyperliquidError::InvalidResponse(format!("invalid number: {:?}", value))
+    })
+}
@@ -1,14 +1,21 @@
 //! Agent wallet management with automatic rotation and safety features
 
 use crate::{
-    errors::HyperliquidError, providers::nonce::NonceManager, signers::HyperliquidSigner,
+    errors::HyperliquidError,
+    providers::{nonce::NonceManager, RawExchangeProvider},
+    signers::HyperliquidSigner,
+    types::responses::ExchangeResponseStatus,
+    utils::now_ms,
     Network,
 };
-use alloy::primitives::Address;
+use alloy::primitives::{Address, B256};
 use alloy::signers::local::PrivateKeySigner;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, OwnedRwLockReadGuard, RwLock};
 
 /// Agent wallet with lifecycle tracking
 #[derive(Clone)]
@@ -70,6 +77,11 @@ pub struct AgentConfig {
     pub health_check_interval: Duration,
     /// Rotate agents proactively before expiry
     pub proactive_rotation_buffer: Duration,
+    /// File keeping agent keys across restarts, so a restart reuses the
+    /// approved agents instead of approving new ones.
+    ///
+    /// Keys are stored unencrypted; keep the file private.
+    pub key_file: Option<PathBuf>,
 }
 
 impl Default for AgentConfig {
@@ -78,16 +90,41 @@ impl Default for AgentConfig {
             ttl: Duration::from_secs(23 * 60 * 60), // Rotate daily
             health_check_interval: Duration::from_secs(300), // Check every 5 min
             proactive_rotation_buffer: Duration::from_secs(60 * 60), // Rotate 1hr before expiry
+            key_file: None,
         }
     }
 }
 
+/// An agent borrowed for signing.
+///
+/// The agent is not rotated while a lease is held, so actions signed with it
+/// are sent before a replacement is approved.
+pub struct AgentLease {
+    /// Agent to sign with
+    pub wallet: AgentWallet,
+    _guard: OwnedRwLockReadGuard<()>,
+}
+
+/// Agent key as stored in [`AgentConfig::key_file`]
+#[derive(Serialize, Deserialize)]
+struct StoredAgent {
+    key: B256,
+    /// Approval time in milliseconds since the epoch
+    approved_at: u64,
+}
+
 /// Manages agent lifecycle with automatic rotation
 pub struct AgentManager<S: HyperliquidSigner> {
     /// Master signer that approves agents
     master_signer: S,
+    /// Provider approving agents, shared with the caller when set
+    exchange: Option<Arc<RawExchangeProvider<S>>>,
     /// Currently active agents by name
-    agents: Arc<RwLock<std::collections::HashMap<String, AgentWallet>>>,
+    agents: Arc<RwLock<HashMap<String, AgentWallet>>>,
+    /// Held while an agent is being replaced
+    rotation: Mutex<()>,
+    /// Read by leases, written while a replacement is approved
+    in_flight: Arc<RwLock<()>>,
     /// Configuration
     config: AgentConfig,
     /// Network for agent operations
@@ -99,43 +136,126 @@ impl<S: HyperliquidSigner + Clone> AgentManager<S> {
     pub fn new(master_signer: S, config: AgentConfig, network: Network) -> Self {
         Self {
             master_signer,
-            agents: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            exchange: None,
+            agents: Arc::new(RwLock::new(HashMap::new())),
+            rotation: Mutex::new(()),
+            in_flight: Arc::new(RwLock::new(())),
             config,
             network,
         }
     }
 
+    /// Approve agents through an existing provider (and its transport) instead
+    /// of a new one for the manager's network.
+    pub fn with_exchange(mut self, exchange: Arc<RawExchangeProvider<S>>) -> Self {
+        self.exchange = Some(exchange);
+        self
+    }
+
+    /// TTL shortened by the proactive rotation buffer
+    fn effective_ttl(&self) -> Duration {
+        self.config
+            .ttl
+            .saturating_sub(self.config.proactive_rotation_buffer)
+    }
+
     /// Get or create an agent, rotating if necessary
+    ///
+    /// Agents are replaced [`AgentConfig::proactive_rotation_buffer`] before
+    /// their TTL runs out. A replacement is approved once every outstanding
+    /// [`AgentLease`] is released, and new leases wait for it.
     pub async fn get_or_rotate_agent(
         &self,
         name: &str,
     ) -> Result<AgentWallet, HyperliquidError> {
-        let mut agents = self.agents.write().await;
+        if let Some(agent) = self.current(name).await {
+            return Ok(agent);
+        }
 
-        // Check if we have an active agent
-        if let Some(agent) = agents.get(name) {
-            let effective_ttl = self
-                .config
-                .ttl
-                .saturating_sub(self.config.proactive_rotation_buffer);
+        let _rotating = self.rotation.lock().await;
+        // Another caller may have rotated while we waited
+        if let Some(agent) = self.current(name).await {
+            return Ok(agent);
+        }
 
-            if !agent.should_rotate(effective_ttl) {
-                return Ok(agent.clone());
+        {
+            let mut agents = self.agents.write().await;
+            if let Some(agent) = agents.get_mut(name) {
+                // Mark for rotation
+                agent.status = AgentStatus::PendingRotation;
+            } else if let Some(agent) = self.restore(name) {
+                agents.insert(name.to_string(), agent);
             }
-
-            // Mark for rotation
-            let mut agent_mut = agent.clone();
-            agent_mut.status = AgentStatus::PendingRotation;
-            agents.insert(name.to_string(), agent_mut);
+        }
+        if let Some(agent) = self.current(name).await {
+            return Ok(agent);
         }
 
-        // Create new agent
+        // Approving a new agent under the same name revokes the old one, so
+        // wait for actions it signed to be sent
+        let _drained = self.in_flight.write().await;
         let new_agent = self.create_new_agent(name).await?;
-        agents.insert(name.to_string(), new_agent.clone());
+        self.agents
+            .write()
+            .await
+            .insert(name.to_string(), new_agent.clone());
 
         Ok(new_agent)
     }
 
+    /// Borrow the agent `name` for signing, rotating it first if necessary.
+    pub async fn lease(&self, name: &str) -> Result<AgentLease, HyperliquidError> {
+        let guard = self.in_flight.clone().read_owned().await;
+        if let Some(wallet) = self.current(name).await {
+            return Ok(AgentLease {
+                wallet,
+                _guard: guard,
+            });
+        }
+        drop(guard);
+
+        self.get_or_rotate_agent(name).await?;
+        let guard = self.in_flight.clone().read_owned().await;
+        let wallet = self.agents.read().await.get(name).cloned().ok_or_else(|| {
+            HyperliquidError::InvalidRequest(format!("agent {:?} was removed", name))
+        })?;
+        Ok(AgentLease {
+            wallet,
+            _guard: guard,
+        })
+    }
+
+    /// Replace every agent that is due for rotation.
+    pub async fn rotate_expiring(&self) -> Result<(), HyperliquidError> {
+        let effective_ttl = self.effective_ttl();
+        let expiring: Vec<String> = self
+            .agents
+            .read()
+            .await
+            .iter()
+            .filter(|(_, agent)| {
+                agent.status != AgentStatus::Deregistered
+                    && agent.should_rotate(effective_ttl)
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in expiring {
+            self.get_or_rotate_agent(&name).await?;
+        }
+        Ok(())
+    }
+
+    /// The agent `name` if it is active and not due for rotation
+    async fn current(&self, name: &str) -> Option<AgentWallet> {
+        let effective_ttl = self.effective_ttl();
+        self.agents
+            .read()
+            .await
+            .get(name)
+            .filter(|agent| !agent.should_rotate(effective_ttl))
+            .cloned()
+    }
+
     /// Create and approve a new agent
     async fn create_new_agent(
         &self,
@@ -150,6 +270,12 @@ impl<S: HyperliquidSigner + Clone> AgentManager<S> {
         self.approve_agent_internal(agent_wallet.address, Some(name.to_string()))
             .await?;
 
+        if let Some(path) = &self.config.key_file {
+            if let Err(e) = store_agent(path, name, &agent_signer) {
+                tracing::warn!("Failed to persist agent {:?}: {}", name, e);
+            }
+        }
+
         Ok(agent_wallet)
     }
 
@@ -159,18 +285,39 @@ impl<S: HyperliquidSigner + Clone> AgentManager<S> {
         agent_address: Address,
         name: Option<String>,
     ) -> Result<(), HyperliquidError> {
-        use crate::providers::RawExchangeProvider;
-
-        // Create a temporary raw provider just for agent approval
-        let raw_provider = match self.network {
-            Network::Mainnet => RawExchangeProvider::mainnet(self.master_signer.clone()),
-            Network::Testnet => RawExchangeProvider::testnet(self.master_signer.clone()),
+        let response = match &self.exchange {
+            Some(exchange) => exchange.approve_agent(agent_address, name).await?,
+            None => {
+                // Create a temporary raw provider just for agent approval
+                let raw_provider = RawExchangeProvider::new(
+                    self.master_signer.clone(),
+                    self.network.clone(),
+                );
+                raw_provider.approve_agent(agent_address, name).await?
+            }
         };
 
-        // Approve the agent
-        raw_provider.approve_agent(agent_address, name).await?;
+        match response {
+            ExchangeResponseStatus::Ok(_) => Ok(()),
+            ExchangeResponseStatus::Err(msg) => Err(HyperliquidError::rejected(msg)),
+        }
+    }
 
-        Ok(())
+    /// Agent `name` from the key file, if it is still within its TTL
+    fn restore(&self, name: &str) -> Option<AgentWallet> {
+        let path = self.config.key_file.as_ref()?;
+        let stored = load_agents(path)
+            .map_err(|e| tracing::warn!("Failed to read agent keys: {}", e))
+            .ok()?
+            .remove(name)?;
+        let signer = PrivateKeySigner::from_bytes(&stored.key).ok()?;
+        let age = Duration::from_millis(now_ms().saturating_sub(stored.approved_at));
+        let created_at = Instant::now().checked_sub(age)?;
+        let wallet = AgentWallet {
+            created_at,
+            ..AgentWallet::new(signer)
+        };
+        (!wallet.should_rotate(self.effective_ttl())).then_some(wallet)
     }
 
     /// Get all active agents
@@ -198,6 +345,38 @@ impl<S: HyperliquidSigner + Clone> AgentManager<S> {
     }
 }
 
+fn load_agents(path: &Path) -> std::io::Result<HashMap<String, StoredAgent>> {
+    match std::fs::read(path) {
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(e) => Err(e),
+    }
+}
+
+fn store_agent(
+    path: &Path,
+    name: &str,
+    signer: &PrivateKeySigner,
+) -> std::io::Result<()> {
+    let mut agents = load_agents(path).unwrap_or_default();
+    agents.insert(
+        name.to_string(),
+        StoredAgent {
+            key: signer.to_bytes(),
+            approved_at: now_ms(),
+        },
+    );
+
+    // Write a private temporary file and move it into place
+    let tmp = path.with_extension("tmp");
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    serde_json::to_writer_pretty(options.open(&tmp)?, &agents)?;
+    std::fs::rename(tmp, path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,379 @@
+//! Symbol to asset id resolution
+//!
+//! Orders address assets by numeric id: the universe index for perpetuals,
+//! `10000 + index` for spot pairs and `100000 + 10000 * dex + index` for perps
+//! listed on builder-deployed DEXs. [`AssetRegistry`] builds that mapping from
+//! `meta`, `spotMeta` and `perpDexs`, together with the size and price rules of
+//! each asset, and refreshes it when it goes stale or a symbol is missing.
+//!
+//! One registry is meant to be shared (`Arc<AssetRegistry>`) between providers.
+//! Attached to an exchange provider with
+//! [`RawExchangeProvider::with_asset_registry`](crate::providers::RawExchangeProvider::with_asset_registry),
+//! it lets [`order_for`](crate::providers::RawExchangeProvider::order_for) place
+//! orders by symbol. Attached to
+//! [`InfoProvider::with_asset_registry`](crate::providers::InfoProvider::with_asset_registry)
+//! or [`ManagedWsProvider::with_asset_registry`](crate::providers::ManagedWsProvider::with_asset_registry),
+//! it resolves the coin of info requests and subscriptions.
+//!
+//! # Example
+//! ```ignore
+//! let info = InfoProvider::mainnet();
+//! let registry = Arc::new(AssetRegistry::new(info.clone()));
+//! let info = info.with_asset_registry(registry.clone());
+//! let exchange = exchange.with_asset_registry(registry.clone());
+//! let ws = ws.with_asset_registry(registry.clone());
+//!
+//! let hype = registry.resolve("HYPE/USDC").await?; // spot, asset 10000 + index
+//! let book = info.l2_book("HYPE/USDC").await?; // requested as `@107`
+//! exchange.order_for("HYPE/USDC").limit_buy("20", "5").send().await?;
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Mutex;
+
+use crate::{
+    constants::{
+        MAX_DECIMALS_PERP, MAX_DECIMALS_SPOT, PERP_DEX_ASSET_OFFSET,
+        PERP_DEX_ASSET_STRIDE, SPOT_ASSET_OFFSET,
+    },
+    errors::HyperliquidError,
+    providers::InfoProvider,
+    types::info_types::{Meta, SpotMeta},
+};
+
+/// Default age after which [`AssetRegistry::resolve`] reloads metadata
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Minimum time between reloads triggered by unknown symbols
+const MISS_REFRESH_COOLDOWN: Duration = Duration::from_secs(5);
+
+/// Minimum time after a failed reload before [`AssetRegistry::resolve`] retries
+const FAILED_REFRESH_COOLDOWN: Duration = Duration::from_secs(5);
+
+/// Market an asset trades on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    Perp,
+    Spot,
+}
+
+/// Trading rules and identifiers of one asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetInfo {
+    /// Id used in order, cancel and leverage actions
+    pub asset: u32,
+    /// Coin name used by info requests and WebSocket subscriptions
+    pub coin: String,
+    pub kind: AssetKind,
+    /// Decimals allowed in order sizes
+    pub sz_decimals: u32,
+    /// Maximum leverage, `None` for spot
+    pub max_leverage: Option<u32>,
+    /// Whether only isolated margin is allowed
+    pub only_isolated: bool,
+    /// Builder-deployed perp DEX listing this asset, `None` for the main DEX and spot
+    pub dex: Option<String>,
+}
+
+impl AssetInfo {
+    /// Whether this is a spot pair
+    pub fn is_spot(&self) -> bool {
+        self.kind == AssetKind::Spot
+    }
+
+    /// Maximum decimals allowed in a price for this asset.
+    pub fn max_price_decimals(&self) -> u32 {
+        let max = match self.kind {
+            AssetKind::Perp => MAX_DECIMALS_PERP,
+            AssetKind::Spot => MAX_DECIMALS_SPOT,
+        };
+        max.saturating_sub(self.sz_decimals)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Snapshot {
+    assets: Vec<AssetInfo>,
+    by_name: HashMap<String, usize>,
+    by_id: HashMap<u32, usize>,
+    loaded_at: Option<Instant>,
+}
+
+impl Snapshot {
+    fn insert(&mut self, info: AssetInfo, aliases: &[String]) {
+        let idx = self.assets.len();
+        self.by_id.insert(info.asset, idx);
+        self.by_name.insert(info.coin.clone(), idx);
+        for alias in aliases {
+            self.by_name.entry(alias.clone()).or_insert(idx);
+        }
+        self.assets.push(info);
+    }
+
+    fn add_perps(&mut self, meta: &Meta, offset: u32, dex: Option<&str>) {
+        for (index, asset) in meta.universe.iter().enumerate() {
+            self.insert(
+                AssetInfo {
+                    asset: offset + index as u32,
+                    coin: asset.name.clone(),
+                    kind: AssetKind::Perp,
+                    sz_decimals: asset.sz_decimals,
+                    max_leverage: Some(asset.max_leverage),
+                    only_isolated: asset.only_isolated,
+                    dex: dex.map(str::to_string),
+                },
+                &[],
+            );
+        }
+    }
+
+    fn add_spot(&mut self, spot_meta: &SpotMeta) {
+        let tokens: HashMap<u32, _> = spot_meta
+            .tokens
+            .iter()
+            .map(|token| (token.index, token))
+            .collect();
+
+        for pair in &spot_meta.universe {
+            let (Some(base), Some(quote)) =
+                (tokens.get(&pair.tokens[0]), tokens.get(&pair.tokens[1]))
+            else {
+                continue;
+            };
+            let aliases = [
+                format!("@{}", pair.index),
+                format!("{}/{}", base.name, quote.name),
+            ];
+            self.insert(
+                AssetInfo {
+                    asset: SPOT_ASSET_OFFSET + pair.index,
+                    coin: pair.name.clone(),
+                    kind: AssetKind::Spot,
+                    sz_decimals: base.sz_decimals,
+                    max_leverage: None,
+                    only_isolated: false,
+                    dex: None,
+                },
+                &aliases,
+            );
+        }
+    }
+
+    fn get(&self, symbol: &str) -> Option<&AssetInfo> {
+        self.by_name.get(symbol).map(|&idx| &self.assets[idx])
+    }
+}
+
+/// Shared map from symbols to asset ids and trading rules.
+///
+/// Perpetuals are keyed by their coin name (`BTC`, or `dex:COIN` on builder
+/// DEXs). Spot pairs are keyed by their coin name, `@index` and `BASE/QUOTE`.
+pub struct AssetRegistry {
+    info: Option<InfoProvider>,
+    snapshot: RwLock<Arc<Snapshot>>,
+    refresh_interval: Option<Duration>,
+    /// Serializes reloads so concurrent misses trigger a single fetch
+    refresh_lock: Mutex<()>,
+    /// When the last reload failed, cleared by a successful one
+    failed_at: RwLock<Option<Instant>>,
+}
+
+impl AssetRegistry {
+    /// Create an empty registry that loads metadata through `info`.
+    ///
+    /// `InfoProvider` is cheap to clone, so pass a clone of the provider the
+    /// registry is later attached to. Nothing is fetched until
+    /// [`refresh`](Self::refresh) or [`resolve`](Self::resolve) is called.
+    pub fn new(info: InfoProvider) -> Self {
+        Self {
+            info: Some(info),
+            snapshot: RwLock::new(Arc::new(Snapshot::default())),
+            refresh_interval: Some(DEFAULT_REFRESH_INTERVAL),
+            refresh_lock: Mutex::new(()),
+            failed_at: RwLock::new(None),
+        }
+    }
+
+    /// Create a fixed registry from already fetched metadata.
+    ///
+    /// The registry never refreshes; builder-deployed DEXs are not included.
+    pub fn from_meta(meta: &Meta, spot_meta: &SpotMeta) -> Self {
+        let mut snapshot = Snapshot::default();
+        snapshot.add_perps(meta, 0, None);
+        snapshot.add_spot(spot_meta);
+        snapshot.loaded_at = Some(Instant::now());
+
+        Self {
+            info: None,
+            snapshot: RwLock::new(Arc::new(snapshot)),
+            refresh_interval: None,
+            refresh_lock: Mutex::new(()),
+            failed_at: RwLock::new(None),
+        }
+    }
+
+    /// Reload metadata in [`resolve`](Self::resolve) once it is older than
+    /// `interval`; `None` disables periodic reloads.
+    pub fn with_refresh_interval(mut self, interval: Option<Duration>) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Fetch `meta`, `spotMeta`, `perpDexs` and every builder DEX's `meta`, then
+    /// replace the current mapping.
+    pub async fn refresh(&self) -> Result<(), HyperliquidError> {
+        let result = self.load().await;
+        *self
+            .failed_at
+            .write()
+            .expect("asset registry lock poisoned") = result.is_err().then(Instant::now);
+        result
+    }
+
+    async fn load(&self) -> Result<(), HyperliquidError> {
+        let info = self.info.as_ref().ok_or_else(|| {
+            HyperliquidError::InvalidRequest(
+                "asset registry was built from fixed metadata and cannot refresh"
+                    .to_string(),
+            )
+        })?;
+
+        let (meta, spot_meta, dexs) =
+            tokio::try_join!(info.meta(), info.spot_meta(), info.perp_dexs())?;
+
+        let mut snapshot = Snapshot::default();
+        snapshot.add_perps(&meta, 0, None);
+        snapshot.add_spot(&spot_meta);
+        for (index, dex) in dexs.iter().enumerate().skip(1) {
+            let Some(dex) = dex else { continue };
+            let dex_meta = info.perp_dex_meta(dex.name.clone()).await?;
+            let offset = PERP_DEX_ASSET_OFFSET + index as u32 * PERP_DEX_ASSET_STRIDE;
+            snapshot.add_perps(&dex_meta, offset, Some(&dex.name));
+        }
+        snapshot.loaded_at = Some(Instant::now());
+
+        *self.snapshot.write().expect("asset registry lock poisoned") =
+            Arc::new(snapshot);
+        Ok(())
+    }
+
+    /// Look up a symbol in the current mapping without fetching.
+    pub fn get(&self, symbol: impl AsRef<str>) -> Option<AssetInfo> {
+        self.snapshot().get(symbol.as_ref()).cloned()
+    }
+
+    /// Look up an asset by id without fetching.
+    pub fn by_asset(&self, asset: u32) -> Option<AssetInfo> {
+        let snapshot = self.snapshot();
+        snapshot
+            .by_id
+            .get(&asset)
+            .map(|&idx| snapshot.assets[idx].clone())
+    }
+
+    /// Look up a symbol, reloading metadata first if it is stale or the symbol
+    /// is unknown.
+    ///
+    /// A failed periodic reload falls back to the cached mapping. Returns
+    /// [`HyperliquidError::AssetNotFound`] if the symbol is still unknown.
+    pub async fn resolve(
+        &self,
+        symbol: impl AsRef<str>,
+    ) -> Result<AssetInfo, HyperliquidError> {
+        let symbol = symbol.as_ref();
+        self.resolve_with(|| self.get(symbol))
+            .await?
+            .ok_or_else(|| HyperliquidError::AssetNotFound(symbol.to_string()))
+    }
+
+    /// Look up an asset id, reloading metadata like [`resolve`](Self::resolve).
+    pub async fn resolve_id(&self, asset: u32) -> Result<AssetInfo, HyperliquidError> {
+        self.resolve_with(|| self.by_asset(asset))
+            .await?
+            .ok_or_else(|| HyperliquidError::AssetNotFound(format!("asset id {}", asset)))
+    }
+
+    async fn resolve_with(
+        &self,
+        lookup: impl Fn() -> Option<AssetInfo>,
+    ) -> Result<Option<AssetInfo>, HyperliquidError> {
+        // An empty registry has nothing to fall back on, so it always retries
+        if self.is_stale() && (self.is_empty() || !self.recently_failed()) {
+            let _guard = self.refresh_lock.lock().await;
+            if self.is_stale() && (self.is_empty() || !self.recently_failed()) {
+                if let Err(e) = self.refresh().await {
+                    if self.is_empty() {
+                        return Err(e);
+                    }
+                    tracing::warn!("Asset metadata refresh failed: {}", e);
+                }
+            }
+        }
+
+        if let Some(info) = lookup() {
+            return Ok(Some(info));
+        }
+
+        if self.info.is_some() {
+            let _guard = self.refresh_lock.lock().await;
+            if lookup().is_none()
+                && self.age() >= Some(MISS_REFRESH_COOLDOWN)
+                && !self.recently_failed()
+            {
+                self.refresh().await?;
+            }
+        }
+
+        Ok(lookup())
+    }
+
+    /// Every known asset, in asset id order within each market.
+    pub fn assets(&self) -> Vec<AssetInfo> {
+        self.snapshot().assets.clone()
+    }
+
+    /// Number of known assets
+    pub fn len(&self) -> usize {
+        self.snapshot().assets.len()
+    }
+
+    /// Whether no metadata has been loaded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Time since the last successful load, `None` if never loaded
+    pub fn age(&self) -> Option<Duration> {
+        self.snapshot().loaded_at.map(|at| at.elapsed())
+    }
+
+    fn is_stale(&self) -> bool {
+        if self.info.is_none() {
+            return false;
+        }
+        match (self.age(), self.refresh_interval) {
+            (None, _) => true,
+            (Some(age), Some(interval)) => age >= interval,
+            (Some(_), None) => false,
+        }
+    }
+
+    fn recently_failed(&self) -> bool {
+        self.failed_at
+            .read()
+            .expect("asset registry lock poisoned")
+            .is_some_and(|at| at.elapsed() < FAILED_REFRESH_COOLDOWN)
+    }
+
+    fn snapshot(&self) -> Arc<Snapshot> {
+        self.snapshot
+            .read()
+            .expect("asset registry lock poisoned")
+            .clone()
+    }
+}
@@ -0,0 +1,468 @@
+//! Candle series kept current from history and live updates
+//!
+//! A [`CandleSeries`] holds the closed bars of one coin plus the bar still
+//! forming. At one of the exchange's [`CandleInterval`]s it is seeded from
+//! `candleSnapshot` and follows the `candle` feed; at any other period it is
+//! rolled from `trades`, optionally seeded from a shorter interval that divides
+//! the period. Every update returns the bars it closed, and an exchange candle
+//! arriving for a bar already closed returns the amended bar again. A live
+//! series is obtained from
+//! [`ManagedWsProvider::subscribe_candle_series`](crate::providers::ManagedWsProvider::subscribe_candle_series).
+
+use std::{
+    collections::{HashSet, VecDeque},
+    time::Duration,
+};
+
+use tokio::sync::watch;
+
+use crate::{
+    errors::HyperliquidError,
+    providers::{
+        info::InfoProvider,
+        ws_channel::{Queued, SubscriptionReceiver},
+    },
+    types::{
+        info_types::{CandleInterval, CandlesSnapshotResponse},
+        ws::{CandleData, Message, Trade},
+    },
+    utils::now_ms,
+};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// Closed bars kept by default
+pub const DEFAULT_MAX_BARS: usize = 1_000;
+
+/// One OHLCV bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    /// Open time (ms since epoch)
+    pub time_open: u64,
+    /// Last millisecond covered by the bar
+    pub time_close: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub num_trades: u64,
+}
+
+impl Bar {
+    /// Bar from a `candleSnapshot` entry.
+    pub fn from_snapshot(candle: &CandlesSnapshotResponse) -> Result<Self> {
+        Ok(Self {
+            time_open: candle.time_open,
+            time_close: candle.time_close,
+            open: candle.open.parse_f64("candle open")?,
+            high: candle.high.parse_f64("candle high")?,
+            low: candle.low.parse_f64("candle low")?,
+            close: candle.close.parse_f64("candle close")?,
+            volume: candle.vlm.parse_f64("candle volume")?,
+            num_trades: candle.num_trades,
+        })
+    }
+
+    /// Bar from a `candle` message.
+    pub fn from_candle(candle: &CandleData) -> Result<Self> {
+        Ok(Self {
+            time_open: candle.time_open,
+            time_close: candle.time_close,
+            open: candle.open.parse_f64("candle open")?,
+            high: candle.high.parse_f64("candle high")?,
+            low: candle.low.parse_f64("candle low")?,
+            close: candle.close.parse_f64("candle close")?,
+            volume: candle.volume.parse_f64("candle volume")?,
+            num_trades: candle.num_trades,
+        })
+    }
+
+    /// Fold a later, finer bar into this one.
+    fn merge(&mut self, other: &Bar) {
+        self.high = self.high.max(other.high);
+        self.low = self.low.min(other.low);
+        self.close = other.close;
+        self.volume += other.volume;
+        self.num_trades += other.num_trades;
+    }
+}
+
+/// Length of the bars in a [`CandleSeries`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarPeriod {
+    /// Exchange candles, updated from the `candle` feed
+    Interval(CandleInterval),
+    /// Custom period rolled from `trades`, aligned to the Unix epoch
+    Custom(Duration),
+}
+
+/// Closed bars and the bar still forming for one coin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandleSeries {
+    coin: String,
+    period: BarPeriod,
+    bars: VecDeque<Bar>,
+    current: Option<Bar>,
+    max_bars: usize,
+    /// Trades before this time are covered by seeded candles
+    seeded_until: u64,
+    /// Trades already rolled into the current bar
+    tids: HashSet<u64>,
+}
+
+impl CandleSeries {
+    /// Empty series of exchange candles.
+    pub fn new(coin: impl Into<String>, interval: CandleInterval) -> Self {
+        Self::empty(coin.into(), BarPeriod::Interval(interval))
+    }
+
+    /// Empty series of `period` bars rolled from trades.
+    ///
+    /// The period must be a whole number of milliseconds.
+    pub fn custom(coin: impl Into<String>, period: Duration) -> Result<Self> {
+        if period.is_zero() || period.subsec_nanos() % 1_000_000 != 0 {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "bar period must be a positive whole number of milliseconds, got {:?}",
+                period
+            )));
+        }
+        Ok(Self::empty(coin.into(), BarPeriod::Custom(period)))
+    }
+
+    fn empty(coin: String, period: BarPeriod) -> Self {
+        Self {
+            coin,
+            period,
+            bars: VecDeque::new(),
+            current: None,
+            max_bars: DEFAULT_MAX_BARS,
+            seeded_until: 0,
+            tids: HashSet::new(),
+        }
+    }
+
+    /// Keep at most `max_bars` closed bars, dropping the oldest.
+    pub fn with_max_bars(mut self, max_bars: usize) -> Self {
+        self.max_bars = max_bars;
+        self.trim();
+        self
+    }
+
+    pub fn coin(&self) -> &str {
+        &self.coin
+    }
+
+    /// Rename the coin to the name the API uses for it
+    pub(crate) fn set_coin(&mut self, coin: String) {
+        self.coin = coin;
+    }
+
+    pub fn period(&self) -> BarPeriod {
+        self.period
+    }
+
+    /// Closed bars, oldest first.
+    pub fn bars(&self) -> &VecDeque<Bar> {
+        &self.bars
+    }
+
+    /// Most recent closed bar.
+    pub fn last_closed(&self) -> Option<&Bar> {
+        self.bars.back()
+    }
+
+    /// Bar still forming.
+    pub fn current(&self) -> Option<&Bar> {
+        self.current.as_ref()
+    }
+
+    /// Number of closed bars.
+    pub fn len(&self) -> usize {
+        self.bars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bars.is_empty() && self.current.is_none()
+    }
+
+    /// Replace the series with `candleSnapshot` history.
+    ///
+    /// The newest candle is taken to be still forming. A custom series merges
+    /// the candles into its own period and rebuilds the bar forming at seed
+    /// time from trades, so that bar only holds the trades seen afterwards.
+    pub fn seed(&mut self, candles: &[CandlesSnapshotResponse]) -> Result<()> {
+        let mut bars = candles
+            .iter()
+            .map(Bar::from_snapshot)
+            .collect::<Result<Vec<_>>>()?;
+        bars.sort_by_key(|bar| bar.time_open);
+
+        self.bars.clear();
+        self.current = None;
+        self.tids.clear();
+        match self.period {
+            BarPeriod::Interval(_) => {
+                self.current = bars.pop();
+                self.bars.extend(bars);
+            }
+            BarPeriod::Custom(period) => {
+                let Some(forming) = bars.pop() else {
+                    return Ok(());
+                };
+                let candle = forming.time_close + 1 - forming.time_open;
+                if period_ms(period) % candle != 0 {
+                    return Err(HyperliquidError::InvalidRequest(format!(
+                        "{}ms candles do not divide the {:?} bar period",
+                        candle, period
+                    )));
+                }
+                for bar in &bars {
+                    self.roll(bar);
+                }
+                // Candles already in the forming bar's period start it off
+                let time_open = forming.time_open - forming.time_open % period_ms(period);
+                if self
+                    .bars
+                    .back()
+                    .is_some_and(|last| last.time_open == time_open)
+                {
+                    self.current = self.bars.pop_back();
+                }
+                self.seeded_until = forming.time_open;
+            }
+        }
+        self.trim();
+        Ok(())
+    }
+
+    /// Seed with the last `lookback` of history from `info`.
+    ///
+    /// A custom series loads the longest candle interval that divides its
+    /// period, and fails if there is none.
+    pub async fn load_history(
+        &mut self,
+        info: &InfoProvider,
+        lookback: Duration,
+    ) -> Result<()> {
+        let interval = match self.period {
+            BarPeriod::Interval(interval) => interval,
+            BarPeriod::Custom(period) => CandleInterval::ALL
+                .into_iter()
+                .rev()
+                .filter(|interval| *interval != CandleInterval::OneMonth)
+                .find(|interval| period_ms(period) % period_ms(interval.duration()) == 0)
+                .ok_or_else(|| {
+                    HyperliquidError::InvalidRequest(format!(
+                        "no candle interval divides the {:?} bar period",
+                        period
+                    ))
+                })?,
+        };
+        let end = now_ms();
+        let start = end.saturating_sub(period_ms(lookback));
+        let candles = info
+            .candles(&self.coin)
+            .interval(interval)
+            .time_range(start, end)
+            .send()
+            .await?;
+        self.seed(&candles)
+    }
+
+    /// Apply a `candle` or `trades` message, returning the bars it closed.
+    pub fn apply_message(&mut self, message: &Message) -> Result<Vec<Bar>> {
+        match message {
+            Message::Candle(candle) => {
+                Ok(self.apply_candle(&candle.data)?.into_iter().collect())
+            }
+            Message::Trades(trades) => {
+                let mut closed = Vec::new();
+                for trade in &trades.data {
+                    closed.extend(self.apply_trade(trade)?);
+                }
+                Ok(closed)
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Apply an exchange candle update, returning the bar it closed.
+    ///
+    /// Ignored by custom series and for other coins or intervals. A late
+    /// update to the last closed bar, such as the exchange's final update after
+    /// [`close_expired`](Self::close_expired), amends it in place and returns
+    /// the amended bar when anything changed. Consumers should replace the bar
+    /// with the same `time_open`.
+    pub fn apply_candle(&mut self, candle: &CandleData) -> Result<Option<Bar>> {
+        let BarPeriod::Interval(interval) = self.period else {
+            return Ok(None);
+        };
+        if candle.coin != self.coin || candle.interval != interval.as_str() {
+            return Ok(None);
+        }
+        let bar = Bar::from_candle(candle)?;
+
+        if let Some(last) = self.bars.back_mut() {
+            if bar.time_open == last.time_open {
+                let amended = *last != bar;
+                *last = bar;
+                return Ok(amended.then_some(bar));
+            }
+            if bar.time_open < last.time_open {
+                return Ok(None);
+            }
+        }
+        match self.current {
+            Some(current) if bar.time_open > current.time_open => {
+                self.current = Some(bar);
+                Ok(Some(self.close(current)))
+            }
+            Some(current) if bar.time_open < current.time_open => Ok(None),
+            _ => {
+                self.current = Some(bar);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Roll a trade into a custom series, returning the bar it closed.
+    ///
+    /// Ignored by exchange-candle series, for other coins, and for trades
+    /// already counted.
+    pub fn apply_trade(&mut self, trade: &Trade) -> Result<Option<Bar>> {
+        let BarPeriod::Custom(period) = self.period else {
+            return Ok(None);
+        };
+        if trade.coin != self.coin || trade.time < self.seeded_until {
+            return Ok(None);
+        }
+        let px = trade.px.parse_f64("candle px")?;
+        let sz = trade.sz.parse_f64("candle sz")?;
+        let period = period_ms(period);
+        let time_open = trade.time - trade.time % period;
+        if self
+            .bars
+            .back()
+            .is_some_and(|last| time_open <= last.time_open)
+        {
+            return Ok(None);
+        }
+
+        let bar = Bar {
+            time_open,
+            time_close: time_open + period - 1,
+            open: px,
+            high: px,
+            low: px,
+            close: px,
+            volume: sz,
+            num_trades: 1,
+        };
+        match self.current.as_mut() {
+            Some(current) if time_open == current.time_open => {
+                if self.tids.insert(trade.tid) {
+                    current.merge(&bar);
+                }
+                Ok(None)
+            }
+            Some(current) if time_open < current.time_open => Ok(None),
+            _ => {
+                let closed = self.current.take().map(|current| self.close(current));
+                self.tids.clear();
+                self.tids.insert(trade.tid);
+                self.current = Some(bar);
+                Ok(closed)
+            }
+        }
+    }
+
+    /// Close the forming bar if it ended before `now` (ms since epoch).
+    ///
+    /// Bars otherwise only close when the next one starts, which can take a
+    /// while in a quiet market. The exchange may still send a final update for
+    /// the bar; [`apply_candle`](Self::apply_candle) returns it as an amendment.
+    pub fn close_expired(&mut self, now: u64) -> Option<Bar> {
+        let current = self.current.filter(|current| current.time_close < now)?;
+        self.current = None;
+        self.tids.clear();
+        Some(self.close(current))
+    }
+
+    /// Merge a seeded candle into the bar of the custom period containing it.
+    fn roll(&mut self, candle: &Bar) {
+        let BarPeriod::Custom(period) = self.period else {
+            return;
+        };
+        let period = period_ms(period);
+        let time_open = candle.time_open - candle.time_open % period;
+        match self.bars.back_mut() {
+            Some(last) if last.time_open == time_open => last.merge(candle),
+            _ => self.bars.push_back(Bar {
+                time_open,
+                time_close: time_open + period - 1,
+                ..*candle
+            }),
+        }
+    }
+
+    fn close(&mut self, bar: Bar) -> Bar {
+        self.bars.push_back(bar);
+        self.trim();
+        bar
+    }
+
+    fn trim(&mut self) {
+        while self.bars.len() > self.max_bars {
+            self.bars.pop_front();
+        }
+    }
+}
+
+impl Queued for Bar {
+    fn conflation_key(&self) -> Option<crate::types::ws::RoutingKey> {
+        None
+    }
+}
+
+/// Live [`CandleSeries`] with a stream of the bars it closes.
+///
+/// Closed bars are queued like subscription messages, up to the provider's
+/// `channel_capacity` and subject to its [`OverflowPolicy`](crate::providers::OverflowPolicy).
+pub struct CandleStream {
+    series: watch::Receiver<CandleSeries>,
+    closed: SubscriptionReceiver<Bar>,
+}
+
+impl CandleStream {
+    pub(crate) fn new(
+        series: watch::Receiver<CandleSeries>,
+        closed: SubscriptionReceiver<Bar>,
+    ) -> Self {
+        Self { series, closed }
+    }
+
+    /// Latest state of the series
+    pub fn series(&self) -> CandleSeries {
+        self.series.borrow().clone()
+    }
+
+    /// Watch every change to the series, including the forming bar
+    pub fn subscribe(&self) -> watch::Receiver<CandleSeries> {
+        self.series.clone()
+    }
+
+    /// Next closed or amended bar; `None` once the feed has stopped
+    pub async fn next_bar(&mut self) -> Option<Bar> {
+        self.closed.recv().await
+    }
+
+    /// Closed bars discarded by the overflow policy so far
+    pub fn dropped(&self) -> u64 {
+        self.closed.dropped()
+    }
+}
+
+fn period_ms(period: Duration) -> u64 {
+    period.as_millis() as u64
+}
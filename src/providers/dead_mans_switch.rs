@@ -0,0 +1,331 @@
+//! Dead man's switch built on `scheduleCancel`.
+//!
+//! The switch keeps a cancel-all scheduled a short horizon ahead and pushes it
+//! back on every tick. When the process stops looking healthy it simply stops
+//! pushing, and the exchange cancels all open orders once the horizon passes.
+//!
+//! # Example
+//! ```ignore
+//! let switch = DeadMansSwitch::builder(exchange.clone())
+//!     .with_rearm_interval(Duration::from_secs(10))
+//!     .with_horizon(Duration::from_secs(60))
+//!     .with_health_check(move || strategy_alive.load(Ordering::Relaxed))
+//!     .with_websocket(ws.clone(), Duration::from_secs(30))
+//!     .start()?;
+//!
+//! // ...
+//! switch.shutdown().await?;
+//! ```
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use tokio::{
+    sync::{watch, Mutex},
+    task::JoinHandle,
+};
+
+use crate::{
+    errors::{HyperliquidError, RejectReason},
+    providers::{exchange::RawExchangeProvider, websocket::ManagedWsProvider},
+    signers::HyperliquidSigner,
+    types::responses::ExchangeResponseStatus,
+    utils::now_ms,
+};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// The exchange rejects cancel times closer than this
+const MIN_HORIZON: Duration = Duration::from_secs(5);
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Configuration for [`DeadMansSwitch`]
+#[derive(Clone, Debug)]
+pub struct DeadMansSwitchConfig {
+    /// How often the scheduled cancel is pushed back
+    pub rearm_interval: Duration,
+    /// How far ahead each re-arm schedules the cancel; at least 5 seconds and
+    /// longer than `rearm_interval`
+    pub horizon: Duration,
+    /// How long the WebSocket may stay disconnected before re-arming stops
+    pub max_ws_disconnect: Duration,
+}
+
+impl Default for DeadMansSwitchConfig {
+    fn default() -> Self {
+        Self {
+            rearm_interval: Duration::from_secs(15),
+            horizon: Duration::from_secs(60),
+            max_ws_disconnect: Duration::from_secs(30),
+        }
+    }
+}
+
+/// What the switch is currently doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchStatus {
+    /// Not running; no cancel scheduled by the switch
+    Disarmed,
+    /// Re-arming on schedule
+    Armed,
+    /// Re-arming stopped because the health check failed
+    Unhealthy,
+    /// Re-arming stopped because the WebSocket stayed disconnected
+    Disconnected,
+    /// The daily trigger limit is reached; re-arming resumes after 00:00 UTC
+    TriggerLimitReached,
+}
+
+/// Snapshot of the switch's state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitchState {
+    pub status: SwitchStatus,
+    /// When the last scheduled cancel fires (ms since epoch), if one is pending
+    pub cancel_at: Option<u64>,
+    /// The last failed re-arm, cleared by the next successful one
+    pub last_error: Option<String>,
+}
+
+type HealthCheck = Arc<dyn Fn() -> bool + Send + Sync>;
+
+/// Builder for [`DeadMansSwitch`]
+pub struct DeadMansSwitchBuilder<S: HyperliquidSigner> {
+    exchange: Arc<RawExchangeProvider<S>>,
+    config: DeadMansSwitchConfig,
+    health_check: Option<HealthCheck>,
+    websocket: Option<Arc<ManagedWsProvider>>,
+}
+
+impl<S: HyperliquidSigner + 'static> DeadMansSwitchBuilder<S> {
+    /// Replace the whole configuration
+    pub fn with_config(mut self, config: DeadMansSwitchConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// How often the scheduled cancel is pushed back
+    pub fn with_rearm_interval(mut self, interval: Duration) -> Self {
+        self.config.rearm_interval = interval;
+        self
+    }
+
+    /// How far ahead each re-arm schedules the cancel
+    pub fn with_horizon(mut self, horizon: Duration) -> Self {
+        self.config.horizon = horizon;
+        self
+    }
+
+    /// Stop re-arming while `check` returns false
+    pub fn with_health_check<F>(mut self, check: F) -> Self
+    where
+        F: Fn() -> bool + Send + Sync + 'static,
+    {
+        self.health_check = Some(Arc::new(check));
+        self
+    }
+
+    /// Stop re-arming once `ws` has been disconnected for `max_disconnect`
+    pub fn with_websocket(
+        mut self,
+        ws: Arc<ManagedWsProvider>,
+        max_disconnect: Duration,
+    ) -> Self {
+        self.websocket = Some(ws);
+        self.config.max_ws_disconnect = max_disconnect;
+        self
+    }
+
+    /// Arm the switch and start re-arming in the background.
+    pub fn start(self) -> Result<DeadMansSwitch<S>> {
+        let config = &self.config;
+        if config.horizon < MIN_HORIZON || config.horizon <= config.rearm_interval {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "horizon {:?} must be at least {:?} and longer than the re-arm interval {:?}",
+                config.horizon, MIN_HORIZON, config.rearm_interval
+            )));
+        }
+
+        let (state_tx, _) = watch::channel(SwitchState {
+            status: SwitchStatus::Disarmed,
+            cancel_at: None,
+            last_error: None,
+        });
+        let state = Arc::new(state_tx);
+        let task = Rearm {
+            exchange: self.exchange.clone(),
+            config: self.config,
+            health_check: self.health_check,
+            websocket: self.websocket,
+            state: state.clone(),
+        };
+        let handle = tokio::spawn(task.run());
+
+        Ok(DeadMansSwitch {
+            exchange: self.exchange,
+            state,
+            handle: Mutex::new(Some(handle)),
+        })
+    }
+}
+
+/// Background task keeping `scheduleCancel` armed while the process is healthy.
+///
+/// Dropping the switch stops re-arming without disarming, so open orders are
+/// cancelled once the horizon passes; call [`shutdown`](Self::shutdown) to
+/// remove the scheduled cancel instead.
+pub struct DeadMansSwitch<S: HyperliquidSigner> {
+    exchange: Arc<RawExchangeProvider<S>>,
+    state: Arc<watch::Sender<SwitchState>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl<S: HyperliquidSigner + 'static> DeadMansSwitch<S> {
+    /// Create a builder re-arming through `exchange`
+    pub fn builder(exchange: Arc<RawExchangeProvider<S>>) -> DeadMansSwitchBuilder<S> {
+        DeadMansSwitchBuilder {
+            exchange,
+            config: DeadMansSwitchConfig::default(),
+            health_check: None,
+            websocket: None,
+        }
+    }
+
+    /// Current state
+    pub fn state(&self) -> SwitchState {
+        self.state.borrow().clone()
+    }
+
+    /// Watch state changes
+    pub fn subscribe(&self) -> watch::Receiver<SwitchState> {
+        self.state.subscribe()
+    }
+
+    /// Stop re-arming and remove the scheduled cancel.
+    pub async fn shutdown(&self) -> Result<()> {
+        if let Some(handle) = self.handle.lock().await.take() {
+            handle.abort();
+            let _ = handle.await;
+        }
+
+        match self.exchange.schedule_cancel(None).await? {
+            ExchangeResponseStatus::Ok(_) => {
+                self.state.send_replace(SwitchState {
+                    status: SwitchStatus::Disarmed,
+                    cancel_at: None,
+                    last_error: None,
+                });
+                Ok(())
+            }
+            ExchangeResponseStatus::Err(message) => {
+                self.state
+                    .send_modify(|state| state.last_error = Some(message.clone()));
+                Err(HyperliquidError::rejected(message))
+            }
+        }
+    }
+}
+
+impl<S: HyperliquidSigner> Drop for DeadMansSwitch<S> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.get_mut().take() {
+            handle.abort();
+        }
+    }
+}
+
+struct Rearm<S: HyperliquidSigner> {
+    exchange: Arc<RawExchangeProvider<S>>,
+    config: DeadMansSwitchConfig,
+    health_check: Option<HealthCheck>,
+    websocket: Option<Arc<ManagedWsProvider>>,
+    state: Arc<watch::Sender<SwitchState>>,
+}
+
+impl<S: HyperliquidSigner> Rearm<S> {
+    async fn run(self) {
+        let mut interval = tokio::time::interval(self.config.rearm_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut disconnected_since: Option<Instant> = None;
+        let mut limit_until: Option<u64> = None;
+
+        loop {
+            interval.tick().await;
+            let now = now_ms();
+
+            if limit_until.is_some_and(|until| now < until) {
+                continue;
+            }
+            limit_until = None;
+
+            // Let the pending cancel fire rather than pushing it back
+            if let Some(status) = self.tripped(&mut disconnected_since).await {
+                if self.state.borrow().status != status {
+                    tracing::warn!(?status, "dead man's switch stopped re-arming");
+                }
+                self.state.send_modify(|state| state.status = status);
+                continue;
+            }
+
+            let cancel_at = now + self.config.horizon.as_millis() as u64;
+            let error = match self.exchange.schedule_cancel(Some(cancel_at)).await {
+                Ok(ExchangeResponseStatus::Ok(_)) => {
+                    self.state.send_replace(SwitchState {
+                        status: SwitchStatus::Armed,
+                        cancel_at: Some(cancel_at),
+                        last_error: None,
+                    });
+                    continue;
+                }
+                Ok(ExchangeResponseStatus::Err(message)) => {
+                    HyperliquidError::rejected(message)
+                }
+                Err(e) => e,
+            };
+
+            if let HyperliquidError::Rejected {
+                reason: RejectReason::ScheduleCancelLimit,
+                ..
+            } = error
+            {
+                // Trigger counts reset at 00:00 UTC
+                let reset = (now / DAY_MS + 1) * DAY_MS;
+                tracing::warn!(reset, "scheduled cancel trigger limit reached");
+                limit_until = Some(reset);
+                self.state.send_modify(|state| {
+                    state.status = SwitchStatus::TriggerLimitReached;
+                    state.last_error = Some(error.to_string());
+                });
+            } else {
+                tracing::warn!(%error, "failed to re-arm scheduled cancel");
+                self.state
+                    .send_modify(|state| state.last_error = Some(error.to_string()));
+            }
+        }
+    }
+
+    /// Why re-arming should stop, if it should
+    async fn tripped(
+        &self,
+        disconnected_since: &mut Option<Instant>,
+    ) -> Option<SwitchStatus> {
+        if let Some(check) = &self.health_check {
+            if !check() {
+                return Some(SwitchStatus::Unhealthy);
+            }
+        }
+
+        if let Some(ws) = &self.websocket {
+            if ws.is_connected().await {
+                *disconnected_since = None;
+            } else {
+                let since = disconnected_since.get_or_insert_with(Instant::now);
+                if since.elapsed() >= self.config.max_ws_disconnect {
+                    return Some(SwitchStatus::Disconnected);
+                }
+            }
+        }
+        None
+    }
+}
@@ -3,19 +3,26 @@
 use uuid::Uuid;
 
 use crate::{
-    constants::TIF_GTC,
+    constants::{TIF_GTC, TIF_IOC, TPSL_SL, TPSL_TP},
     errors::HyperliquidError,
     signers::HyperliquidSigner,
     types::{
-        requests::{Limit, OrderRequest, OrderType, Trigger},
+        requests::{Grouping, Limit, OrderRequest, OrderType, Trigger},
         responses::ExchangeResponseStatus,
+        Symbol,
     },
 };
 
-use super::{format_float_string, RawExchangeProvider};
+use super::{round_order, rounding::canonical_order, RawExchangeProvider, RoundingMode};
 
 type Result<T> = std::result::Result<T, HyperliquidError>;
 
+/// Asset an order targets, resolved to an id when the order is built
+enum AssetTarget {
+    Id(u32),
+    Symbol(Symbol),
+}
+
 /// Builder pattern for constructing orders fluently.
 ///
 /// # Example
@@ -27,21 +34,43 @@ type Result<T> = std::result::Result<T, HyperliquidError>;
 ///     .size("0.1")
 ///     .send()
 ///     .await?;
+///
+/// // With an asset registry attached, by symbol
+/// let response = provider.order_for("ETH").limit_buy("3000", "0.5").send().await?;
 /// ```
 pub struct OrderBuilder<'a, S: HyperliquidSigner> {
     provider: &'a RawExchangeProvider<S>,
-    asset: u32,
+    asset: AssetTarget,
     is_buy: Option<bool>,
     limit_px: Option<String>,
     sz: Option<String>,
     reduce_only: bool,
     order_type: Option<OrderType>,
     cloid: Option<Uuid>,
+    rounding: Option<RoundingMode>,
+    slippage: Option<f64>,
+    take_profit: Option<String>,
+    stop_loss: Option<String>,
+    position_tpsl: bool,
 }
 
 impl<'a, S: HyperliquidSigner> OrderBuilder<'a, S> {
     /// Create a new order builder for the given asset.
     pub fn new(provider: &'a RawExchangeProvider<S>, asset: u32) -> Self {
+        Self::with_target(provider, AssetTarget::Id(asset))
+    }
+
+    /// Create a new order builder for a symbol.
+    ///
+    /// The symbol is resolved through the provider's asset registry.
+    pub fn for_symbol(
+        provider: &'a RawExchangeProvider<S>,
+        symbol: impl Into<Symbol>,
+    ) -> Self {
+        Self::with_target(provider, AssetTarget::Symbol(symbol.into()))
+    }
+
+    fn with_target(provider: &'a RawExchangeProvider<S>, asset: AssetTarget) -> Self {
         Self {
             provider,
             asset,
@@ -51,6 +80,11 @@ impl<'a, S: HyperliquidSigner> OrderBuilder<'a, S> {
             reduce_only: false,
             order_type: None,
             cloid: None,
+            rounding: None,
+            slippage: None,
+            take_profit: None,
+            stop_loss: None,
+            position_tpsl: false,
         }
     }
 
@@ -96,6 +130,12 @@ impl<'a, S: HyperliquidSigner> OrderBuilder<'a, S> {
         self
     }
 
+    /// Override the provider's rounding mode for this order.
+    pub fn rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = Some(mode);
+        self
+    }
+
     /// Convenience method for creating a limit buy order.
     pub fn limit_buy(self, price: impl ToString, size: impl ToString) -> Self {
         self.buy().limit_px(price).size(size)
@@ -106,6 +146,30 @@ impl<'a, S: HyperliquidSigner> OrderBuilder<'a, S> {
         self.sell().limit_px(price).size(size)
     }
 
+    /// Convenience method for creating a market buy order.
+    ///
+    /// The limit price is fetched by [`send`](Self::send): the best ask (or the
+    /// mid) raised by `slippage`, e.g. `0.01` for 1%, and sent as IOC. Requires an
+    /// asset registry.
+    pub fn market_buy(self, size: impl ToString, slippage: f64) -> Self {
+        self.buy().size(size).market(slippage)
+    }
+
+    /// Convenience method for creating a market sell order.
+    ///
+    /// Priced like [`market_buy`](Self::market_buy) from the best bid lowered by
+    /// `slippage`.
+    pub fn market_sell(self, size: impl ToString, slippage: f64) -> Self {
+        self.sell().size(size).market(slippage)
+    }
+
+    fn market(mut self, slippage: f64) -> Self {
+        self.slippage = Some(slippage);
+        self.order_type(OrderType::Limit(Limit {
+            tif: TIF_IOC.to_string(),
+        }))
+    }
+
     /// Convenience method for creating a trigger buy order.
     pub fn trigger_buy(
         self,
@@ -142,43 +206,207 @@ impl<'a, S: HyperliquidSigner> OrderBuilder<'a, S> {
             }))
     }
 
+    /// Attach a take-profit at `trigger_px` to this entry order.
+    ///
+    /// The entry is then sent together with a reduce-only market trigger on the
+    /// opposite side for the same size, grouped as [`Grouping::NormalTpsl`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let response = provider
+    ///     .order_for("ETH")
+    ///     .limit_buy("3000", "0.5")
+    ///     .with_take_profit("3300")
+    ///     .with_stop_loss("2850")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn with_take_profit(mut self, trigger_px: impl ToString) -> Self {
+        self.take_profit = Some(trigger_px.to_string());
+        self
+    }
+
+    /// Attach a stop-loss at `trigger_px` to this entry order.
+    ///
+    /// See [`with_take_profit`](Self::with_take_profit).
+    pub fn with_stop_loss(mut self, trigger_px: impl ToString) -> Self {
+        self.stop_loss = Some(trigger_px.to_string());
+        self
+    }
+
+    /// Attach the take-profit and stop-loss to the existing position instead of
+    /// a new entry ([`Grouping::PositionTpsl`]).
+    ///
+    /// Side and size describe the position, e.g. `buy()` for a long; no entry
+    /// order is sent and no limit price is needed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let response = provider
+    ///     .order_for("ETH")
+    ///     .buy()
+    ///     .size("0.5")
+    ///     .position_tpsl()
+    ///     .with_stop_loss("2850")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn position_tpsl(mut self) -> Self {
+        self.position_tpsl = true;
+        self
+    }
+
     /// Build the order request without sending it.
+    ///
+    /// With an asset registry attached, prices and size are fitted to the asset's
+    /// tick and lot rules (see [`RoundingMode`]). Assets are looked up in the
+    /// registry's current mapping without refreshing it; [`send`](Self::send)
+    /// loads missing metadata first.
+    ///
+    /// Orders with a take-profit or stop-loss are built with
+    /// [`build_grouped`](Self::build_grouped).
     pub fn build(self) -> Result<OrderRequest> {
-        let limit_px = self.limit_px.ok_or(HyperliquidError::InvalidRequest(
-            "limit_px must be specified".to_string(),
-        ))?;
+        if self.take_profit.is_some() || self.stop_loss.is_some() || self.position_tpsl {
+            return Err(HyperliquidError::InvalidRequest(
+                "orders with a take-profit or stop-loss are built with build_grouped()"
+                    .to_string(),
+            ));
+        }
+        let (mut orders, _) = self.build_grouped()?;
+        Ok(orders.remove(0))
+    }
+
+    /// Build the entry order (unless [`position_tpsl`](Self::position_tpsl) is
+    /// set) followed by its take-profit and stop-loss, with their grouping.
+    pub fn build_grouped(self) -> Result<(Vec<OrderRequest>, Grouping)> {
+        let (asset, asset_info) = match &self.asset {
+            AssetTarget::Id(asset) => (
+                *asset,
+                self.provider
+                    .asset_registry()
+                    .and_then(|registry| registry.by_asset(*asset)),
+            ),
+            AssetTarget::Symbol(symbol) => {
+                let info = self
+                    .provider
+                    .require_asset_registry()?
+                    .get(symbol)
+                    .ok_or_else(|| HyperliquidError::AssetNotFound(symbol.to_string()))?;
+                (info.asset, Some(info))
+            }
+        };
+
+        let limit_px = match (self.limit_px, self.position_tpsl) {
+            (_, true) => None,
+            (Some(limit_px), false) => Some(limit_px),
+            (None, false) => {
+                return Err(HyperliquidError::InvalidRequest(
+                    if self.slippage.is_some() {
+                        "market order prices are fetched by send()"
+                    } else {
+                        "limit_px must be specified"
+                    }
+                    .to_string(),
+                ))
+            }
+        };
         let sz = self.sz.ok_or(HyperliquidError::InvalidRequest(
             "sz must be specified".to_string(),
         ))?;
+        let is_buy = self.is_buy.ok_or(HyperliquidError::InvalidRequest(
+            "is_buy must be specified".to_string(),
+        ))?;
+
+        let mut orders = Vec::new();
+        if let Some(limit_px) = limit_px {
+            orders.push(OrderRequest {
+                asset,
+                is_buy,
+                limit_px,
+                sz: sz.clone(),
+                reduce_only: self.reduce_only,
+                order_type: self.order_type.unwrap_or(OrderType::Limit(Limit {
+                    tif: TIF_GTC.to_string(),
+                })),
+                cloid: self.cloid.map(|id| format!("{:032x}", id.as_u128())),
+            });
+        }
 
-        // Parse and format the prices to match API expectations
-        let limit_px_f64 = limit_px.parse::<f64>().map_err(|_| {
-            HyperliquidError::InvalidRequest("Invalid limit_px format".to_string())
-        })?;
-        let sz_f64 = sz.parse::<f64>().map_err(|_| {
-            HyperliquidError::InvalidRequest("Invalid sz format".to_string())
-        })?;
-
-        Ok(OrderRequest {
-            asset: self.asset,
-            is_buy: self.is_buy.ok_or(HyperliquidError::InvalidRequest(
-                "is_buy must be specified".to_string(),
-            ))?,
-            limit_px: format_float_string(limit_px_f64),
-            sz: format_float_string(sz_f64),
-            reduce_only: self.reduce_only,
-            order_type: self.order_type.unwrap_or(OrderType::Limit(Limit {
-                tif: TIF_GTC.to_string(),
-            })),
-            cloid: self.cloid.map(|id| format!("{:032x}", id.as_u128())),
-        })
-    }
-
-    /// Build and send the order.
-    pub async fn send(self) -> Result<ExchangeResponseStatus> {
+        for (trigger_px, tpsl) in [(self.take_profit, TPSL_TP), (self.stop_loss, TPSL_SL)]
+        {
+            let Some(trigger_px) = trigger_px else {
+                continue;
+            };
+            orders.push(OrderRequest {
+                asset,
+                is_buy: !is_buy,
+                limit_px: trigger_px.clone(),
+                sz: sz.clone(),
+                reduce_only: true,
+                order_type: OrderType::Trigger(Trigger {
+                    is_market: true,
+                    trigger_px,
+                    tpsl: tpsl.to_string(),
+                }),
+                cloid: None,
+            });
+        }
+
+        let grouping = if self.position_tpsl {
+            if orders.is_empty() {
+                return Err(HyperliquidError::InvalidRequest(
+                    "position_tpsl needs a take-profit or stop-loss".to_string(),
+                ));
+            }
+            Grouping::PositionTpsl
+        } else if orders.len() > 1 {
+            Grouping::NormalTpsl
+        } else {
+            Grouping::Na
+        };
+
+        let orders = match asset_info {
+            Some(info) => {
+                let mode = self.rounding.unwrap_or(self.provider.rounding());
+                orders
+                    .iter()
+                    .map(|order| round_order(order, &info, mode))
+                    .collect::<Result<_>>()?
+            }
+            None => orders.iter().map(canonical_order).collect::<Result<_>>()?,
+        };
+        Ok((orders, grouping))
+    }
+
+    /// Build and send the order, with its take-profit and stop-loss if set.
+    ///
+    /// Market orders are priced here from the current book.
+    pub async fn send(mut self) -> Result<ExchangeResponseStatus> {
         let provider = self.provider;
-        let order = self.build()?;
-        provider.place_order(&order).await
+        // Load the asset's rules so build_grouped() can apply them
+        if let Some(registry) = provider.asset_registry() {
+            let info = match &self.asset {
+                AssetTarget::Id(asset) => registry.resolve_id(*asset).await?,
+                AssetTarget::Symbol(symbol) => registry.resolve(symbol).await?,
+            };
+            if let (Some(slippage), false) = (self.slippage, self.position_tpsl) {
+                let is_buy = self.is_buy.ok_or(HyperliquidError::InvalidRequest(
+                    "is_buy must be specified".to_string(),
+                ))?;
+                let px = provider.market_price(&info, is_buy, slippage).await?;
+                self.limit_px = Some(px);
+            }
+            self.asset = AssetTarget::Id(info.asset);
+        } else if self.slippage.is_some() {
+            provider.require_asset_registry()?;
+        }
+
+        let (mut orders, grouping) = self.build_grouped()?;
+        match grouping {
+            // build_grouped() already applied the tick and lot rules
+            Grouping::Na => provider.place_rounded_order(orders.remove(0)).await,
+            _ => provider.bulk_rounded_orders(orders, grouping).await,
+        }
     }
 }
 
@@ -198,4 +426,19 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
     pub fn order(&self, asset: u32) -> OrderBuilder<'_, S> {
         OrderBuilder::new(self, asset)
     }
+
+    /// Create an order builder for a symbol, resolved through the asset registry.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let provider = provider.with_asset_registry(registry);
+    /// let response = provider
+    ///     .order_for("HYPE/USDC")
+    ///     .limit_buy("20", "5")
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn order_for(&self, symbol: impl Into<Symbol>) -> OrderBuilder<'_, S> {
+        OrderBuilder::for_symbol(self, symbol)
+    }
 }
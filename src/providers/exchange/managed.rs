@@ -1,17 +1,19 @@
 //! Managed exchange provider with safety features and optimizations.
 
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
-use alloy::primitives::Address;
-use tokio::sync::Mutex as TokioMutex;
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
+use tokio::sync::{Mutex as TokioMutex, RwLock};
 
 use crate::{
     constants::Network,
     errors::HyperliquidError,
     providers::{
-        agent::{AgentConfig, AgentManager, AgentWallet},
+        agent::{AgentConfig, AgentLease, AgentManager, AgentWallet},
+        asset_registry::AssetRegistry,
         batcher::{BatchConfig, OrderBatcher, OrderHandle},
         nonce::NonceManager,
+        transport::Transport,
     },
     signers::HyperliquidSigner,
     types::{
@@ -20,10 +22,13 @@ use crate::{
     },
 };
 
-use super::RawExchangeProvider;
+use super::{RawExchangeProvider, RoundingMode};
 
 type Result<T> = std::result::Result<T, HyperliquidError>;
 
+/// Name of the agent used when none is given to the builder
+const DEFAULT_AGENT_NAME: &str = "default";
+
 /// Configuration for managed exchange provider.
 #[derive(Clone, Debug)]
 pub struct ManagedExchangeConfig {
@@ -37,7 +42,8 @@ pub struct ManagedExchangeConfig {
     /// Agent configuration
     pub agent_config: AgentConfig,
 
-    /// Nonce isolation per subaccount
+    /// Keep a separate nonce sequence per signing address instead of one
+    /// sequence shared by every signer
     pub isolate_subaccount_nonces: bool,
 
     /// Safety features
@@ -59,10 +65,75 @@ impl Default for ManagedExchangeConfig {
     }
 }
 
+/// Sends actions signed by the active agent, or by the master signer when
+/// agents are disabled.
+struct ActionRouter<S: HyperliquidSigner> {
+    inner: Arc<RawExchangeProvider<S>>,
+    agents: Option<Arc<AgentManager<S>>>,
+    agent_name: String,
+    /// Provider signing with the current agent, rebuilt after rotation
+    agent_exchange: RwLock<Option<Arc<RawExchangeProvider<PrivateKeySigner>>>>,
+}
+
+impl<S: HyperliquidSigner + Clone + 'static> ActionRouter<S> {
+    /// Lease the active agent and its provider, `None` without agents
+    async fn agent(
+        &self,
+    ) -> Result<Option<(AgentLease, Arc<RawExchangeProvider<PrivateKeySigner>>)>> {
+        let Some(agents) = &self.agents else {
+            return Ok(None);
+        };
+        let lease = agents.lease(&self.agent_name).await?;
+
+        let current = self.agent_exchange.read().await.clone();
+        let exchange = match current {
+            Some(exchange) if exchange.signer_address() == lease.wallet.address => {
+                exchange
+            }
+            _ => {
+                let exchange = Arc::new(self.inner.with_agent_signer(
+                    lease.wallet.signer.clone(),
+                    lease.wallet.nonce_manager.clone(),
+                ));
+                *self.agent_exchange.write().await = Some(exchange.clone());
+                exchange
+            }
+        };
+        Ok(Some((lease, exchange)))
+    }
+
+    async fn place_order(&self, order: &OrderRequest) -> Result<ExchangeResponseStatus> {
+        match self.agent().await? {
+            Some((_lease, exchange)) => exchange.place_order(order).await,
+            None => self.inner.place_order(order).await,
+        }
+    }
+
+    async fn bulk_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Result<ExchangeResponseStatus> {
+        match self.agent().await? {
+            Some((_lease, exchange)) => exchange.bulk_orders(orders).await,
+            None => self.inner.bulk_orders(orders).await,
+        }
+    }
+
+    async fn bulk_cancel(
+        &self,
+        cancels: Vec<CancelRequest>,
+    ) -> Result<ExchangeResponseStatus> {
+        match self.agent().await? {
+            Some((_lease, exchange)) => exchange.bulk_cancel(cancels).await,
+            None => self.inner.bulk_cancel(cancels).await,
+        }
+    }
+}
+
 /// Managed exchange provider with safety features and optimizations.
 ///
 /// This provider wraps `RawExchangeProvider` and adds:
-/// - Automatic agent rotation for security
+/// - Orders signed by a rotated agent wallet instead of the master key
 /// - Order batching for performance
 /// - Nonce management for correctness
 ///
@@ -81,7 +152,11 @@ pub struct ManagedExchangeProvider<S: HyperliquidSigner> {
     /// Agent manager for lifecycle
     agent_manager: Option<Arc<AgentManager<S>>>,
 
-    /// Nonce tracking
+    /// Signs with the active agent
+    router: Arc<ActionRouter<S>>,
+    rotation_handle: Option<Arc<TokioMutex<Option<tokio::task::JoinHandle<()>>>>>,
+
+    /// Nonce source shared with the inner provider
     nonce_manager: Arc<NonceManager>,
 
     /// Order batching
@@ -115,51 +190,27 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProvider<S> {
     }
 
     /// Place an order with all managed features.
+    ///
+    /// With agent rotation enabled the order is signed by the active agent,
+    /// which is approved on first use and replaced before it expires.
     pub async fn place_order(&self, order: &OrderRequest) -> Result<OrderHandle> {
-        // Get nonce based on configuration
-        let nonce = if self.config.auto_rotate_agents {
-            if let Some(agent_mgr) = &self.agent_manager {
-                let agent = agent_mgr.get_or_rotate_agent("default").await?;
-                // Use agent's nonce
-                agent.next_nonce()
-            } else {
-                // Fallback to regular nonce
-                self.nonce_manager.next_nonce(None)
-            }
-        } else {
-            // Not using agents, use regular nonce
-            if self.config.isolate_subaccount_nonces {
-                // For subaccounts, we'd need to extract the address from somewhere
-                // For now, just use global nonce
-                self.nonce_manager.next_nonce(None)
-            } else {
-                self.nonce_manager.next_nonce(None)
-            }
-        };
-
-        // Check nonce validity
-        if !NonceManager::is_valid_nonce(nonce) {
-            return Err(HyperliquidError::InvalidRequest(
-                "Generated nonce is outside valid time bounds".to_string(),
-            ));
-        }
-
-        // For now, we always use the main provider
-        // In a full implementation, we'd need to handle agent signing differently
-        // This is a limitation of the current design where we can't easily swap signers
-
         // Batch or direct execution
         if self.config.batch_orders {
             if let Some(batcher) = &self.batcher {
+                // Queued orders are ordered by nonce; the batch itself is signed
+                // with a fresh nonce when it is flushed
+                let nonce = self
+                    .nonce_manager
+                    .next_nonce(Some(self.inner.signer_address()));
                 Ok(batcher.add_order(order.clone(), nonce).await)
             } else {
                 // Fallback to direct
-                let result = self.inner.place_order(order).await?;
+                let result = self.router.place_order(order).await?;
                 Ok(OrderHandle::Immediate(Ok(result)))
             }
         } else {
             // Direct execution
-            let result = self.inner.place_order(order).await?;
+            let result = self.router.place_order(order).await?;
             Ok(OrderHandle::Immediate(Ok(result)))
         }
     }
@@ -169,7 +220,7 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProvider<S> {
         &self,
         order: &OrderRequest,
     ) -> Result<ExchangeResponseStatus> {
-        self.inner.place_order(order).await
+        self.router.place_order(order).await
     }
 
     /// Access the raw provider for advanced usage.
@@ -188,8 +239,11 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProvider<S> {
 
     /// Shutdown the managed provider cleanly.
     pub async fn shutdown(self: Arc<Self>) {
-        // Stop batcher if running
-        if let Some(handle_mutex) = &self.batcher_handle {
+        // Stop batcher and agent rotation if running
+        for handle_mutex in [&self.batcher_handle, &self.rotation_handle]
+            .into_iter()
+            .flatten()
+        {
             if let Some(handle) = handle_mutex.lock().await.take() {
                 handle.abort();
             }
@@ -205,6 +259,10 @@ pub struct ManagedExchangeProviderBuilder<S: HyperliquidSigner> {
     vault_address: Option<Address>,
     initial_agent: Option<String>,
     builder_address: Option<Address>,
+    asset_registry: Option<Arc<AssetRegistry>>,
+    rounding: RoundingMode,
+    nonce_manager: Option<Arc<NonceManager>>,
+    transport: Option<Arc<dyn Transport>>,
 }
 
 impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProviderBuilder<S> {
@@ -216,6 +274,10 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProviderBuilder<S> {
             vault_address: None,
             initial_agent: None,
             builder_address: None,
+            asset_registry: None,
+            rounding: RoundingMode::default(),
+            nonce_manager: None,
+            transport: None,
         }
     }
 
@@ -239,6 +301,21 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProviderBuilder<S> {
         self
     }
 
+    /// Replace the agent configuration, enabling agent rotation.
+    pub fn with_agent_config(mut self, config: AgentConfig) -> Self {
+        self.config.auto_rotate_agents = true;
+        self.config.agent_config = config;
+        self
+    }
+
+    /// Keep agent keys in `path` so restarts reuse the approved agents.
+    ///
+    /// Keys are stored unencrypted; keep the file private.
+    pub fn with_agent_key_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.agent_config.key_file = Some(path.into());
+        self
+    }
+
     /// Start with an agent.
     pub fn with_agent(mut self, name: Option<String>) -> Self {
         self.initial_agent = name;
@@ -258,6 +335,34 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProviderBuilder<S> {
         self
     }
 
+    /// Resolve symbols to asset ids through a shared registry.
+    pub fn with_asset_registry(mut self, registry: Arc<AssetRegistry>) -> Self {
+        self.asset_registry = Some(registry);
+        self
+    }
+
+    /// Choose how order prices and sizes are fitted to the tick and lot rules.
+    pub fn with_rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = mode;
+        self
+    }
+
+    /// Send requests through a custom transport (proxy, mTLS, in-memory mock, ...).
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Draw nonces from a shared manager, e.g. one per signing key across
+    /// providers for several vaults or sub-accounts.
+    ///
+    /// Without this the provider creates its own manager, isolated per address
+    /// according to [`ManagedExchangeConfig::isolate_subaccount_nonces`].
+    pub fn with_nonce_manager(mut self, nonce_manager: Arc<NonceManager>) -> Self {
+        self.nonce_manager = Some(nonce_manager);
+        self
+    }
+
     /// Disable agent rotation.
     pub fn without_agent_rotation(mut self) -> Self {
         self.config.auto_rotate_agents = false;
@@ -266,44 +371,75 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProviderBuilder<S> {
 
     /// Build the provider.
     pub async fn build(self) -> Result<Arc<ManagedExchangeProvider<S>>> {
+        // Create nonce manager, shared with the raw provider for signing
+        let nonce_manager = self.nonce_manager.unwrap_or_else(|| {
+            Arc::new(NonceManager::new(self.config.isolate_subaccount_nonces))
+        });
+
         // Create raw provider
-        let raw = match self.network {
-            Network::Mainnet => {
-                if let Some(vault) = self.vault_address {
-                    RawExchangeProvider::mainnet_vault(self.signer.clone(), vault)
-                } else if let Some(builder) = self.builder_address {
-                    RawExchangeProvider::mainnet_builder(self.signer.clone(), builder)
-                } else {
-                    RawExchangeProvider::mainnet(self.signer.clone())
-                }
-            }
-            Network::Testnet => {
-                if let Some(vault) = self.vault_address {
-                    RawExchangeProvider::testnet_vault(self.signer.clone(), vault)
-                } else if let Some(builder) = self.builder_address {
-                    RawExchangeProvider::testnet_builder(self.signer.clone(), builder)
-                } else {
-                    RawExchangeProvider::testnet(self.signer.clone())
-                }
-            }
+        let raw = RawExchangeProvider::new_with_options(
+            self.signer.clone(),
+            self.network.clone(),
+            self.vault_address,
+            None,
+            self.builder_address,
+        )
+        .with_rounding(self.rounding)
+        .with_nonce_manager(nonce_manager.clone());
+        let raw = match self.asset_registry {
+            Some(registry) => raw.with_asset_registry(registry),
+            None => raw,
+        };
+        let raw = match self.transport {
+            Some(transport) => raw.with_transport(transport),
+            None => raw,
         };
 
         let inner = Arc::new(raw);
 
         // Create agent manager if needed
         let agent_manager = if self.config.auto_rotate_agents {
-            Some(Arc::new(AgentManager::new(
-                self.signer,
-                self.config.agent_config.clone(),
-                self.network,
-            )))
+            Some(Arc::new(
+                AgentManager::new(
+                    self.signer,
+                    self.config.agent_config.clone(),
+                    self.network,
+                )
+                .with_exchange(inner.clone()),
+            ))
         } else {
             None
         };
 
-        // Create nonce manager
-        let nonce_manager =
-            Arc::new(NonceManager::new(self.config.isolate_subaccount_nonces));
+        let agent_name = self
+            .initial_agent
+            .clone()
+            .unwrap_or_else(|| DEFAULT_AGENT_NAME.to_string());
+        let router = Arc::new(ActionRouter {
+            inner: inner.clone(),
+            agents: agent_manager.clone(),
+            agent_name,
+            agent_exchange: RwLock::new(None),
+        });
+
+        // Replace agents ahead of expiry so orders don't wait on approvals
+        let rotation_handle = agent_manager.as_ref().map(|agent_mgr| {
+            let agent_mgr = agent_mgr.clone();
+            let period = self.config.agent_config.health_check_interval;
+            let handle = tokio::spawn(async move {
+                let mut interval = tokio::time::interval_at(
+                    tokio::time::Instant::now() + period,
+                    period,
+                );
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = agent_mgr.rotate_expiring().await {
+                        tracing::warn!("Agent rotation failed: {}", e);
+                    }
+                }
+            });
+            Arc::new(TokioMutex::new(Some(handle)))
+        });
 
         // Create batcher if needed
         let (batcher, batcher_handle) = if self.config.batch_orders {
@@ -311,19 +447,19 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProviderBuilder<S> {
             let batcher = Arc::new(batcher);
 
             // Spawn batch processing task
-            let inner_clone = inner.clone();
-            let inner_clone2 = inner.clone();
+            let router_clone = router.clone();
+            let router_clone2 = router.clone();
             let handle_future = tokio::spawn(async move {
                 handle
                     .run(
                         move |orders| {
-                            let inner = inner_clone.clone();
+                            let router = router_clone.clone();
                             Box::pin(async move {
                                 // Execute batch
                                 let order_requests: Vec<OrderRequest> =
                                     orders.iter().map(|o| o.order.clone()).collect();
 
-                                match inner.bulk_orders(order_requests).await {
+                                match router.bulk_orders(order_requests).await {
                                     Ok(status) => {
                                         // Return same status for all orders in batch
                                         orders
@@ -347,13 +483,13 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProviderBuilder<S> {
                             })
                         },
                         move |cancels| {
-                            let inner = inner_clone2.clone();
+                            let router = router_clone2.clone();
                             Box::pin(async move {
                                 // Execute cancel batch
                                 let cancel_requests: Vec<CancelRequest> =
                                     cancels.iter().map(|c| c.cancel.clone()).collect();
 
-                                match inner.bulk_cancel(cancel_requests).await {
+                                match router.bulk_cancel(cancel_requests).await {
                                     Ok(status) => {
                                         // Return same status for all cancels in batch
                                         cancels
@@ -391,6 +527,8 @@ impl<S: HyperliquidSigner + Clone + 'static> ManagedExchangeProviderBuilder<S> {
         let provider = Arc::new(ManagedExchangeProvider {
             inner,
             agent_manager,
+            router,
+            rotation_handle,
             nonce_manager,
             batcher,
             batcher_handle,
@@ -0,0 +1,167 @@
+//! Market orders: IOC limit orders priced off the book with a slippage bound.
+
+use alloy::primitives::Address;
+
+use crate::{
+    constants::DEFAULT_SLIPPAGE,
+    errors::HyperliquidError,
+    providers::{asset_registry::AssetInfo, InfoProvider},
+    signers::HyperliquidSigner,
+    types::{
+        responses::{ExchangeResponseStatus, FilledOrder, OrderOutcome},
+        Symbol,
+    },
+};
+
+use super::{round_price, rounding::slipped_price, RawExchangeProvider, RoundingMode};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+impl<S: HyperliquidSigner> RawExchangeProvider<S> {
+    /// Info provider sharing this provider's network and transport.
+    pub(crate) fn info(&self) -> InfoProvider {
+        InfoProvider::new(self.network.clone()).with_transport(self.transport.clone())
+    }
+
+    /// Account whose positions this provider trades: the vault if set, then the
+    /// account given to [`with_account_address`](Self::with_account_address),
+    /// otherwise the signer.
+    pub fn account_address(&self) -> Address {
+        self.vault_address
+            .or(self.account_address)
+            .unwrap_or_else(|| self.signer.address())
+    }
+
+    /// Worst acceptable price for a market order on `asset`.
+    ///
+    /// Starts from the best opposite-side level of the L2 book (the mid if that
+    /// side is empty), moves it by `slippage` against the order and rounds it
+    /// back inside the bound.
+    pub(crate) async fn market_price(
+        &self,
+        asset: &AssetInfo,
+        is_buy: bool,
+        slippage: f64,
+    ) -> Result<String> {
+        if !(0.0..1.0).contains(&slippage) {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "slippage must be in [0, 1), got {}",
+                slippage
+            )));
+        }
+
+        let info = self.info();
+        let book = info.l2_book(asset.coin.clone()).await?;
+        let side = if is_buy { 1 } else { 0 };
+        let reference = match book.levels.get(side).and_then(|levels| levels.first()) {
+            Some(level) => level.px.clone(),
+            None => info.all_mids().await?.remove(&asset.coin).ok_or_else(|| {
+                HyperliquidError::InvalidResponse(format!(
+                    "no market price for {}",
+                    asset.coin
+                ))
+            })?,
+        };
+
+        let bound = slipped_price(&reference, slippage, is_buy).ok_or_else(|| {
+            HyperliquidError::InvalidResponse(format!(
+                "invalid price {:?} for {}",
+                reference.as_str(),
+                asset.coin
+            ))
+        })?;
+        round_price(&bound, is_buy, asset, RoundingMode::Passive)
+    }
+
+    /// Open or add to a position at market.
+    ///
+    /// Sends an IOC order priced `slippage` (default
+    /// [`DEFAULT_SLIPPAGE`](crate::constants::DEFAULT_SLIPPAGE)) beyond the best
+    /// opposite-side price and returns the average fill price and filled size.
+    /// Requires an asset registry.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let fill = provider.market_open("ETH", true, "0.5", None).await?;
+    /// println!("bought {} at {}", fill.total_sz, fill.avg_px);
+    /// ```
+    pub async fn market_open(
+        &self,
+        symbol: impl Into<Symbol>,
+        is_buy: bool,
+        sz: impl ToString,
+        slippage: Option<f64>,
+    ) -> Result<FilledOrder> {
+        let slippage = slippage.unwrap_or(DEFAULT_SLIPPAGE);
+        let builder = self.order_for(symbol);
+        let builder = if is_buy {
+            builder.market_buy(sz, slippage)
+        } else {
+            builder.market_sell(sz, slippage)
+        };
+        into_fill(builder.send().await?)
+    }
+
+    /// Close the whole perpetual position in `symbol` at market.
+    ///
+    /// Sends a reduce-only IOC order against the position of
+    /// [`account_address`](Self::account_address) and returns the average fill
+    /// price and filled size. Requires an asset registry.
+    pub async fn market_close(
+        &self,
+        symbol: impl Into<Symbol>,
+        slippage: Option<f64>,
+    ) -> Result<FilledOrder> {
+        let asset = self.resolve_asset(symbol).await?;
+        let state = self.info().user_state(self.account_address()).await?;
+        let szi = state
+            .asset_positions
+            .iter()
+            .find(|position| position.position.coin == asset.coin)
+            .map(|position| position.position.szi.to_string())
+            .filter(|szi| szi.parse::<f64>().is_ok_and(|szi| szi != 0.0))
+            .ok_or_else(|| {
+                HyperliquidError::InvalidRequest(format!(
+                    "no open position in {}",
+                    asset.coin
+                ))
+            })?;
+
+        let slippage = slippage.unwrap_or(DEFAULT_SLIPPAGE);
+        let builder = self.order(asset.asset).reduce_only(true);
+        let builder = match szi.strip_prefix('-') {
+            Some(short) => builder.market_buy(short, slippage),
+            None => builder.market_sell(szi, slippage),
+        };
+        into_fill(builder.send().await?)
+    }
+}
+
+/// Extract the fill of a single market order.
+fn into_fill(response: ExchangeResponseStatus) -> Result<FilledOrder> {
+    let outcome = response
+        .order_outcomes()?
+        .into_result()?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            HyperliquidError::InvalidResponse("order response has no status".to_string())
+        })?;
+    match outcome {
+        OrderOutcome::Filled {
+            oid,
+            cloid,
+            avg_px,
+            total_sz,
+        } => Ok(FilledOrder {
+            total_sz,
+            avg_px,
+            oid,
+            cloid,
+        }),
+        other => Err(HyperliquidError::InvalidResponse(format!(
+            "market order did not fill: {:?}",
+            other
+        ))),
+    }
+}
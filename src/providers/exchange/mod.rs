@@ -14,11 +14,20 @@
 
 mod builder;
 mod managed;
+mod market;
+mod multi_sig;
+mod positions;
+mod rounding;
+mod signed;
 
 pub use builder::OrderBuilder;
 pub use managed::{
     ManagedExchangeConfig, ManagedExchangeProvider, ManagedExchangeProviderBuilder,
 };
+pub use multi_sig::{CollectedSignature, MultiSigSession};
+pub use positions::{CloseFailure, CloseReport, ClosedPosition};
+pub use rounding::{round_order, round_price, round_size, RoundingMode};
+pub use signed::{ActionSignature, SignedAction};
 
 use std::{
     sync::Arc,
@@ -26,19 +35,21 @@ use std::{
 };
 
 use alloy::primitives::{keccak256, Address, B256};
-use http_body_util::{BodyExt, Full};
-use hyper::{body::Bytes, Method, Request};
-use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
-use hyper_util::client::legacy::{connect::HttpConnector, Client};
 use serde::Serialize;
-use serde_json::{json, Value};
+use serde_json::json;
 use uuid::Uuid;
 
 use crate::{
     constants::*,
     errors::HyperliquidError,
-    providers::order_tracker::{OrderStatus, OrderTracker, TrackedOrder},
-    signers::{HyperliquidSignature, HyperliquidSigner},
+    providers::{
+        asset_registry::{AssetInfo, AssetRegistry},
+        nonce::NonceManager,
+        order_tracker::{OrderStatus, OrderTracker, TrackedOrder},
+        transport::{HyperTransport, Transport, WsTransport},
+        websocket::ManagedWsProvider,
+    },
+    signers::HyperliquidSigner,
     types::{
         actions::{
             Agent,
@@ -60,12 +71,14 @@ use crate::{
             ConvertToMultiSigUser,
             CreateSubAccount,
             MultiSig,
+            MultiSigPayload,
             MultiSigSignature,
             MultiSigSigner,
             Noop,
             PerpDeployRegisterAsset,
             PerpDeploySetOracle,
             ScheduleCancel,
+            SendMultiSig,
             SetReferrer,
             SpotDeployEnableFreezePrivilege,
             SpotDeployEnableQuoteToken,
@@ -94,28 +107,187 @@ use crate::{
         },
         eip712::HyperliquidAction,
         requests::*,
-        responses::ExchangeResponseStatus,
+        responses::{ExchangeResponseStatus, OrderOutcome, OrderOutcomes},
         Symbol,
     },
 };
 
 type Result<T> = std::result::Result<T, HyperliquidError>;
 
-/// Format a float for use in API requests.
-/// Formats to 8 decimal places and removes trailing zeros.
-pub(crate) fn format_float_string(value: f64) -> String {
-    let mut x = format!("{:.8}", value);
-    while x.ends_with('0') {
-        x.pop();
-    }
-    if x.ends_with('.') {
-        x.pop();
-    }
-    if x == "-0" {
-        "0".to_string()
+/// Outcomes of an order response that must hold one status per sent order
+fn aligned_outcomes(
+    response: &ExchangeResponseStatus,
+    count: usize,
+) -> Result<OrderOutcomes> {
+    let outcomes = response.order_outcomes()?;
+    if outcomes.len() != count {
+        return Err(HyperliquidError::InvalidResponse(format!(
+            "expected {} order statuses, got {}",
+            count,
+            outcomes.len()
+        )));
+    }
+    Ok(outcomes)
+}
+
+/// Compute the connection id signed for an L1 action.
+///
+/// The action is msgpack-encoded under its `type` tag, followed by the big-endian
+/// nonce and an optional vault address marker.
+pub(crate) fn hash_action<T: Serialize>(
+    action_type: &str,
+    action: &T,
+    timestamp: u64,
+    vault_address: Option<Address>,
+) -> Result<B256> {
+    // Create an enum wrapper for proper serialization
+    // This matches how the original Hyperliquid SDK serializes actions
+    // The enum variant becomes the "type" field in the serialized output
+    #[derive(serde::Serialize)]
+    #[serde(tag = "type")]
+    #[serde(rename_all = "camelCase")]
+    enum ActionWrapper<'a, T> {
+        Order(&'a T),
+        Cancel(&'a T),
+        CancelByCloid(&'a T),
+        BatchModify(&'a T),
+        UpdateLeverage(&'a T),
+        UpdateIsolatedMargin(&'a T),
+        UsdSend(&'a T),
+        SpotSend(&'a T),
+        SpotUser(&'a T),
+        VaultTransfer(&'a T),
+        SetReferrer(&'a T),
+        ApproveAgent(&'a T),
+        ApproveBuilderFee(&'a T),
+        Withdraw3(&'a T),
+        // Phase 1 new actions
+        ScheduleCancel(&'a T),
+        CreateSubAccount(&'a T),
+        SubAccountTransfer(&'a T),
+        SubAccountSpotTransfer(&'a T),
+        UsdClassTransfer(&'a T),
+        // Phase 2 new actions
+        TwapOrder(&'a T),
+        TwapCancel(&'a T),
+        AgentEnableDexAbstraction(&'a T),
+        // Phase 3 new actions - Spot Deployment
+        SpotDeployRegisterToken(&'a T),
+        SpotDeployUserGenesis(&'a T),
+        SpotDeployFreezeUser(&'a T),
+        SpotDeployEnableFreezePrivilege(&'a T),
+        SpotDeployRevokeFreezePrivilege(&'a T),
+        SpotDeployEnableQuoteToken(&'a T),
+        SpotDeployGenesis(&'a T),
+        SpotDeployRegisterSpot(&'a T),
+        SpotDeployRegisterHyperliquidity(&'a T),
+        SpotDeploySetDeployerTradingFeeShare(&'a T),
+        // Phase 3 new actions - Perp Deployment
+        PerpDeployRegisterAsset(&'a T),
+        PerpDeploySetOracle(&'a T),
+        // Phase 3 new actions - Validator/Staking
+        CSignerUnjailSelf(&'a T),
+        CSignerJailSelf(&'a T),
+        CValidatorRegister(&'a T),
+        CValidatorChangeProfile(&'a T),
+        CValidatorUnregister(&'a T),
+        TokenDelegate(&'a T),
+        // Phase 3 new actions - Other
+        UseBigBlocks(&'a T),
+        Noop(&'a T),
+    }
+
+    // Wrap the action based on type
+    let wrapped = match action_type {
+        "order" => ActionWrapper::Order(action),
+        "cancel" => ActionWrapper::Cancel(action),
+        "cancelByCloid" => ActionWrapper::CancelByCloid(action),
+        "batchModify" => ActionWrapper::BatchModify(action),
+        "updateLeverage" => ActionWrapper::UpdateLeverage(action),
+        "updateIsolatedMargin" => ActionWrapper::UpdateIsolatedMargin(action),
+        "usdSend" => ActionWrapper::UsdSend(action),
+        "spotSend" => ActionWrapper::SpotSend(action),
+        "spotUser" => ActionWrapper::SpotUser(action),
+        "vaultTransfer" => ActionWrapper::VaultTransfer(action),
+        "setReferrer" => ActionWrapper::SetReferrer(action),
+        "approveAgent" => ActionWrapper::ApproveAgent(action),
+        "approveBuilderFee" => ActionWrapper::ApproveBuilderFee(action),
+        "withdraw3" => ActionWrapper::Withdraw3(action),
+        // Phase 1 new actions
+        "scheduleCancel" => ActionWrapper::ScheduleCancel(action),
+        "createSubAccount" => ActionWrapper::CreateSubAccount(action),
+        "subAccountTransfer" => ActionWrapper::SubAccountTransfer(action),
+        "subAccountSpotTransfer" => ActionWrapper::SubAccountSpotTransfer(action),
+        "usdClassTransfer" => ActionWrapper::UsdClassTransfer(action),
+        // Phase 2 new actions
+        "twapOrder" => ActionWrapper::TwapOrder(action),
+        "twapCancel" => ActionWrapper::TwapCancel(action),
+        "agentEnableDexAbstraction" => ActionWrapper::AgentEnableDexAbstraction(action),
+        // Phase 3 new actions - Spot Deployment
+        "spotDeployRegisterToken" => ActionWrapper::SpotDeployRegisterToken(action),
+        "spotDeployUserGenesis" => ActionWrapper::SpotDeployUserGenesis(action),
+        "spotDeployFreezeUser" => ActionWrapper::SpotDeployFreezeUser(action),
+        "spotDeployEnableFreezePrivilege" => {
+            ActionWrapper::SpotDeployEnableFreezePrivilege(action)
+        }
+        "spotDeployRevokeFreezePrivilege" => {
+            ActionWrapper::SpotDeployRevokeFreezePrivilege(action)
+        }
+        "spotDeployEnableQuoteToken" => ActionWrapper::SpotDeployEnableQuoteToken(action),
+        "spotDeployGenesis" => ActionWrapper::SpotDeployGenesis(action),
+        "spotDeployRegisterSpot" => ActionWrapper::SpotDeployRegisterSpot(action),
+        "spotDeployRegisterHyperliquidity" => {
+            ActionWrapper::SpotDeployRegisterHyperliquidity(action)
+        }
+        "spotDeploySetDeployerTradingFeeShare" => {
+            ActionWrapper::SpotDeploySetDeployerTradingFeeShare(action)
+        }
+        // Phase 3 new actions - Perp Deployment
+        "perpDeployRegisterAsset" => ActionWrapper::PerpDeployRegisterAsset(action),
+        "perpDeploySetOracle" => ActionWrapper::PerpDeploySetOracle(action),
+        // Phase 3 new actions - Validator/Staking
+        "cSignerUnjailSelf" => ActionWrapper::CSignerUnjailSelf(action),
+        "cSignerJailSelf" => ActionWrapper::CSignerJailSelf(action),
+        "cValidatorRegister" => ActionWrapper::CValidatorRegister(action),
+        "cValidatorChangeProfile" => ActionWrapper::CValidatorChangeProfile(action),
+        "cValidatorUnregister" => ActionWrapper::CValidatorUnregister(action),
+        "tokenDelegate" => ActionWrapper::TokenDelegate(action),
+        // Phase 3 new actions - Other
+        "useBigBlocks" => ActionWrapper::UseBigBlocks(action),
+        "noop" => ActionWrapper::Noop(action),
+        _ => {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "Unknown action type: {}",
+                action_type
+            )))
+        }
+    };
+
+    hash_msgpack(&wrapped, timestamp, vault_address)
+}
+
+/// Hash a msgpack-encoded payload with its nonce and optional vault address.
+///
+/// [`hash_action`] uses this for tagged L1 actions; multi-sig envelopes and the
+/// untagged `multiSig` action are hashed with it directly.
+pub(crate) fn hash_msgpack<T: Serialize>(
+    payload: &T,
+    timestamp: u64,
+    vault_address: Option<Address>,
+) -> Result<B256> {
+    // NOTE: Hyperliquid uses MessagePack (rmp_serde) for action serialization
+    // This is different from typical EVM systems that use RLP
+    let mut bytes = rmp_serde::to_vec_named(payload).map_err(|e| {
+        HyperliquidError::InvalidRequest(format!("Failed to serialize action: {}", e))
+    })?;
+    bytes.extend(timestamp.to_be_bytes());
+    if let Some(vault) = vault_address {
+        bytes.push(1);
+        bytes.extend(vault.as_slice());
     } else {
-        x
+        bytes.push(0);
     }
+    Ok(keccak256(bytes))
 }
 
 /// Raw exchange provider for direct API access.
@@ -123,25 +295,58 @@ pub(crate) fn format_float_string(value: f64) -> String {
 /// This provider offers low-level access to all exchange endpoints.
 /// For most use cases, consider using [`ManagedExchangeProvider`] instead.
 pub struct RawExchangeProvider<S: HyperliquidSigner> {
-    client: Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
-    endpoint: &'static str,
+    transport: Arc<dyn Transport>,
+    network: Network,
+    endpoint: String,
     rate_limiter: Arc<crate::providers::info::RateLimiter>,
     signer: S,
     vault_address: Option<Address>,
+    account_address: Option<Address>,
     agent: Option<Address>,
     builder: Option<Address>,
     order_tracker: Option<OrderTracker>,
+    asset_registry: Option<Arc<AssetRegistry>>,
+    rounding: RoundingMode,
+    nonce_manager: Arc<NonceManager>,
 }
 
 impl<S: HyperliquidSigner> RawExchangeProvider<S> {
     // ==================== Helper Methods ====================
 
-    pub(crate) fn infer_network(&self) -> (u64, &'static str) {
-        if self.endpoint.contains("testnet") {
-            (CHAIN_ID_TESTNET, AGENT_SOURCE_TESTNET)
-        } else {
-            (CHAIN_ID_MAINNET, AGENT_SOURCE_MAINNET)
-        }
+    pub(crate) fn infer_network(&self) -> (u64, &str) {
+        (self.network.chain_id(), self.network.agent_source())
+    }
+
+    /// Get the network this provider signs for and sends to.
+    pub fn network(&self) -> &Network {
+        &self.network
+    }
+
+    /// Get the address of the key signing actions.
+    pub fn signer_address(&self) -> Address {
+        self.signer.address()
+    }
+
+    /// Get the agent address given at construction.
+    ///
+    /// Agent keys sign plain actions, so this is informational only: to trade
+    /// as an agent, construct the provider with the agent key as its signer.
+    #[deprecated(note = "the signer is the agent; see signer_address()")]
+    pub fn agent_address(&self) -> Option<Address> {
+        self.agent
+    }
+
+    /// Trade for `account` while signing with another key.
+    ///
+    /// Set this when the signer is an approved agent (API wallet) of `account`:
+    /// the exchange credits the agent's actions to it, and position and order
+    /// queries such as [`market_close`](Self::market_close),
+    /// [`close_position`](Self::close_position) and
+    /// [`reconcile_orders`](Self::reconcile_orders) must look it up instead of
+    /// the agent key.
+    pub fn with_account_address(mut self, account: Address) -> Self {
+        self.account_address = Some(account);
+        self
     }
 
     /// Get the configured builder address.
@@ -149,14 +354,163 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         self.builder
     }
 
+    /// Send requests through a custom transport (proxy, mTLS, in-memory mock, ...).
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Send signed actions over an open WebSocket connection instead of HTTPS.
+    pub fn with_ws_transport(self, ws: Arc<ManagedWsProvider>) -> Self {
+        self.with_transport(Arc::new(WsTransport::new(ws)))
+    }
+
+    /// Resolve symbols to asset ids through a shared registry.
+    pub fn with_asset_registry(mut self, registry: Arc<AssetRegistry>) -> Self {
+        self.asset_registry = Some(registry);
+        self
+    }
+
+    /// Draw nonces from a shared [`NonceManager`].
+    ///
+    /// Every signed action takes its nonce from this manager, keyed by the
+    /// signer address. Hyperliquid tracks nonces per signer, so providers
+    /// signing with the same key (for example one per vault or sub-account)
+    /// should share a manager to keep their nonces from colliding.
+    pub fn with_nonce_manager(mut self, nonce_manager: Arc<NonceManager>) -> Self {
+        self.nonce_manager = nonce_manager;
+        self
+    }
+
+    /// Get the nonce manager used for signed actions.
+    pub fn nonce_manager(&self) -> &Arc<NonceManager> {
+        &self.nonce_manager
+    }
+
+    /// Get the attached asset registry.
+    pub fn asset_registry(&self) -> Option<&Arc<AssetRegistry>> {
+        self.asset_registry.as_ref()
+    }
+
+    /// Look up a symbol in the attached asset registry, refreshing it if needed.
+    pub async fn resolve_asset(&self, symbol: impl Into<Symbol>) -> Result<AssetInfo> {
+        self.require_asset_registry()?.resolve(symbol.into()).await
+    }
+
+    /// Choose how order prices and sizes are fitted to the tick and lot rules.
+    ///
+    /// Rules are only checked when an asset registry is attached; the default is
+    /// [`RoundingMode::Strict`].
+    pub fn with_rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = mode;
+        self
+    }
+
+    /// Get the configured rounding mode.
+    pub fn rounding(&self) -> RoundingMode {
+        self.rounding
+    }
+
+    /// Fit an order's prices and size to its asset's tick and lot rules.
+    ///
+    /// Orders pass through unchanged when no asset registry is attached. Every
+    /// order, modify and bulk method applies this before signing.
+    pub async fn apply_rounding(&self, order: &OrderRequest) -> Result<OrderRequest> {
+        self.apply_rounding_with(order, self.rounding).await
+    }
+
+    pub(crate) async fn apply_rounding_with(
+        &self,
+        order: &OrderRequest,
+        mode: RoundingMode,
+    ) -> Result<OrderRequest> {
+        match &self.asset_registry {
+            Some(registry) => {
+                let asset = registry.resolve_id(order.asset).await?;
+                round_order(order, &asset, mode)
+            }
+            None => Ok(order.clone()),
+        }
+    }
+
+    async fn apply_rounding_all(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Result<Vec<OrderRequest>> {
+        let mut rounded = Vec::with_capacity(orders.len());
+        for order in &orders {
+            rounded.push(self.apply_rounding(order).await?);
+        }
+        Ok(rounded)
+    }
+
+    pub(crate) fn require_asset_registry(&self) -> Result<&Arc<AssetRegistry>> {
+        self.asset_registry.as_ref().ok_or_else(|| {
+            HyperliquidError::InvalidRequest(
+                "symbol lookup requires an asset registry, see with_asset_registry"
+                    .to_string(),
+            )
+        })
+    }
+
     /// Enable order tracking for this exchange instance.
     pub fn with_order_tracking(mut self) -> Self {
         self.order_tracker = Some(OrderTracker::new());
         self
     }
 
+    /// Track orders with `tracker`, such as one persisted with
+    /// [`OrderTracker::with_store`].
+    pub fn with_order_tracker(mut self, tracker: OrderTracker) -> Self {
+        self.order_tracker = Some(tracker);
+        self
+    }
+
     // ==================== Order Tracking Methods ====================
 
+    /// Get the order tracker, if tracking is enabled.
+    pub fn order_tracker(&self) -> Option<&OrderTracker> {
+        self.order_tracker.as_ref()
+    }
+
+    /// Follow tracked orders through `orderUpdates` and `userFills` on `ws`.
+    ///
+    /// Updates are read for [`account_address`](Self::account_address). See
+    /// [`OrderTracker::follow`].
+    pub async fn follow_order_updates(
+        &self,
+        ws: &ManagedWsProvider,
+    ) -> Result<tokio::task::JoinHandle<()>> {
+        self.require_order_tracker()?
+            .follow(ws, self.account_address())
+            .await
+    }
+
+    /// Settle tracked orders left live by a restart, see
+    /// [`OrderTracker::reconcile`].
+    ///
+    /// Returns the number of orders whose status changed.
+    pub async fn reconcile_orders(&self) -> Result<usize> {
+        self.require_order_tracker()?
+            .reconcile(&self.info(), self.account_address())
+            .await
+    }
+
+    fn require_order_tracker(&self) -> Result<&OrderTracker> {
+        self.order_tracker.as_ref().ok_or_else(|| {
+            HyperliquidError::InvalidRequest(
+                "order tracking is disabled, see with_order_tracking".to_string(),
+            )
+        })
+    }
+
+    /// Wait until a tracked order is filled, canceled, rejected or failed.
+    ///
+    /// Returns `None` if tracking is disabled or the order is not tracked.
+    pub async fn wait_for_order(&self, cloid: &Uuid) -> Option<TrackedOrder> {
+        self.order_tracker.as_ref()?.wait_for_terminal(cloid).await
+    }
+
     /// Get a tracked order by CLOID.
     pub fn get_tracked_order(&self, cloid: &Uuid) -> Option<TrackedOrder> {
         self.order_tracker.as_ref()?.get_order(cloid)
@@ -221,63 +575,51 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
 
     /// Create a mainnet provider.
     pub fn mainnet(signer: S) -> Self {
-        Self::new(signer, EXCHANGE_ENDPOINT_MAINNET, None, None, None)
+        Self::new_with_options(signer, Network::Mainnet, None, None, None)
     }
 
     /// Create a testnet provider.
     pub fn testnet(signer: S) -> Self {
-        Self::new(signer, EXCHANGE_ENDPOINT_TESTNET, None, None, None)
+        Self::new_with_options(signer, Network::Testnet, None, None, None)
     }
 
     /// Create a mainnet provider with vault.
     pub fn mainnet_vault(signer: S, vault_address: Address) -> Self {
-        Self::new(
-            signer,
-            EXCHANGE_ENDPOINT_MAINNET,
-            Some(vault_address),
-            None,
-            None,
-        )
+        Self::new_with_options(signer, Network::Mainnet, Some(vault_address), None, None)
     }
 
     /// Create a testnet provider with vault.
     pub fn testnet_vault(signer: S, vault_address: Address) -> Self {
-        Self::new(
-            signer,
-            EXCHANGE_ENDPOINT_TESTNET,
-            Some(vault_address),
-            None,
-            None,
-        )
+        Self::new_with_options(signer, Network::Testnet, Some(vault_address), None, None)
     }
 
     /// Create a mainnet provider with agent.
+    ///
+    /// Agent keys sign plain actions, so `agent_address` has no effect on
+    /// signing: construct with the agent key as the signer instead.
+    #[deprecated(
+        note = "construct with the agent key and call with_account_address(master)"
+    )]
     pub fn mainnet_agent(signer: S, agent_address: Address) -> Self {
-        Self::new(
-            signer,
-            EXCHANGE_ENDPOINT_MAINNET,
-            None,
-            Some(agent_address),
-            None,
-        )
+        Self::new_with_options(signer, Network::Mainnet, None, Some(agent_address), None)
     }
 
     /// Create a testnet provider with agent.
+    ///
+    /// Agent keys sign plain actions, so `agent_address` has no effect on
+    /// signing: construct with the agent key as the signer instead.
+    #[deprecated(
+        note = "construct with the agent key and call with_account_address(master)"
+    )]
     pub fn testnet_agent(signer: S, agent_address: Address) -> Self {
-        Self::new(
-            signer,
-            EXCHANGE_ENDPOINT_TESTNET,
-            None,
-            Some(agent_address),
-            None,
-        )
+        Self::new_with_options(signer, Network::Testnet, None, Some(agent_address), None)
     }
 
     /// Create a mainnet provider with builder.
     pub fn mainnet_builder(signer: S, builder_address: Address) -> Self {
-        Self::new(
+        Self::new_with_options(
             signer,
-            EXCHANGE_ENDPOINT_MAINNET,
+            Network::Mainnet,
             None,
             None,
             Some(builder_address),
@@ -286,9 +628,9 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
 
     /// Create a testnet provider with builder.
     pub fn testnet_builder(signer: S, builder_address: Address) -> Self {
-        Self::new(
+        Self::new_with_options(
             signer,
-            EXCHANGE_ENDPOINT_TESTNET,
+            Network::Testnet,
             None,
             None,
             Some(builder_address),
@@ -302,7 +644,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         agent: Option<Address>,
         builder: Option<Address>,
     ) -> Self {
-        Self::new(signer, EXCHANGE_ENDPOINT_MAINNET, vault, agent, builder)
+        Self::new_with_options(signer, Network::Mainnet, vault, agent, builder)
     }
 
     /// Create a testnet provider with all options.
@@ -312,51 +654,104 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         agent: Option<Address>,
         builder: Option<Address>,
     ) -> Self {
-        Self::new(signer, EXCHANGE_ENDPOINT_TESTNET, vault, agent, builder)
+        Self::new_with_options(signer, Network::Testnet, vault, agent, builder)
+    }
+
+    /// Create a provider for any network, including [`Network::Custom`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let network = Network::custom(
+    ///     "http://127.0.0.1:3001",
+    ///     "ws://127.0.0.1:3001/ws",
+    ///     &Network::Testnet,
+    /// );
+    /// let provider = RawExchangeProvider::new(signer, network);
+    /// ```
+    pub fn new(signer: S, network: Network) -> Self {
+        Self::new_with_options(signer, network, None, None, None)
     }
 
-    fn new(
+    /// Create a provider for any network with all options.
+    pub fn new_with_options(
         signer: S,
-        endpoint: &'static str,
+        network: Network,
         vault_address: Option<Address>,
         agent: Option<Address>,
         builder: Option<Address>,
     ) -> Self {
-        let https = HttpsConnectorBuilder::new()
-            .with_native_roots()
-            .unwrap()
-            .https_only()
-            .enable_http1()
-            .build();
-        let client = Client::builder(hyper_util::rt::TokioExecutor::new()).build(https);
         let rate_limiter = Arc::new(crate::providers::info::RateLimiter::new(
             RATE_LIMIT_MAX_TOKENS,
             RATE_LIMIT_REFILL_RATE,
         ));
 
         Self {
-            client,
-            endpoint,
+            transport: Arc::new(HyperTransport::new()),
+            endpoint: network.exchange_url(),
+            network,
             rate_limiter,
             signer,
             vault_address,
+            account_address: None,
             agent,
             builder,
             order_tracker: None,
+            asset_registry: None,
+            rounding: RoundingMode::default(),
+            nonce_manager: Arc::new(NonceManager::new(true)),
+        }
+    }
+
+    /// Copy of this provider whose actions are signed by an approved agent key.
+    ///
+    /// The copy shares the transport, rate limiter, registry and settings, trades
+    /// for the same [`account_address`](Self::account_address) and takes its
+    /// nonces from `nonce_manager`.
+    pub(crate) fn with_agent_signer<T: HyperliquidSigner>(
+        &self,
+        signer: T,
+        nonce_manager: Arc<NonceManager>,
+    ) -> RawExchangeProvider<T> {
+        RawExchangeProvider {
+            transport: self.transport.clone(),
+            network: self.network.clone(),
+            endpoint: self.endpoint.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            agent: None,
+            signer,
+            vault_address: self.vault_address,
+            account_address: Some(self.account_address()),
+            builder: self.builder,
+            order_tracker: self.order_tracker.clone(),
+            asset_registry: self.asset_registry.clone(),
+            rounding: self.rounding,
+            nonce_manager,
         }
     }
 
     // ==================== Direct Order Operations ====================
 
     /// Place a single order.
+    ///
+    /// The order addresses its asset by id. To place an order by symbol, use
+    /// [`order_for`](Self::order_for), which resolves it through the attached
+    /// asset registry.
     pub async fn place_order(
         &self,
         order: &OrderRequest,
+    ) -> Result<ExchangeResponseStatus> {
+        let order = self.apply_rounding(order).await?;
+        self.place_rounded_order(order).await
+    }
+
+    /// Place a single order that already follows the tick and lot rules.
+    pub(crate) async fn place_rounded_order(
+        &self,
+        mut order: OrderRequest,
     ) -> Result<ExchangeResponseStatus> {
         self.rate_limiter.check_weight(WEIGHT_PLACE_ORDER)?;
 
         // Auto-generate CLOID if tracking is enabled and order doesn't have one
-        let mut order = order.clone();
         let cloid = if let Some(tracker) = &self.order_tracker {
             let cloid = order
                 .cloid
@@ -383,7 +778,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
 
         let bulk_order = BulkOrder {
             orders: vec![order],
-            grouping: "na".to_string(),
+            grouping: Grouping::Na,
             builder: self.builder.map(|addr| BuilderInfo {
                 builder: format!("0x{}", hex::encode(addr)),
                 fee: 0, // Default fee, use place_order_with_builder_fee to specify
@@ -397,11 +792,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
             if let Some(cloid) = cloid {
                 match &result {
                     Ok(response) => {
-                        tracker.update_order_status(
-                            &cloid,
-                            OrderStatus::Submitted,
-                            Some(response.clone()),
-                        );
+                        tracker.record_response(&cloid, response.clone());
                     }
                     Err(e) => {
                         tracker.update_order_status(
@@ -426,7 +817,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         self.rate_limiter.check_weight(WEIGHT_PLACE_ORDER)?;
 
         // Auto-generate CLOID if tracking is enabled and order doesn't have one
-        let mut order = order.clone();
+        let mut order = self.apply_rounding(order).await?;
         let cloid = if let Some(tracker) = &self.order_tracker {
             let cloid = order
                 .cloid
@@ -453,7 +844,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
 
         let bulk_order = BulkOrder {
             orders: vec![order],
-            grouping: "na".to_string(),
+            grouping: Grouping::Na,
             builder: self.builder.map(|addr| BuilderInfo {
                 builder: format!("0x{}", hex::encode(addr)),
                 fee: builder_fee,
@@ -467,11 +858,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
             if let Some(cloid) = cloid {
                 match &result {
                     Ok(response) => {
-                        tracker.update_order_status(
-                            &cloid,
-                            OrderStatus::Submitted,
-                            Some(response.clone()),
-                        );
+                        tracker.record_response(&cloid, response.clone());
                     }
                     Err(e) => {
                         tracker.update_order_status(
@@ -539,7 +926,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         let bulk_modify = BulkModify {
             modifies: vec![ModifyRequest {
                 oid,
-                order: new_order,
+                order: self.apply_rounding(&new_order).await?,
             }],
         };
 
@@ -553,17 +940,61 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         &self,
         orders: Vec<OrderRequest>,
     ) -> Result<ExchangeResponseStatus> {
-        self.rate_limiter.check_weight(WEIGHT_BULK_ORDER)?;
+        self.bulk_orders_with_grouping(orders, Grouping::Na).await
+    }
 
-        let bulk_order = BulkOrder {
-            orders,
-            grouping: "na".to_string(),
-            builder: self.builder.map(|addr| BuilderInfo {
-                builder: format!("0x{}", hex::encode(addr)),
-                fee: 0, // Default fee, use bulk_orders_with_builder_fee to specify
-            }),
-        };
+    /// Place a single order and return its outcome.
+    ///
+    /// A rejected order is an [`OrderOutcome::Rejected`]; an error means the
+    /// request itself failed or was refused.
+    pub async fn place_order_outcome(
+        &self,
+        order: &OrderRequest,
+    ) -> Result<OrderOutcome> {
+        let response = self.place_order(order).await?;
+        let mut outcomes = aligned_outcomes(&response, 1)?;
+        Ok(outcomes.0.remove(0))
+    }
 
+    /// Place multiple orders and return one outcome per order, in the order
+    /// they were given.
+    ///
+    /// Fails with [`HyperliquidError::InvalidResponse`] if the exchange returns a
+    /// different number of statuses, so outcomes can be zipped with `orders`.
+    pub async fn bulk_order_outcomes(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Result<OrderOutcomes> {
+        let count = orders.len();
+        let response = self.bulk_orders(orders).await?;
+        aligned_outcomes(&response, count)
+    }
+
+    /// Place multiple orders in a single request with the given grouping.
+    ///
+    /// With [`Grouping::NormalTpsl`] the first order is the entry and the
+    /// following trigger orders are its take-profit / stop-loss; with
+    /// [`Grouping::PositionTpsl`] every order is a trigger attached to the open
+    /// position. See [`OrderBuilder::with_take_profit`] for a builder.
+    pub async fn bulk_orders_with_grouping(
+        &self,
+        orders: Vec<OrderRequest>,
+        grouping: Grouping,
+    ) -> Result<ExchangeResponseStatus> {
+        let orders = self.apply_rounding_all(orders).await?;
+        self.bulk_rounded_orders(orders, grouping).await
+    }
+
+    /// Place orders that already follow the tick and lot rules.
+    pub(crate) async fn bulk_rounded_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+        grouping: Grouping,
+    ) -> Result<ExchangeResponseStatus> {
+        self.rate_limiter.check_weight(WEIGHT_BULK_ORDER)?;
+
+        // Default fee, use bulk_orders_with_builder_fee to specify
+        let bulk_order = self.bulk_order_action(orders, grouping, 0);
         self.send_l1_action("order", &bulk_order).await
     }
 
@@ -575,16 +1006,26 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
     ) -> Result<ExchangeResponseStatus> {
         self.rate_limiter.check_weight(WEIGHT_BULK_ORDER)?;
 
-        let bulk_order = BulkOrder {
+        let orders = self.apply_rounding_all(orders).await?;
+        let bulk_order = self.bulk_order_action(orders, Grouping::Na, builder_fee);
+        self.send_l1_action("order", &bulk_order).await
+    }
+
+    /// Already rounded orders with the configured builder
+    fn bulk_order_action(
+        &self,
+        orders: Vec<OrderRequest>,
+        grouping: Grouping,
+        builder_fee: u64,
+    ) -> BulkOrder {
+        BulkOrder {
             orders,
-            grouping: "na".to_string(),
+            grouping,
             builder: self.builder.map(|addr| BuilderInfo {
                 builder: format!("0x{}", hex::encode(addr)),
                 fee: builder_fee,
             }),
-        };
-
-        self.send_l1_action("order", &bulk_order).await
+        }
     }
 
     /// Place multiple orders with client order IDs.
@@ -629,7 +1070,14 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
     ) -> Result<ExchangeResponseStatus> {
         self.rate_limiter.check_weight(WEIGHT_BULK_ORDER)?;
 
-        let bulk_modify = BulkModify { modifies };
+        let mut rounded = Vec::with_capacity(modifies.len());
+        for modify in modifies {
+            rounded.push(ModifyRequest {
+                oid: modify.oid,
+                order: self.apply_rounding(&modify.order).await?,
+            });
+        }
+        let bulk_modify = BulkModify { modifies: rounded };
         self.send_l1_action("batchModify", &bulk_modify).await
     }
 
@@ -679,22 +1127,19 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         destination: Address,
         amount: &str,
     ) -> Result<ExchangeResponseStatus> {
-        let (chain_id, _) = self.infer_network();
-        let chain = if chain_id == CHAIN_ID_MAINNET {
-            "Mainnet"
-        } else {
-            "Testnet"
-        };
+        let action = self.usd_send_action(destination, amount);
+        self.send_user_action(&action).await
+    }
 
-        let action = UsdSend {
+    fn usd_send_action(&self, destination: Address, amount: &str) -> UsdSend {
+        let (chain_id, _) = self.infer_network();
+        UsdSend {
             signature_chain_id: chain_id,
-            hyperliquid_chain: chain.to_string(),
+            hyperliquid_chain: self.network.hyperliquid_chain().to_string(),
             destination: format!("{:#x}", destination),
             amount: amount.to_string(),
-            time: Self::current_nonce(),
-        };
-
-        self.send_user_action(&action).await
+            time: self.next_nonce(),
+        }
     }
 
     /// Withdraw funds to an address.
@@ -703,22 +1148,19 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         destination: Address,
         amount: &str,
     ) -> Result<ExchangeResponseStatus> {
-        let (chain_id, _) = self.infer_network();
-        let chain = if chain_id == CHAIN_ID_MAINNET {
-            "Mainnet"
-        } else {
-            "Testnet"
-        };
+        let action = self.withdraw_action(destination, amount);
+        self.send_user_action(&action).await
+    }
 
-        let action = Withdraw {
+    fn withdraw_action(&self, destination: Address, amount: &str) -> Withdraw {
+        let (chain_id, _) = self.infer_network();
+        Withdraw {
             signature_chain_id: chain_id,
-            hyperliquid_chain: chain.to_string(),
+            hyperliquid_chain: self.network.hyperliquid_chain().to_string(),
             destination: format!("{:#x}", destination),
             amount: amount.to_string(),
-            time: Self::current_nonce(),
-        };
-
-        self.send_user_action(&action).await
+            time: self.next_nonce(),
+        }
     }
 
     /// Transfer spot tokens to another address.
@@ -729,11 +1171,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         amount: &str,
     ) -> Result<ExchangeResponseStatus> {
         let (chain_id, _) = self.infer_network();
-        let chain = if chain_id == CHAIN_ID_MAINNET {
-            "Mainnet"
-        } else {
-            "Testnet"
-        };
+        let chain = self.network.hyperliquid_chain();
 
         let symbol = token.into();
         let action = SpotSend {
@@ -742,7 +1180,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
             destination: format!("{:#x}", destination),
             token: symbol.as_str().to_string(),
             amount: amount.to_string(),
-            time: Self::current_nonce(),
+            time: self.next_nonce(),
         };
 
         self.send_user_action(&action).await
@@ -754,19 +1192,14 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         agent_address: Address,
         agent_name: Option<String>,
     ) -> Result<ExchangeResponseStatus> {
-        let (chain_id, _) = self.infer_network();
-        let chain = if chain_id == CHAIN_ID_MAINNET {
-            "Mainnet"
-        } else {
-            "Testnet"
-        };
+        let chain = self.network.hyperliquid_chain();
 
         let action = ApproveAgent {
             signature_chain_id: 421614, // Always use Arbitrum Sepolia chain ID like SDK
             hyperliquid_chain: chain.to_string(),
             agent_address,
             agent_name,
-            nonce: Self::current_nonce(),
+            nonce: self.next_nonce(),
         };
 
         self.send_user_action(&action).await
@@ -796,12 +1229,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         let agent_address = signer.address();
 
         // Get chain info
-        let (_, _) = self.infer_network();
-        let chain = if self.endpoint.contains("testnet") {
-            "Testnet"
-        } else {
-            "Mainnet"
-        };
+        let chain = self.network.hyperliquid_chain();
 
         // Create the action with proper Address type
         let action = ApproveAgent {
@@ -809,7 +1237,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
             hyperliquid_chain: chain.to_string(),
             agent_address,
             agent_name: None,
-            nonce: Self::current_nonce(),
+            nonce: self.next_nonce(),
         };
 
         // Use send_user_action which handles EIP-712 signing
@@ -825,18 +1253,14 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         max_fee_rate: String,
     ) -> Result<ExchangeResponseStatus> {
         let (chain_id, _) = self.infer_network();
-        let chain = if chain_id == CHAIN_ID_MAINNET {
-            "Mainnet"
-        } else {
-            "Testnet"
-        };
+        let chain = self.network.hyperliquid_chain();
 
         let action = ApproveBuilderFee {
             signature_chain_id: chain_id,
             hyperliquid_chain: chain.to_string(),
             builder: format!("0x{}", hex::encode(builder)),
             max_fee_rate,
-            nonce: Self::current_nonce(),
+            nonce: self.next_nonce(),
         };
 
         self.send_user_action(&action).await
@@ -1022,11 +1446,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         threshold: u32,
     ) -> Result<ExchangeResponseStatus> {
         let (chain_id, _) = self.infer_network();
-        let chain = if chain_id == CHAIN_ID_MAINNET {
-            "Mainnet"
-        } else {
-            "Testnet"
-        };
+        let chain = self.network.hyperliquid_chain();
 
         // Sort users by address and create signer structs
         let mut signers: Vec<MultiSigSigner> = authorized_users
@@ -1043,7 +1463,7 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
             hyperliquid_chain: chain.to_string(),
             signers,
             threshold,
-            nonce: Self::current_nonce(),
+            nonce: self.next_nonce(),
         };
 
         self.send_user_action(&action).await
@@ -1052,15 +1472,19 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
     /// Execute a multi-sig transaction.
     ///
     /// Used to execute actions on a multi-sig account with collected signatures.
+    /// This provider signs as the outer signer; the signatures must have been
+    /// made over the same `nonce`. [`MultiSigSession`] collects them.
     ///
     /// * `multi_sig_user` - The multi-sig account address
-    /// * `inner_action` - The action to execute (as JSON value)
-    /// * `signatures` - Signatures from other authorized users
+    /// * `inner_action` - The action to execute (as JSON value, with its `type` tag)
+    /// * `signatures` - Signatures from authorized users
+    /// * `nonce` - The nonce the signatures were made with
     pub async fn multi_sig(
         &self,
         multi_sig_user: Address,
         inner_action: serde_json::Value,
         signatures: Vec<(String, String, u8)>, // (r, s, v)
+        nonce: u64,
     ) -> Result<ExchangeResponseStatus> {
         let (chain_id, _) = self.infer_network();
 
@@ -1071,29 +1495,31 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
 
         let action = MultiSig {
             signature_chain_id: chain_id,
-            multi_sig_user: format!("{:#x}", multi_sig_user),
-            outer_signer: format!("{:#x}", self.signer.address()),
-            inner_action,
             signatures: sigs,
-            nonce: Self::current_nonce(),
+            payload: MultiSigPayload {
+                multi_sig_user: format!("{:#x}", multi_sig_user),
+                outer_signer: format!("{:#x}", self.signer.address()),
+                action: inner_action,
+            },
+        };
+
+        // The outer signer signs the hash of the untagged action
+        let envelope = SendMultiSig {
+            signature_chain_id: chain_id,
+            hyperliquid_chain: self.network.hyperliquid_chain().to_string(),
+            multi_sig_action_hash: hash_msgpack(&action, nonce, self.vault_address)?,
+            nonce,
         };
+        let signing_hash = envelope.eip712_signing_hash(&envelope.domain());
+        let signature = self.signer.sign_hash(signing_hash).await?;
 
-        // Multi-sig actions are posted directly without additional signing
-        let nonce = action.nonce;
-        let action_value = serde_json::to_value(&action)?;
-        let mut action_with_type = action_value;
+        let mut action_with_type = serde_json::to_value(&action)?;
         if let serde_json::Value::Object(ref mut map) = action_with_type {
             map.insert("type".to_string(), json!("multiSig"));
         }
 
-        // For multi-sig, we need a dummy signature since the actual signatures are in the payload
-        let signature = crate::signers::HyperliquidSignature {
-            r: alloy::primitives::U256::ZERO,
-            s: alloy::primitives::U256::ZERO,
-            v: 27,
-        };
-
-        self.post(action_with_type, signature, nonce).await
+        let signed = self.signed(action_with_type, signature.into(), nonce);
+        self.submit_signed(&signed).await
     }
 
     /// Enable DEX abstraction for the current agent.
@@ -1460,159 +1886,12 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
 
     // ==================== Helper Methods ====================
 
-    fn current_nonce() -> u64 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("system time before UNIX epoch")
-            .as_millis() as u64
-    }
-
-    fn hash_action<T: Serialize>(
-        action_type: &str,
-        action: &T,
-        timestamp: u64,
-        vault_address: Option<Address>,
-    ) -> Result<B256> {
-        // Create an enum wrapper for proper serialization
-        // This matches how the original Hyperliquid SDK serializes actions
-        // The enum variant becomes the "type" field in the serialized output
-        #[derive(serde::Serialize)]
-        #[serde(tag = "type")]
-        #[serde(rename_all = "camelCase")]
-        enum ActionWrapper<'a, T> {
-            Order(&'a T),
-            Cancel(&'a T),
-            CancelByCloid(&'a T),
-            BatchModify(&'a T),
-            UpdateLeverage(&'a T),
-            UpdateIsolatedMargin(&'a T),
-            UsdSend(&'a T),
-            SpotSend(&'a T),
-            SpotUser(&'a T),
-            VaultTransfer(&'a T),
-            SetReferrer(&'a T),
-            ApproveAgent(&'a T),
-            ApproveBuilderFee(&'a T),
-            Withdraw3(&'a T),
-            // Phase 1 new actions
-            ScheduleCancel(&'a T),
-            CreateSubAccount(&'a T),
-            SubAccountTransfer(&'a T),
-            SubAccountSpotTransfer(&'a T),
-            UsdClassTransfer(&'a T),
-            // Phase 2 new actions
-            TwapOrder(&'a T),
-            TwapCancel(&'a T),
-            AgentEnableDexAbstraction(&'a T),
-            // Phase 3 new actions - Spot Deployment
-            SpotDeployRegisterToken(&'a T),
-            SpotDeployUserGenesis(&'a T),
-            SpotDeployFreezeUser(&'a T),
-            SpotDeployEnableFreezePrivilege(&'a T),
-            SpotDeployRevokeFreezePrivilege(&'a T),
-            SpotDeployEnableQuoteToken(&'a T),
-            SpotDeployGenesis(&'a T),
-            SpotDeployRegisterSpot(&'a T),
-            SpotDeployRegisterHyperliquidity(&'a T),
-            SpotDeploySetDeployerTradingFeeShare(&'a T),
-            // Phase 3 new actions - Perp Deployment
-            PerpDeployRegisterAsset(&'a T),
-            PerpDeploySetOracle(&'a T),
-            // Phase 3 new actions - Validator/Staking
-            CSignerUnjailSelf(&'a T),
-            CSignerJailSelf(&'a T),
-            CValidatorRegister(&'a T),
-            CValidatorChangeProfile(&'a T),
-            CValidatorUnregister(&'a T),
-            TokenDelegate(&'a T),
-            // Phase 3 new actions - Other
-            UseBigBlocks(&'a T),
-            Noop(&'a T),
-        }
-
-        // Wrap the action based on type
-        let wrapped = match action_type {
-            "order" => ActionWrapper::Order(action),
-            "cancel" => ActionWrapper::Cancel(action),
-            "cancelByCloid" => ActionWrapper::CancelByCloid(action),
-            "batchModify" => ActionWrapper::BatchModify(action),
-            "updateLeverage" => ActionWrapper::UpdateLeverage(action),
-            "updateIsolatedMargin" => ActionWrapper::UpdateIsolatedMargin(action),
-            "usdSend" => ActionWrapper::UsdSend(action),
-            "spotSend" => ActionWrapper::SpotSend(action),
-            "spotUser" => ActionWrapper::SpotUser(action),
-            "vaultTransfer" => ActionWrapper::VaultTransfer(action),
-            "setReferrer" => ActionWrapper::SetReferrer(action),
-            "approveAgent" => ActionWrapper::ApproveAgent(action),
-            "approveBuilderFee" => ActionWrapper::ApproveBuilderFee(action),
-            "withdraw3" => ActionWrapper::Withdraw3(action),
-            // Phase 1 new actions
-            "scheduleCancel" => ActionWrapper::ScheduleCancel(action),
-            "createSubAccount" => ActionWrapper::CreateSubAccount(action),
-            "subAccountTransfer" => ActionWrapper::SubAccountTransfer(action),
-            "subAccountSpotTransfer" => ActionWrapper::SubAccountSpotTransfer(action),
-            "usdClassTransfer" => ActionWrapper::UsdClassTransfer(action),
-            // Phase 2 new actions
-            "twapOrder" => ActionWrapper::TwapOrder(action),
-            "twapCancel" => ActionWrapper::TwapCancel(action),
-            "agentEnableDexAbstraction" => {
-                ActionWrapper::AgentEnableDexAbstraction(action)
-            }
-            // Phase 3 new actions - Spot Deployment
-            "spotDeployRegisterToken" => ActionWrapper::SpotDeployRegisterToken(action),
-            "spotDeployUserGenesis" => ActionWrapper::SpotDeployUserGenesis(action),
-            "spotDeployFreezeUser" => ActionWrapper::SpotDeployFreezeUser(action),
-            "spotDeployEnableFreezePrivilege" => {
-                ActionWrapper::SpotDeployEnableFreezePrivilege(action)
-            }
-            "spotDeployRevokeFreezePrivilege" => {
-                ActionWrapper::SpotDeployRevokeFreezePrivilege(action)
-            }
-            "spotDeployEnableQuoteToken" => {
-                ActionWrapper::SpotDeployEnableQuoteToken(action)
-            }
-            "spotDeployGenesis" => ActionWrapper::SpotDeployGenesis(action),
-            "spotDeployRegisterSpot" => ActionWrapper::SpotDeployRegisterSpot(action),
-            "spotDeployRegisterHyperliquidity" => {
-                ActionWrapper::SpotDeployRegisterHyperliquidity(action)
-            }
-            "spotDeploySetDeployerTradingFeeShare" => {
-                ActionWrapper::SpotDeploySetDeployerTradingFeeShare(action)
-            }
-            // Phase 3 new actions - Perp Deployment
-            "perpDeployRegisterAsset" => ActionWrapper::PerpDeployRegisterAsset(action),
-            "perpDeploySetOracle" => ActionWrapper::PerpDeploySetOracle(action),
-            // Phase 3 new actions - Validator/Staking
-            "cSignerUnjailSelf" => ActionWrapper::CSignerUnjailSelf(action),
-            "cSignerJailSelf" => ActionWrapper::CSignerJailSelf(action),
-            "cValidatorRegister" => ActionWrapper::CValidatorRegister(action),
-            "cValidatorChangeProfile" => ActionWrapper::CValidatorChangeProfile(action),
-            "cValidatorUnregister" => ActionWrapper::CValidatorUnregister(action),
-            "tokenDelegate" => ActionWrapper::TokenDelegate(action),
-            // Phase 3 new actions - Other
-            "useBigBlocks" => ActionWrapper::UseBigBlocks(action),
-            "noop" => ActionWrapper::Noop(action),
-            _ => {
-                return Err(HyperliquidError::InvalidRequest(format!(
-                    "Unknown action type: {}",
-                    action_type
-                )))
-            }
-        };
-
-        // NOTE: Hyperliquid uses MessagePack (rmp_serde) for action serialization
-        // This is different from typical EVM systems that use RLP
-        let mut bytes = rmp_serde::to_vec_named(&wrapped).map_err(|e| {
-            HyperliquidError::InvalidRequest(format!("Failed to serialize action: {}", e))
-        })?;
-        bytes.extend(timestamp.to_be_bytes());
-        if let Some(vault) = vault_address {
-            bytes.push(1);
-            bytes.extend(vault.as_slice());
-        } else {
-            bytes.push(0);
-        }
-        Ok(keccak256(bytes))
+    /// Next nonce for the signer, strictly greater than any it was given before.
+    ///
+    /// Use it for the `time` / `nonce` field of user actions signed with
+    /// [`sign_user_action`](Self::sign_user_action).
+    pub fn next_nonce(&self) -> u64 {
+        self.nonce_manager.next_nonce(Some(self.signer_address()))
     }
 
     async fn send_l1_action<T: Serialize>(
@@ -1620,143 +1899,15 @@ impl<S: HyperliquidSigner> RawExchangeProvider<S> {
         action_type: &str,
         action: &T,
     ) -> Result<ExchangeResponseStatus> {
-        let nonce = Self::current_nonce();
-        let connection_id =
-            Self::hash_action(action_type, action, nonce, self.vault_address)?;
-
-        // Create Agent L1 action
-        let (_, agent_source) = self.infer_network();
-        let agent = Agent {
-            source: agent_source.to_string(),
-            connection_id,
-        };
-
-        // Sign using EIP-712
-        let domain = agent.domain();
-        let signing_hash = agent.eip712_signing_hash(&domain);
-        let signature = self.signer.sign_hash(signing_hash).await?;
-
-        // Build action value with type tag
-        let mut action_value = serde_json::to_value(action)?;
-        if let Value::Object(ref mut map) = action_value {
-            map.insert("type".to_string(), json!(action_type));
-        }
-
-        // Wrap action if using agent
-        let final_action = if let Some(agent_address) = &self.agent {
-            let (_, agent_source) = self.infer_network();
-            json!({
-                "type": "agent",
-                "agentAddress": format!("{:#x}", agent_address),
-                "agentAction": action_value,
-                "source": agent_source,
-            })
-        } else {
-            action_value
-        };
-
-        self.post(final_action, signature, nonce).await
+        let signed = self.sign_l1_action(action_type, action).await?;
+        self.submit_signed(&signed).await
     }
 
     async fn send_user_action<T: HyperliquidAction + Serialize>(
         &self,
         action: &T,
     ) -> Result<ExchangeResponseStatus> {
-        let domain = action.domain();
-        let signing_hash = action.eip712_signing_hash(&domain);
-        let signature = self.signer.sign_hash(signing_hash).await?;
-
-        // Get action type from type name
-        // This extracts "UsdSend" from "ferrofluid::types::actions::UsdSend"
-        let action_type = std::any::type_name::<T>()
-            .split("::")
-            .last()
-            .unwrap_or("Unknown");
-
-        // Get action value and extract nonce
-        let mut action_value = serde_json::to_value(action)?;
-        let nonce = action_value
-            .get("time")
-            .or_else(|| action_value.get("nonce"))
-            .and_then(|v| v.as_u64())
-            .unwrap_or_else(Self::current_nonce);
-
-        // For ApproveAgent, we need to use camelCase type name to match SDK
-        let type_tag = match action_type {
-            "ApproveAgent" => "approveAgent",
-            "UsdSend" => "usdSend",
-            "Withdraw" => "withdraw3",
-            "SpotSend" => "spotSend",
-            "ApproveBuilderFee" => "approveBuilderFee",
-            _ => action_type,
-        };
-
-        // Add type tag
-        if let Value::Object(ref mut map) = action_value {
-            map.insert("type".to_string(), json!(type_tag));
-        }
-
-        // Send directly without L1 wrapping for user actions
-        self.post(action_value, signature, nonce).await
-    }
-
-    async fn post(
-        &self,
-        action: Value,
-        signature: HyperliquidSignature,
-        nonce: u64,
-    ) -> Result<ExchangeResponseStatus> {
-        // Hyperliquid expects signature as an object with r, s, v fields
-        // not as a concatenated hex string
-        let payload = json!({
-            "action": action,
-            "signature": {
-                "r": format!("0x{:064x}", signature.r),
-                "s": format!("0x{:064x}", signature.s),
-                "v": signature.v,
-            },
-            "nonce": nonce,
-            "vaultAddress": self.vault_address,
-        });
-
-        let body = Full::new(Bytes::from(serde_json::to_vec(&payload)?));
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri(self.endpoint)
-            .header("Content-Type", "application/json")
-            .body(body)
-            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
-
-        let response = self
-            .client
-            .request(request)
-            .await
-            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
-        let status = response.status();
-        let body_bytes = response
-            .into_body()
-            .collect()
-            .await
-            .map_err(|e| HyperliquidError::Network(e.to_string()))?
-            .to_bytes();
-
-        // Always try to deserialize the response as ExchangeResponseStatus
-        // The API returns this format even for error status codes
-        serde_json::from_slice(&body_bytes).map_err(|e| {
-            // If deserialization fails and we have an error status,
-            // return the HTTP error with the body
-            if !status.is_success() {
-                let body_text = String::from_utf8_lossy(&body_bytes);
-                HyperliquidError::Http {
-                    status: status.as_u16(),
-                    body: body_text.to_string(),
-                }
-            } else {
-                HyperliquidError::InvalidResponse(format!(
-                    "Failed to parse exchange response: {}",
-                    e
-                ))
-            }
-        })
+        let signed = self.sign_user_action(action).await?;
+        self.submit_signed(&signed).await
     }
 }
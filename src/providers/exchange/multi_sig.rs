@@ -0,0 +1,398 @@
+//! Collecting co-signer signatures for multi-sig actions.
+//!
+//! A [`MultiSigSession`] fixes the inner action and nonce, tells each
+//! authorized user what to sign, and checks signatures against the account's
+//! signers and weights until the threshold is met. Sessions serialize to JSON
+//! so they can be passed between machines holding different keys.
+//!
+//! # Example
+//! ```ignore
+//! // Outer signer creates the session
+//! let mut session = exchange
+//!     .multi_sig_session(multi_sig_user, "order", &bulk_order)
+//!     .await?;
+//! session.save("session.json")?;
+//!
+//! // Each co-signer adds their signature
+//! let mut session = MultiSigSession::load("session.json")?;
+//! session.sign(&co_signer).await?;
+//! session.save("session.json")?;
+//!
+//! // Outer signer submits once the threshold is met
+//! let response = exchange.submit_multi_sig(&session).await?;
+//! ```
+
+use std::path::Path;
+
+use alloy::primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    constants::Network,
+    errors::HyperliquidError,
+    signers::{
+        verify::{is_user_action, recover_from_hash, user_action_hash},
+        HyperliquidSigner, SignerError,
+    },
+    types::{
+        actions::{Agent, TaggedAction},
+        eip712::HyperliquidAction,
+        info_types::{MultiSigSignerInfo, MultiSigUserInfo},
+        responses::ExchangeResponseStatus,
+    },
+};
+
+use super::{
+    hash_msgpack,
+    signed::{user_action_nonce, user_action_type},
+    ActionSignature, RawExchangeProvider,
+};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// A co-signer's signature over a session's signing hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectedSignature {
+    pub signer: Address,
+    pub signature: ActionSignature,
+}
+
+/// An inner action awaiting signatures from a multi-sig user's signers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiSigSession {
+    pub multi_sig_user: Address,
+    /// Authorized user that submits the action
+    pub outer_signer: Address,
+    /// Inner action with its `type` tag first
+    pub action: Value,
+    pub nonce: u64,
+    /// Agent source of the network L1 actions are signed for
+    pub source: String,
+    /// Total weight required to submit
+    pub threshold: u32,
+    pub signers: Vec<MultiSigSignerInfo>,
+    pub signatures: Vec<CollectedSignature>,
+}
+
+impl MultiSigSession {
+    /// Session for an L1 action (orders, cancels, leverage, ...).
+    ///
+    /// `action_type` is the action's `type` tag, as for
+    /// [`sign_l1_action`](RawExchangeProvider::sign_l1_action).
+    pub fn l1<T: Serialize>(
+        network: &Network,
+        multi_sig_user: Address,
+        outer_signer: Address,
+        signers: MultiSigUserInfo,
+        action_type: &str,
+        action: &T,
+        nonce: u64,
+    ) -> Result<Self> {
+        Self::new(
+            network,
+            multi_sig_user,
+            outer_signer,
+            signers,
+            tagged(action_type, action)?,
+            nonce,
+        )
+    }
+
+    /// Session for a user action (`usdSend`, `withdraw3`, ...).
+    ///
+    /// The nonce is the action's `time` or `nonce` field.
+    pub fn user_action<T: HyperliquidAction + Serialize>(
+        network: &Network,
+        multi_sig_user: Address,
+        outer_signer: Address,
+        signers: MultiSigUserInfo,
+        action: &T,
+    ) -> Result<Self> {
+        let action = tagged(user_action_type::<T>(), action)?;
+        let nonce = user_action_nonce(&action).ok_or_else(|| {
+            HyperliquidError::InvalidRequest("user action has no time or nonce".into())
+        })?;
+        Self::new(
+            network,
+            multi_sig_user,
+            outer_signer,
+            signers,
+            action,
+            nonce,
+        )
+    }
+
+    fn new(
+        network: &Network,
+        multi_sig_user: Address,
+        outer_signer: Address,
+        signers: MultiSigUserInfo,
+        action: Value,
+        nonce: u64,
+    ) -> Result<Self> {
+        let session = Self {
+            multi_sig_user,
+            outer_signer,
+            action,
+            nonce,
+            source: network.agent_source().to_string(),
+            threshold: signers.threshold,
+            signers: signers.signers,
+            signatures: Vec::new(),
+        };
+        if session.weight_of(outer_signer).is_none() {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "outer signer {:#x} is not authorized for {:#x}",
+                outer_signer, multi_sig_user
+            )));
+        }
+        // Fail early on action types that cannot be hashed
+        session.signing_hash()?;
+        Ok(session)
+    }
+
+    /// The inner action's `type` tag
+    pub fn action_type(&self) -> Option<&str> {
+        self.action.get("type").and_then(Value::as_str)
+    }
+
+    /// The hash every co-signer signs.
+    ///
+    /// L1 actions are hashed as `[multi_sig_user, outer_signer, action]`;
+    /// user actions gain `payloadMultiSigUser` and `outerSigner` fields.
+    pub fn signing_hash(&self) -> Result<B256> {
+        let action_type = self.action_type().ok_or_else(|| {
+            HyperliquidError::InvalidRequest("action has no type".into())
+        })?;
+        let user = format!("{:#x}", self.multi_sig_user);
+        let outer_signer = format!("{:#x}", self.outer_signer);
+
+        if is_user_action(action_type) {
+            let fields: Map<String, Value> = self
+                .action
+                .as_object()
+                .into_iter()
+                .flatten()
+                .filter(|(key, _)| key.as_str() != "type")
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            return user_action_hash(
+                action_type,
+                fields,
+                Some((self.multi_sig_user, self.outer_signer)),
+            );
+        }
+
+        // Hash the inner action in its struct's field order
+        let action: TaggedAction = serde_json::from_value(self.action.clone())?;
+        let envelope = (user, outer_signer, action);
+        let agent = Agent {
+            source: self.source.clone(),
+            connection_id: hash_msgpack(&envelope, self.nonce, None)?,
+        };
+        Ok(agent.eip712_signing_hash(&agent.domain()))
+    }
+
+    /// Weight of `signer`, or `None` if it is not an authorized user
+    pub fn weight_of(&self, signer: Address) -> Option<u32> {
+        self.signers
+            .iter()
+            .find(|info| info.address == signer)
+            .map(|info| info.weight)
+    }
+
+    /// Total weight of the collected signatures
+    pub fn signed_weight(&self) -> u32 {
+        self.signatures
+            .iter()
+            .filter_map(|collected| self.weight_of(collected.signer))
+            .sum()
+    }
+
+    /// Whether the collected signatures meet the threshold
+    pub fn is_ready(&self) -> bool {
+        self.signed_weight() >= self.threshold
+    }
+
+    /// Sign the session with one of the authorized users' keys.
+    pub async fn sign<S: HyperliquidSigner>(&mut self, signer: &S) -> Result<()> {
+        self.check_authorized(signer.address())?;
+        let signature = signer.sign_hash(self.signing_hash()?).await?;
+        self.add_signature(signer.address(), signature.into())
+    }
+
+    /// Add a signature made elsewhere.
+    ///
+    /// The signature must recover to `signer`, an authorized user. A later
+    /// signature from the same signer replaces the earlier one.
+    pub fn add_signature(
+        &mut self,
+        signer: Address,
+        signature: ActionSignature,
+    ) -> Result<()> {
+        self.check_authorized(signer)?;
+        let recovered = recover_from_hash(self.signing_hash()?, &signature)?;
+        if recovered != signer {
+            return Err(HyperliquidError::Signer(SignerError::InvalidSignature(
+                format!("signed by {:#x}, not {:#x}", recovered, signer),
+            )));
+        }
+
+        self.signatures
+            .retain(|collected| collected.signer != signer);
+        self.signatures
+            .push(CollectedSignature { signer, signature });
+        Ok(())
+    }
+
+    /// Write the session as JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = serde_json::to_vec_pretty(self)?;
+        std::fs::write(path, json).map_err(|e| {
+            HyperliquidError::InvalidRequest(format!("Failed to write session: {}", e))
+        })
+    }
+
+    /// Read a session written by [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read(path).map_err(|e| {
+            HyperliquidError::InvalidRequest(format!("Failed to read session: {}", e))
+        })?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    fn check_authorized(&self, signer: Address) -> Result<()> {
+        match self.weight_of(signer) {
+            Some(_) => Ok(()),
+            None => Err(HyperliquidError::InvalidRequest(format!(
+                "{:#x} is not an authorized signer for {:#x}",
+                signer, self.multi_sig_user
+            ))),
+        }
+    }
+}
+
+impl<S: HyperliquidSigner> RawExchangeProvider<S> {
+    // ==================== Multi-Sig Sessions ====================
+
+    /// Start a session for an L1 action on `multi_sig_user`, submitted by
+    /// this provider's signer.
+    ///
+    /// Signers and the threshold are fetched with `userToMultiSigSigners`.
+    pub async fn multi_sig_session<T: Serialize>(
+        &self,
+        multi_sig_user: Address,
+        action_type: &str,
+        action: &T,
+    ) -> Result<MultiSigSession> {
+        let signers = self
+            .info()
+            .user_to_multi_sig_signers(multi_sig_user)
+            .await?;
+        MultiSigSession::l1(
+            &self.network,
+            multi_sig_user,
+            self.signer.address(),
+            signers,
+            action_type,
+            action,
+            self.next_nonce(),
+        )
+    }
+
+    /// Start a session for a user action on `multi_sig_user`, submitted by
+    /// this provider's signer.
+    pub async fn multi_sig_user_session<T: HyperliquidAction + Serialize>(
+        &self,
+        multi_sig_user: Address,
+        action: &T,
+    ) -> Result<MultiSigSession> {
+        let signers = self
+            .info()
+            .user_to_multi_sig_signers(multi_sig_user)
+            .await?;
+        MultiSigSession::user_action(
+            &self.network,
+            multi_sig_user,
+            self.signer.address(),
+            signers,
+            action,
+        )
+    }
+
+    /// Submit a session whose signatures meet its threshold.
+    ///
+    /// This provider's signer must be the session's outer signer.
+    pub async fn submit_multi_sig(
+        &self,
+        session: &MultiSigSession,
+    ) -> Result<ExchangeResponseStatus> {
+        if self.signer.address() != session.outer_signer {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "session must be submitted by {:#x}",
+                session.outer_signer
+            )));
+        }
+        if !session.is_ready() {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "signed weight {} is below threshold {}",
+                session.signed_weight(),
+                session.threshold
+            )));
+        }
+
+        let signatures = session
+            .signatures
+            .iter()
+            .map(|collected| {
+                let signature = &collected.signature;
+                let v = u8::try_from(signature.v).map_err(|_| {
+                    HyperliquidError::Signer(SignerError::InvalidSignature(format!(
+                        "v out of range: {}",
+                        signature.v
+                    )))
+                })?;
+                Ok((minimal_hex(&signature.r)?, minimal_hex(&signature.s)?, v))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.multi_sig(
+            session.multi_sig_user,
+            session.action.clone(),
+            signatures,
+            session.nonce,
+        )
+        .await
+    }
+}
+
+/// The action as a JSON object with its `type` tag.
+fn tagged<T: Serialize>(action_type: &str, action: &T) -> Result<Value> {
+    let mut tagged = Map::new();
+    tagged.insert("type".to_string(), Value::from(action_type));
+    match serde_json::to_value(action)? {
+        Value::Object(fields) => tagged.extend(fields),
+        _ => {
+            return Err(HyperliquidError::InvalidRequest(
+                "action is not an object".into(),
+            ))
+        }
+    }
+    Ok(Value::Object(tagged))
+}
+
+/// Signature components are hashed into the `multiSig` action as unpadded hex,
+/// matching the reference SDK.
+fn minimal_hex(component: &str) -> Result<String> {
+    component
+        .parse::<U256>()
+        .map(|value| format!("{:#x}", value))
+        .map_err(|e| {
+            HyperliquidError::Signer(SignerError::InvalidSignature(format!(
+                "bad component {:?}: {}",
+                component, e
+            )))
+        })
+}
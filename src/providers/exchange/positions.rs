@@ -0,0 +1,308 @@
+//! Closing positions and flattening an account.
+
+use crate::{
+    constants::{DEFAULT_SLIPPAGE, TIF_IOC},
+    errors::HyperliquidError,
+    providers::asset_registry::AssetInfo,
+    signers::HyperliquidSigner,
+    types::{
+        info_types::{AssetPosition, OpenOrdersResponse},
+        requests::{CancelRequest, OrderRequest},
+        responses::{ExchangeDataStatus, ExchangeResponseStatus, FilledOrder},
+        Symbol,
+    },
+};
+
+use super::{round_size, rounding::scaled_size, RawExchangeProvider, RoundingMode};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// A position reduced by a close order.
+#[derive(Debug, Clone)]
+pub struct ClosedPosition {
+    pub coin: String,
+    pub asset: u32,
+    /// Size sent in the reduce-only order
+    pub sz: String,
+    /// Average price and size actually filled
+    pub fill: FilledOrder,
+}
+
+/// A close order or cancel that did not go through.
+#[derive(Debug, Clone)]
+pub struct CloseFailure {
+    pub coin: String,
+    /// Order id for failed cancels, `None` for positions
+    pub oid: Option<u64>,
+    pub reason: String,
+}
+
+/// Outcome of [`RawExchangeProvider::close_position`] and
+/// [`RawExchangeProvider::close_all_positions`].
+#[derive(Debug, Clone, Default)]
+pub struct CloseReport {
+    pub closed: Vec<ClosedPosition>,
+    /// Ids of open orders that were cancelled
+    pub cancelled: Vec<u64>,
+    pub failed: Vec<CloseFailure>,
+}
+
+impl CloseReport {
+    /// Whether every close order filled and every cancel succeeded
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    fn fail(&mut self, coin: &str, oid: Option<u64>, reason: impl ToString) {
+        self.failed.push(CloseFailure {
+            coin: coin.to_string(),
+            oid,
+            reason: reason.to_string(),
+        });
+    }
+}
+
+/// Signed size of an open position, `None` when flat or unparseable
+fn open_size(position: &AssetPosition) -> Option<String> {
+    let szi = position.position.szi.to_string();
+    szi.parse::<f64>()
+        .is_ok_and(|value| value != 0.0)
+        .then_some(szi)
+}
+
+/// Per-order results of a bulk action, or the reason the whole action failed
+fn statuses(
+    response: Result<ExchangeResponseStatus>,
+    count: usize,
+) -> std::result::Result<Vec<ExchangeDataStatus>, String> {
+    let response = match response {
+        Ok(ExchangeResponseStatus::Ok(response)) => response,
+        Ok(ExchangeResponseStatus::Err(msg)) => return Err(msg),
+        Err(e) => return Err(e.to_string()),
+    };
+    let statuses = response.data.map(|data| data.statuses).unwrap_or_default();
+    if statuses.len() != count {
+        return Err(format!(
+            "expected {} statuses in response, got {}",
+            count,
+            statuses.len()
+        ));
+    }
+    Ok(statuses)
+}
+
+impl<S: HyperliquidSigner> RawExchangeProvider<S> {
+    /// Close `fraction` (in `(0, 1]`) of the perpetual position in `symbol`.
+    ///
+    /// Sends a reduce-only IOC order priced [`DEFAULT_SLIPPAGE`] beyond the book,
+    /// with the size rounded down to the lot size. A full close (`fraction` of
+    /// `1.0`) also cancels the open orders in `symbol`. Requires an asset
+    /// registry.
+    ///
+    /// Errors are returned when the account state cannot be read or there is no
+    /// position; orders and cancels that fail are listed in the report.
+    pub async fn close_position(
+        &self,
+        symbol: impl Into<Symbol>,
+        fraction: f64,
+    ) -> Result<CloseReport> {
+        if !(fraction > 0.0 && fraction <= 1.0) {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "fraction must be in (0, 1], got {}",
+                fraction
+            )));
+        }
+        let asset = self.resolve_asset(symbol).await?;
+        let info = self.info();
+        let user = self.account_address();
+
+        let state = info.user_state(user).await?;
+        let szi = state
+            .asset_positions
+            .iter()
+            .filter(|position| position.position.coin == asset.coin)
+            .find_map(open_size)
+            .ok_or_else(|| {
+                HyperliquidError::InvalidRequest(format!(
+                    "no open position in {}",
+                    asset.coin
+                ))
+            })?;
+        let open_orders = if fraction == 1.0 {
+            info.open_orders(user).await?
+        } else {
+            Vec::new()
+        };
+
+        let mut report = CloseReport::default();
+        self.send_close_orders(vec![(asset.clone(), szi)], fraction, &mut report)
+            .await;
+        let open_orders = open_orders
+            .into_iter()
+            .filter(|order| order.coin == asset.coin)
+            .collect();
+        self.cancel_all(open_orders, &mut report).await;
+        Ok(report)
+    }
+
+    /// Close every perpetual position and cancel every open order.
+    ///
+    /// Positions are closed with one bulk request of reduce-only IOC orders, then
+    /// the open orders are cancelled with one bulk cancel. Requires an asset
+    /// registry.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let report = provider.close_all_positions().await?;
+    /// for failure in &report.failed {
+    ///     eprintln!("{}: {}", failure.coin, failure.reason);
+    /// }
+    /// ```
+    pub async fn close_all_positions(&self) -> Result<CloseReport> {
+        let registry = self.require_asset_registry()?;
+        let info = self.info();
+        let user = self.account_address();
+        let (state, open_orders) =
+            tokio::try_join!(info.user_state(user), info.open_orders(user))?;
+
+        let mut report = CloseReport::default();
+        let mut positions = Vec::new();
+        for position in &state.asset_positions {
+            let Some(szi) = open_size(position) else {
+                continue;
+            };
+            match registry.resolve(&position.position.coin).await {
+                Ok(asset) => positions.push((asset, szi)),
+                Err(e) => report.fail(&position.position.coin, None, e),
+            }
+        }
+
+        self.send_close_orders(positions, 1.0, &mut report).await;
+        self.cancel_all(open_orders, &mut report).await;
+        Ok(report)
+    }
+
+    async fn send_close_orders(
+        &self,
+        positions: Vec<(AssetInfo, String)>,
+        fraction: f64,
+        report: &mut CloseReport,
+    ) {
+        let mut orders = Vec::new();
+        let mut sent = Vec::new();
+        for (asset, szi) in positions {
+            match self.close_order(&asset, &szi, fraction).await {
+                Ok(order) => {
+                    sent.push((asset, order.sz.clone()));
+                    orders.push(order);
+                }
+                Err(e) => report.fail(&asset.coin, None, e),
+            }
+        }
+        if orders.is_empty() {
+            return;
+        }
+
+        let count = orders.len();
+        match statuses(self.bulk_orders(orders).await, count) {
+            Ok(statuses) => {
+                for ((asset, sz), status) in sent.into_iter().zip(statuses) {
+                    match status {
+                        ExchangeDataStatus::Filled(fill) => {
+                            report.closed.push(ClosedPosition {
+                                coin: asset.coin,
+                                asset: asset.asset,
+                                sz,
+                                fill,
+                            })
+                        }
+                        ExchangeDataStatus::Error(msg) => {
+                            report.fail(&asset.coin, None, msg)
+                        }
+                        other => report.fail(
+                            &asset.coin,
+                            None,
+                            format!("close order did not fill: {:?}", other),
+                        ),
+                    }
+                }
+            }
+            Err(reason) => {
+                for (asset, _) in sent {
+                    report.fail(&asset.coin, None, &reason);
+                }
+            }
+        }
+    }
+
+    /// Reduce-only IOC order closing `fraction` of a position of signed size `szi`
+    async fn close_order(
+        &self,
+        asset: &AssetInfo,
+        szi: &str,
+        fraction: f64,
+    ) -> Result<OrderRequest> {
+        let (is_buy, size) = match szi.strip_prefix('-') {
+            Some(short) => (true, short),
+            None => (false, szi),
+        };
+        let sz = if fraction == 1.0 {
+            size.to_string()
+        } else {
+            scaled_size(size, fraction).ok_or_else(|| {
+                HyperliquidError::InvalidResponse(format!(
+                    "invalid position size {:?}",
+                    szi
+                ))
+            })?
+        };
+        let sz = round_size(&sz, asset, RoundingMode::Passive)?;
+        let px = self.market_price(asset, is_buy, DEFAULT_SLIPPAGE).await?;
+        Ok(OrderRequest::limit(asset.asset, is_buy, px, sz, TIF_IOC).reduce_only(true))
+    }
+
+    async fn cancel_all(
+        &self,
+        open_orders: Vec<OpenOrdersResponse>,
+        report: &mut CloseReport,
+    ) {
+        let mut cancels = Vec::new();
+        let mut sent = Vec::new();
+        for order in open_orders {
+            let asset = match self.resolve_asset(order.coin.clone()).await {
+                Ok(asset) => asset,
+                Err(e) => {
+                    report.fail(&order.coin, Some(order.oid), e);
+                    continue;
+                }
+            };
+            cancels.push(CancelRequest {
+                asset: asset.asset,
+                oid: order.oid,
+            });
+            sent.push(order);
+        }
+        if cancels.is_empty() {
+            return;
+        }
+
+        let count = cancels.len();
+        match statuses(self.bulk_cancel(cancels).await, count) {
+            Ok(statuses) => {
+                for (order, status) in sent.into_iter().zip(statuses) {
+                    match status {
+                        ExchangeDataStatus::Error(msg) => {
+                            report.fail(&order.coin, Some(order.oid), msg)
+                        }
+                        _ => report.cancelled.push(order.oid),
+                    }
+                }
+            }
+            Err(reason) => {
+                for order in sent {
+                    report.fail(&order.coin, Some(order.oid), &reason);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,332 @@
+//! Tick and lot rules for order prices and sizes.
+//!
+//! Hyperliquid rejects an order whose size has more than `szDecimals` decimals,
+//! or whose price has more than 5 significant figures or more than
+//! `MAX_DECIMALS - szDecimals` decimals (6 for perps, 8 for spot). Integer prices
+//! are always accepted. Values are handled as exact decimal strings, so rounding
+//! never picks up binary floating point noise.
+
+use crate::{
+    constants::{MAX_DECIMALS_PERP, MAX_DECIMALS_SPOT, MAX_PRICE_SIGNIFICANT_FIGURES},
+    errors::HyperliquidError,
+    providers::asset_registry::{AssetInfo, AssetKind},
+    types::requests::{OrderRequest, OrderType},
+};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// How prices and sizes that break the tick and lot rules are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Round prices and sizes to the nearest valid value
+    Nearest,
+    /// Round prices away from the spread (buys down, sells up) so a resting
+    /// order never becomes more aggressive; sizes round down
+    Passive,
+    /// Reject invalid values with an error naming the violated rule
+    #[default]
+    Strict,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Nearest,
+    Down,
+    Up,
+}
+
+/// Non-negative decimal `mantissa * 10^-scale`, kept without trailing zeros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fixed {
+    mantissa: u128,
+    scale: u32,
+}
+
+impl Fixed {
+    fn parse(value: &str) -> Option<Self> {
+        let (int, frac) = value.trim().split_once('.').unwrap_or((value.trim(), ""));
+        if int.is_empty() && frac.is_empty()
+            || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let mut mantissa: u128 = 0;
+        for digit in int.bytes().chain(frac.bytes()) {
+            mantissa = mantissa
+                .checked_mul(10)?
+                .checked_add(u128::from(digit - b'0'))?;
+        }
+        Some(
+            Self {
+                mantissa,
+                scale: frac.len() as u32,
+            }
+            .normalize(),
+        )
+    }
+
+    fn normalize(mut self) -> Self {
+        while self.scale > 0 && self.mantissa % 10 == 0 {
+            self.mantissa /= 10;
+            self.scale -= 1;
+        }
+        if self.mantissa == 0 {
+            self.scale = 0;
+        }
+        self
+    }
+
+    fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Power of ten of the leading digit, e.g. 2 for 123.4 and -3 for 0.0012
+    fn magnitude(self) -> i64 {
+        let digits = self.mantissa.checked_ilog10().unwrap_or(0) as i64 + 1;
+        digits - 1 - self.scale as i64
+    }
+
+    /// Mantissa at `scale`, which must not be below `self.scale`
+    fn mantissa_at(self, scale: u32) -> Option<u128> {
+        self.mantissa
+            .checked_mul(10u128.checked_pow(scale - self.scale)?)
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self
+            .mantissa_at(scale)?
+            .checked_add(other.mantissa_at(scale)?)?;
+        Some(Self { mantissa, scale }.normalize())
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let mantissa = self
+            .mantissa_at(scale)?
+            .checked_sub(other.mantissa_at(scale)?)?;
+        Some(Self { mantissa, scale }.normalize())
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(
+            Self {
+                mantissa: self.mantissa.checked_mul(other.mantissa)?,
+                scale: self.scale + other.scale,
+            }
+            .normalize(),
+        )
+    }
+
+    fn round(self, decimals: u32, direction: Direction) -> Self {
+        if self.scale <= decimals {
+            return self;
+        }
+        let factor = 10u128.pow(self.scale - decimals);
+        let (quotient, remainder) = (self.mantissa / factor, self.mantissa % factor);
+        let round_up = match direction {
+            Direction::Down => false,
+            Direction::Up => remainder > 0,
+            Direction::Nearest => remainder >= factor - remainder,
+        };
+        Self {
+            mantissa: quotient + u128::from(round_up),
+            scale: decimals,
+        }
+        .normalize()
+    }
+}
+
+impl std::fmt::Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = format!(
+            "{:0>width$}",
+            self.mantissa,
+            width = self.scale as usize + 1
+        );
+        let (int, frac) = digits.split_at(digits.len() - self.scale as usize);
+        if frac.is_empty() {
+            f.write_str(int)
+        } else {
+            write!(f, "{}.{}", int, frac)
+        }
+    }
+}
+
+fn parse(value: &str, field: &str, asset: &AssetInfo) -> Result<Fixed> {
+    Fixed::parse(value).ok_or_else(|| {
+        HyperliquidError::InvalidRequest(format!(
+            "{} {:?} for {} is not a non-negative decimal number",
+            field, value, asset.coin
+        ))
+    })
+}
+
+/// `px` moved by the fraction `slippage` against an order: up for buys, down
+/// for sells. `None` if `px` is not a non-negative decimal or the result would
+/// be negative.
+pub(crate) fn slipped_price(px: &str, slippage: f64, is_buy: bool) -> Option<String> {
+    let px = Fixed::parse(px)?;
+    let offset = px.checked_mul(Fixed::parse(&slippage.to_string())?)?;
+    let bound = if is_buy {
+        px.checked_add(offset)?
+    } else {
+        px.checked_sub(offset)?
+    };
+    Some(bound.to_string())
+}
+
+/// `sz * fraction` to 8 decimals. The product is exact, so only the binary
+/// approximation of `fraction` (such as `1.0 / 3.0`) is rounded away.
+pub(crate) fn scaled_size(sz: &str, fraction: f64) -> Option<String> {
+    let product = Fixed::parse(sz)?.checked_mul(Fixed::parse(&fraction.to_string())?)?;
+    Some(product.round(8, Direction::Nearest).to_string())
+}
+
+/// Decimals a price of this magnitude may carry, `None` for integers
+fn allowed_price_decimals(px: Fixed, asset: &AssetInfo) -> Option<u32> {
+    if px.scale == 0 {
+        return None;
+    }
+    let sig_fig_decimals =
+        (i64::from(MAX_PRICE_SIGNIFICANT_FIGURES) - 1 - px.magnitude()).max(0) as u32;
+    Some(sig_fig_decimals.min(asset.max_price_decimals()))
+}
+
+/// Apply the price rules to `px` for an order on `asset`.
+///
+/// Returns the canonical string form (no trailing zeros).
+pub fn round_price(
+    px: &str,
+    is_buy: bool,
+    asset: &AssetInfo,
+    mode: RoundingMode,
+) -> Result<String> {
+    let value = parse(px, "price", asset)?;
+    if value.is_zero() {
+        return Err(HyperliquidError::InvalidRequest(format!(
+            "price for {} must be positive",
+            asset.coin
+        )));
+    }
+
+    let allowed = match allowed_price_decimals(value, asset) {
+        Some(allowed) if value.scale > allowed => allowed,
+        _ => return Ok(value.to_string()),
+    };
+
+    let direction = match mode {
+        RoundingMode::Strict => {
+            let max_decimals = asset.max_price_decimals();
+            let rule = if value.scale > max_decimals {
+                let base = match asset.kind {
+                    AssetKind::Perp => MAX_DECIMALS_PERP,
+                    AssetKind::Spot => MAX_DECIMALS_SPOT,
+                };
+                format!(
+                    "has more than {} decimals (MAX_DECIMALS {} - szDecimals {})",
+                    max_decimals, base, asset.sz_decimals
+                )
+            } else {
+                format!(
+                    "has more than {} significant figures",
+                    MAX_PRICE_SIGNIFICANT_FIGURES
+                )
+            };
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "price {} for {} {}",
+                px, asset.coin, rule
+            )));
+        }
+        RoundingMode::Nearest => Direction::Nearest,
+        RoundingMode::Passive if is_buy => Direction::Down,
+        RoundingMode::Passive => Direction::Up,
+    };
+
+    let mut rounded = value.round(allowed, direction);
+    // Rounding up can gain an integer digit and with it lose a decimal
+    if let Some(allowed) = allowed_price_decimals(rounded, asset) {
+        rounded = rounded.round(allowed, direction);
+    }
+    if rounded.is_zero() {
+        return Err(HyperliquidError::InvalidRequest(format!(
+            "price {} for {} rounds to zero at {} decimals",
+            px, asset.coin, allowed
+        )));
+    }
+    Ok(rounded.to_string())
+}
+
+/// Apply the lot rule (`szDecimals`) to `sz` for an order on `asset`.
+///
+/// Returns the canonical string form (no trailing zeros).
+pub fn round_size(sz: &str, asset: &AssetInfo, mode: RoundingMode) -> Result<String> {
+    let value = parse(sz, "size", asset)?;
+    if value.scale <= asset.sz_decimals {
+        return Ok(value.to_string());
+    }
+
+    let direction = match mode {
+        RoundingMode::Strict => {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "size {} for {} has more than {} decimals (szDecimals)",
+                sz, asset.coin, asset.sz_decimals
+            )))
+        }
+        RoundingMode::Nearest => Direction::Nearest,
+        RoundingMode::Passive => Direction::Down,
+    };
+
+    let rounded = value.round(asset.sz_decimals, direction);
+    if rounded.is_zero() {
+        return Err(HyperliquidError::InvalidRequest(format!(
+            "size {} for {} rounds to zero at {} decimals (szDecimals)",
+            sz, asset.coin, asset.sz_decimals
+        )));
+    }
+    Ok(rounded.to_string())
+}
+
+/// `order` with its prices and size in canonical form (no trailing zeros), for
+/// orders on assets whose rules are unknown.
+pub(crate) fn canonical_order(order: &OrderRequest) -> Result<OrderRequest> {
+    fn canonical(value: &str, field: &str) -> Result<String> {
+        Fixed::parse(value)
+            .map(|value| value.to_string())
+            .ok_or_else(|| {
+                HyperliquidError::InvalidRequest(format!("Invalid {} format", field))
+            })
+    }
+
+    let mut order = order.clone();
+    order.limit_px = canonical(&order.limit_px, "limit_px")?;
+    order.sz = canonical(&order.sz, "sz")?;
+    if let OrderType::Trigger(trigger) = &mut order.order_type {
+        trigger.trigger_px = canonical(&trigger.trigger_px, "trigger_px")?;
+    }
+    Ok(order)
+}
+
+/// Apply the price and size rules to every price and the size of `order`.
+pub fn round_order(
+    order: &OrderRequest,
+    asset: &AssetInfo,
+    mode: RoundingMode,
+) -> Result<OrderRequest> {
+    let mut order = order.clone();
+    // `OrderRequest::trigger` leaves the limit price at zero; only the trigger
+    // price and size of such orders are subject to the rules
+    let unpriced_trigger = matches!(order.order_type, OrderType::Trigger(_))
+        && Fixed::parse(&order.limit_px).is_some_and(Fixed::is_zero);
+    order.limit_px = if unpriced_trigger {
+        "0".to_string()
+    } else {
+        round_price(&order.limit_px, order.is_buy, asset, mode)?
+    };
+    order.sz = round_size(&order.sz, asset, mode)?;
+    if let OrderType::Trigger(trigger) = &mut order.order_type {
+        trigger.trigger_px = round_price(&trigger.trigger_px, order.is_buy, asset, mode)?;
+    }
+    Ok(order)
+}
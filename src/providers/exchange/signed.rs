@@ -0,0 +1,248 @@
+//! Signing actions without sending them, and sending pre-signed actions.
+//!
+//! Useful when keys live on an isolated host: sign there, move the
+//! [`SignedAction`] JSON elsewhere and broadcast it with
+//! [`RawExchangeProvider::submit_signed`].
+
+use alloy::primitives::Address;
+use hyper::body::Bytes;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    errors::HyperliquidError,
+    signers::{HyperliquidSignature, HyperliquidSigner},
+    types::{
+        actions::{BulkCancel, UsdSend, Withdraw},
+        eip712::HyperliquidAction,
+        requests::{CancelRequest, Grouping, OrderRequest},
+        responses::ExchangeResponseStatus,
+    },
+};
+
+use super::{hash_action, Agent, RawExchangeProvider};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// Signature in the `{r, s, v}` form the exchange expects.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionSignature {
+    /// `r` as 0x-prefixed, zero-padded hex
+    pub r: String,
+    /// `s` as 0x-prefixed, zero-padded hex
+    pub s: String,
+    pub v: u64,
+}
+
+impl From<HyperliquidSignature> for ActionSignature {
+    fn from(signature: HyperliquidSignature) -> Self {
+        Self {
+            r: format!("0x{:064x}", signature.r),
+            s: format!("0x{:064x}", signature.s),
+            v: signature.v,
+        }
+    }
+}
+
+/// A signed action, serialized exactly as it is posted to `/exchange`.
+///
+/// # Example
+/// ```ignore
+/// // On the signing host
+/// let signed = signer_provider.sign_orders(vec![order]).await?;
+/// let json = serde_json::to_string(&signed)?;
+///
+/// // On the broadcasting host
+/// let signed: SignedAction = serde_json::from_str(&json)?;
+/// let response = provider.submit_signed(&signed).await?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedAction {
+    /// Action with its `type` tag
+    pub action: Value,
+    pub nonce: u64,
+    pub signature: ActionSignature,
+    pub vault_address: Option<Address>,
+}
+
+impl SignedAction {
+    /// The action's `type` tag (e.g. `"order"`, `"usdSend"`)
+    pub fn action_type(&self) -> Option<&str> {
+        self.action.get("type").and_then(Value::as_str)
+    }
+}
+
+impl<S: HyperliquidSigner> RawExchangeProvider<S> {
+    // ==================== Offline Signing ====================
+
+    /// Sign any L1 action (orders, cancels, leverage, TWAP, vault transfers,
+    /// ...) without sending it.
+    ///
+    /// `action_type` is the action's `type` tag, e.g. `"order"` for
+    /// [`BulkOrder`](crate::types::actions::BulkOrder) or `"cancel"` for
+    /// [`BulkCancel`]. The nonce comes from the provider's nonce manager.
+    pub async fn sign_l1_action<T: Serialize>(
+        &self,
+        action_type: &str,
+        action: &T,
+    ) -> Result<SignedAction> {
+        let nonce = self.next_nonce();
+        let connection_id = hash_action(action_type, action, nonce, self.vault_address)?;
+
+        // Create Agent L1 action
+        let (_, agent_source) = self.infer_network();
+        let agent = Agent {
+            source: agent_source.to_string(),
+            connection_id,
+        };
+
+        // Sign using EIP-712
+        let domain = agent.domain();
+        let signing_hash = agent.eip712_signing_hash(&domain);
+        let signature = self.signer.sign_hash(signing_hash).await?;
+
+        // Build action value with type tag
+        let mut action_value = serde_json::to_value(action)?;
+        if let Value::Object(ref mut map) = action_value {
+            map.insert("type".to_string(), json!(action_type));
+        }
+
+        // Agents sign the plain action; the exchange maps them to their account
+        Ok(self.signed(action_value, signature.into(), nonce))
+    }
+
+    /// Sign any EIP-712 user action (`usdSend`, `withdraw3`, `spotSend`,
+    /// `approveAgent`, ...) without sending it.
+    ///
+    /// The nonce is read from the action's `time` or `nonce` field; fill it
+    /// with [`next_nonce`](Self::next_nonce) to keep nonces increasing.
+    pub async fn sign_user_action<T: HyperliquidAction + Serialize>(
+        &self,
+        action: &T,
+    ) -> Result<SignedAction> {
+        let domain = action.domain();
+        let signing_hash = action.eip712_signing_hash(&domain);
+        let signature = self.signer.sign_hash(signing_hash).await?;
+
+        // Get action value and extract nonce
+        let mut action_value = serde_json::to_value(action)?;
+        let nonce = user_action_nonce(&action_value).unwrap_or_else(|| self.next_nonce());
+
+        // Add type tag
+        if let Value::Object(ref mut map) = action_value {
+            map.insert("type".to_string(), json!(user_action_type::<T>()));
+        }
+
+        // User actions are sent without L1 wrapping
+        Ok(self.signed(action_value, signature.into(), nonce))
+    }
+
+    /// Sign the action [`bulk_orders`](Self::bulk_orders) would send.
+    pub async fn sign_orders(&self, orders: Vec<OrderRequest>) -> Result<SignedAction> {
+        let orders = self.apply_rounding_all(orders).await?;
+        let bulk_order = self.bulk_order_action(orders, Grouping::Na, 0);
+        self.sign_l1_action("order", &bulk_order).await
+    }
+
+    /// Sign the action [`bulk_cancel`](Self::bulk_cancel) would send.
+    pub async fn sign_cancels(
+        &self,
+        cancels: Vec<CancelRequest>,
+    ) -> Result<SignedAction> {
+        self.sign_l1_action("cancel", &BulkCancel { cancels }).await
+    }
+
+    /// Sign the action [`usd_transfer`](Self::usd_transfer) would send.
+    pub async fn sign_usd_transfer(
+        &self,
+        destination: Address,
+        amount: &str,
+    ) -> Result<SignedAction> {
+        let action: UsdSend = self.usd_send_action(destination, amount);
+        self.sign_user_action(&action).await
+    }
+
+    /// Sign the action [`withdraw`](Self::withdraw) would send.
+    pub async fn sign_withdraw(
+        &self,
+        destination: Address,
+        amount: &str,
+    ) -> Result<SignedAction> {
+        let action: Withdraw = self.withdraw_action(destination, amount);
+        self.sign_user_action(&action).await
+    }
+
+    /// Send a pre-signed action as is.
+    ///
+    /// The payload is posted unchanged, so its nonce and vault address are the
+    /// ones it was signed with rather than this provider's.
+    pub async fn submit_signed(
+        &self,
+        signed: &SignedAction,
+    ) -> Result<ExchangeResponseStatus> {
+        let body = Bytes::from(serde_json::to_vec(signed)?);
+        let (status, body_bytes) = self.transport.post(&self.endpoint, body).await?;
+
+        // Always try to deserialize the response as ExchangeResponseStatus
+        // The API returns this format even for error status codes
+        serde_json::from_slice(&body_bytes).map_err(|e| {
+            // If deserialization fails and we have an error status,
+            // return the HTTP error with the body
+            if !(200..300).contains(&status) {
+                let body_text = String::from_utf8_lossy(&body_bytes);
+                HyperliquidError::Http {
+                    status,
+                    body: body_text.to_string(),
+                }
+            } else {
+                HyperliquidError::InvalidResponse(format!(
+                    "Failed to parse exchange response: {}",
+                    e
+                ))
+            }
+        })
+    }
+
+    pub(super) fn signed(
+        &self,
+        action: Value,
+        signature: ActionSignature,
+        nonce: u64,
+    ) -> SignedAction {
+        SignedAction {
+            action,
+            nonce,
+            signature,
+            vault_address: self.vault_address,
+        }
+    }
+}
+
+/// The `type` tag of a user action.
+pub(super) fn user_action_type<T>() -> &'static str {
+    // Get action type from type name
+    // This extracts "UsdSend" from "ferrofluid::types::actions::UsdSend"
+    let action_type = std::any::type_name::<T>()
+        .split("::")
+        .last()
+        .unwrap_or("Unknown");
+
+    // For ApproveAgent, we need to use camelCase type name to match SDK
+    match action_type {
+        "ApproveAgent" => "approveAgent",
+        "UsdSend" => "usdSend",
+        "Withdraw" => "withdraw3",
+        "SpotSend" => "spotSend",
+        "ApproveBuilderFee" => "approveBuilderFee",
+        _ => action_type,
+    }
+}
+
+/// The nonce a user action carries in its `time` or `nonce` field.
+pub(super) fn user_action_nonce(action: &Value) -> Option<u64> {
+    action
+        .get("time")
+        .or_else(|| action.get("nonce"))
+        .and_then(|v| v.as_u64())
+}
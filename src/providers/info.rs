@@ -1,30 +1,28 @@
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use alloy::primitives::Address;
-use http::{Method, Request};
-use http_body_util::{BodyExt, Full};
 use hyper::body::Bytes;
-use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
-use hyper_util::client::legacy::{connect::HttpConnector, Client};
-use hyper_util::rt::TokioExecutor;
 use serde_json::json;
+use uuid::Uuid;
 
 use crate::constants::Network;
 use crate::errors::HyperliquidError;
+use crate::providers::asset_registry::AssetRegistry;
+use crate::providers::transport::{HyperTransport, Transport};
 use crate::types::info_types::{
-    CandlesSnapshotResponse, Delegation, DelegatorHistoryEntry, DelegatorReward,
-    DelegatorSummary, ExtraAgent, FrontendOpenOrder, FundingHistoryResponse,
-    HistoricalOrder, L2SnapshotResponse, Meta, MetaAndAssetCtxs, MultiSigUserInfo,
-    NonFundingLedgerUpdate, OpenOrdersResponse, OrderStatusResponse,
+    CandleInterval, CandlesSnapshotResponse, Delegation, DelegatorHistoryEntry,
+    DelegatorReward, DelegatorSummary, ExtraAgent, FrontendOpenOrder,
+    FundingHistoryResponse, HistoricalOrder, L2SnapshotResponse, Meta, MetaAndAssetCtxs,
+    MultiSigUserInfo, NonFundingLedgerUpdate, OpenOrdersResponse, OrderStatusResponse,
     PerpDeployAuctionStatus, PerpDex, Portfolio, RecentTradesResponse, ReferralResponse,
     SpotDeployState, SpotMeta, SpotMetaAndAssetCtxs, SpotPairDeployAuctionStatus,
     SubAccount, TokenDetails, TwapSliceFill, UserDexAbstraction, UserFeesResponse,
     UserFillByTime, UserFillsResponse, UserFundingResponse, UserRateLimit, UserRole,
     UserStateResponse, UserTokenBalanceResponse, VaultEquity,
 };
-use crate::types::Symbol;
+use crate::types::{decimal::Num, Symbol};
 
 // Rate limiter implementation
 pub struct RateLimiter {
@@ -70,9 +68,11 @@ impl RateLimiter {
     }
 }
 
+#[derive(Clone)]
 pub struct InfoProvider {
-    client: Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
-    endpoint: &'static str,
+    transport: Arc<dyn Transport>,
+    endpoint: String,
+    asset_registry: Option<Arc<AssetRegistry>>,
 }
 
 impl InfoProvider {
@@ -85,24 +85,41 @@ impl InfoProvider {
     }
 
     pub fn new(network: Network) -> Self {
-        // Initialize rustls crypto provider if not already set
-        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+        Self {
+            transport: Arc::new(HyperTransport::new()),
+            endpoint: network.info_url(),
+            asset_registry: None,
+        }
+    }
 
-        let https = HttpsConnectorBuilder::new()
-            .with_native_roots()
-            .expect("TLS initialization failed")
-            .https_only()
-            .enable_http1()
-            .build();
+    /// Send requests through a custom transport (proxy, mTLS, in-memory mock, ...).
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = transport;
+        self
+    }
 
-        let client = Client::builder(TokioExecutor::new()).build(https);
+    /// Resolve the coin of coin-based requests (`l2Book`, `recentTrades`,
+    /// candles, funding history) through a shared registry, so spot pairs can
+    /// be named `BASE/QUOTE`.
+    ///
+    /// Build the registry from a clone taken before attaching it, e.g.
+    /// `AssetRegistry::new(info.clone())`.
+    pub fn with_asset_registry(mut self, registry: Arc<AssetRegistry>) -> Self {
+        self.asset_registry = Some(registry);
+        self
+    }
 
-        Self {
-            client,
-            endpoint: match network {
-                Network::Mainnet => "https://api.hyperliquid.xyz/info",
-                Network::Testnet => "https://api.hyperliquid-testnet.xyz/info",
-            },
+    /// Get the attached asset registry.
+    pub fn asset_registry(&self) -> Option<&Arc<AssetRegistry>> {
+        self.asset_registry.as_ref()
+    }
+
+    /// Coin name the API expects for `symbol`, resolved through the asset
+    /// registry when one is attached.
+    async fn coin(&self, symbol: &Symbol) -> Result<String, HyperliquidError> {
+        match &self.asset_registry {
+            Some(registry) => Ok(registry.resolve(symbol).await?.coin),
+            None => Ok(symbol.as_str().to_string()),
         }
     }
 
@@ -116,29 +133,13 @@ impl InfoProvider {
         let body_string = serde_json::to_string(&request_json)?;
         let body_bytes = Bytes::from(body_string);
 
-        let req = Request::builder()
-            .method(Method::POST)
-            .uri(self.endpoint)
-            .header("Content-Type", "application/json")
-            .body(Full::new(body_bytes))?;
-
-        let res = self
-            .client
-            .request(req)
-            .await
-            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
-        let status = res.status();
-
-        let body_bytes = res
-            .collect()
-            .await
-            .map_err(|e| HyperliquidError::Network(e.to_string()))?
-            .to_bytes();
-        let body_str = String::from_utf8_lossy(&body_bytes);
-
-        if !status.is_success() {
+        let (status, body_bytes) =
+            self.transport.post(&self.endpoint, body_bytes).await?;
+
+        if !(200..300).contains(&status) {
+            let body_str = String::from_utf8_lossy(&body_bytes);
             return Err(HyperliquidError::Http {
-                status: status.as_u16(),
+                status,
                 body: body_str.to_string(),
             });
         }
@@ -149,7 +150,7 @@ impl InfoProvider {
 
     // ==================== Simple Direct Methods ====================
 
-    pub async fn all_mids(&self) -> Result<HashMap<String, String>, HyperliquidError> {
+    pub async fn all_mids(&self) -> Result<HashMap<String, Num>, HyperliquidError> {
         let request = json!({
             "type": "allMids"
         });
@@ -171,10 +172,10 @@ impl InfoProvider {
         &self,
         coin: impl Into<Symbol>,
     ) -> Result<L2SnapshotResponse, HyperliquidError> {
-        let symbol = coin.into();
+        let coin = self.coin(&coin.into()).await?;
         let request = json!({
             "type": "l2Book",
-            "coin": symbol.as_str()
+            "coin": coin
         });
         self.request(request).await
     }
@@ -192,6 +193,20 @@ impl InfoProvider {
         self.request(request).await
     }
 
+    /// Get the status of an order by its client order id
+    pub async fn order_status_by_cloid(
+        &self,
+        user: Address,
+        cloid: Uuid,
+    ) -> Result<OrderStatusResponse, HyperliquidError> {
+        let request = json!({
+            "type": "orderStatus",
+            "user": user,
+            "oid": format!("{:#034x}", cloid.as_u128())
+        });
+        self.request(request).await
+    }
+
     pub async fn open_orders(
         &self,
         user: Address,
@@ -248,10 +263,10 @@ impl InfoProvider {
         &self,
         coin: impl Into<Symbol>,
     ) -> Result<Vec<RecentTradesResponse>, HyperliquidError> {
-        let symbol = coin.into();
+        let coin = self.coin(&coin.into()).await?;
         let request = json!({
             "type": "recentTrades",
-            "coin": symbol.as_str()
+            "coin": coin
         });
         self.request(request).await
     }
@@ -285,6 +300,20 @@ impl InfoProvider {
         self.request(request).await
     }
 
+    /// Get perpetual metadata for a builder-deployed DEX
+    ///
+    /// Coin names in the returned universe carry the `dex:` prefix.
+    pub async fn perp_dex_meta(
+        &self,
+        dex: impl Into<String>,
+    ) -> Result<Meta, HyperliquidError> {
+        let request = json!({
+            "type": "meta",
+            "dex": dex.into()
+        });
+        self.request(request).await
+    }
+
     pub async fn spot_meta(&self) -> Result<SpotMeta, HyperliquidError> {
         let request = json!({
             "type": "spotMeta"
@@ -581,8 +610,8 @@ impl InfoProvider {
 
     /// Get available perpetual DEXs
     ///
-    /// Returns list of DEXs and their listed coins.
-    pub async fn perp_dexs(&self) -> Result<Vec<PerpDex>, HyperliquidError> {
+    /// Entries are indexed by DEX id; index 0 is the main DEX and is `None`.
+    pub async fn perp_dexs(&self) -> Result<Vec<Option<PerpDex>>, HyperliquidError> {
         let request = json!({
             "type": "perpDexs"
         });
@@ -658,14 +687,14 @@ impl InfoProvider {
 pub struct CandlesRequestBuilder<'a> {
     provider: &'a InfoProvider,
     coin: Symbol,
-    interval: Option<String>,
+    interval: Option<CandleInterval>,
     start_time: Option<u64>,
     end_time: Option<u64>,
 }
 
 impl<'a> CandlesRequestBuilder<'a> {
-    pub fn interval(mut self, interval: impl Into<String>) -> Self {
-        self.interval = Some(interval.into());
+    pub fn interval(mut self, interval: CandleInterval) -> Self {
+        self.interval = Some(interval);
         self
     }
 
@@ -696,10 +725,11 @@ impl<'a> CandlesRequestBuilder<'a> {
             HyperliquidError::InvalidRequest("end_time is required".into())
         })?;
 
+        let coin = self.provider.coin(&self.coin).await?;
         let request = json!({
             "type": "candleSnapshot",
             "req": {
-                "coin": self.coin.as_str(),
+                "coin": coin,
                 "interval": interval,
                 "startTime": start_time,
                 "endTime": end_time
@@ -739,9 +769,10 @@ impl<'a> FundingHistoryBuilder<'a> {
             HyperliquidError::InvalidRequest("start_time is required".into())
         })?;
 
+        let coin = self.provider.coin(&self.coin).await?;
         let mut request = json!({
             "type": "fundingHistory",
-            "coin": self.coin.as_str(),
+            "coin": coin,
             "startTime": start_time
         });
 
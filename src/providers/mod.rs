@@ -1,10 +1,18 @@
+pub mod account_state;
 pub mod agent;
+pub mod asset_registry;
 pub mod batcher;
+pub mod candles;
+pub mod dead_mans_switch;
 pub mod exchange;
 pub mod info;
 pub mod nonce;
+pub mod order_book;
+pub mod order_store;
 pub mod order_tracker;
+pub mod transport;
 pub mod websocket;
+pub mod ws_channel;
 
 // Raw providers (backwards compatibility)
 pub use exchange::RawExchangeProvider as ExchangeProvider;
@@ -22,7 +30,29 @@ pub use exchange::{
 pub use websocket::{ManagedWsProvider, WsConfig};
 
 // Common types
+pub use account_state::{
+    AccountMargin, AccountSnapshot, AccountState, AccountStateConfig, OpenOrder,
+    Position, PositionLeverage, SpotBalance,
+};
+pub use asset_registry::{AssetInfo, AssetKind, AssetRegistry};
 pub use batcher::OrderHandle;
-pub use exchange::OrderBuilder;
+pub use candles::{Bar, BarPeriod, CandleSeries, CandleStream};
+pub use dead_mans_switch::{
+    DeadMansSwitch, DeadMansSwitchBuilder, DeadMansSwitchConfig, SwitchState,
+    SwitchStatus,
+};
+pub use exchange::{
+    ActionSignature, CloseFailure, CloseReport, ClosedPosition, CollectedSignature,
+    MultiSigSession, OrderBuilder, RoundingMode, SignedAction,
+};
 pub use info::RateLimiter;
-pub use websocket::SubscriptionId;
+pub use order_book::{OrderBook, PriceLevel};
+#[cfg(feature = "sqlite")]
+pub use order_store::SqliteOrderStore;
+pub use order_store::{JsonlOrderStore, OrderStore};
+pub use order_tracker::{
+    OrderStatus, OrderTracker, OrderTransition, OrderTransitions, TrackedOrder,
+};
+pub use transport::{HyperTransport, MockTransport, Transport, WsTransport};
+pub use websocket::{PendingPost, SubscriptionId};
+pub use ws_channel::{OverflowPolicy, SubscriptionReceiver};
@@ -11,22 +11,48 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// - New nonce > smallest in the set
 /// - Never reuse a nonce
 /// - Nonces must be within (T - 2 days, T + 1 day)
+///
+/// Nonces are the current time in milliseconds, bumped past the last nonce
+/// issued for the same key so they stay strictly increasing under bursts and
+/// clock steps.
 #[derive(Debug)]
 pub struct NonceManager {
-    /// Separate nonce counters per address for subaccount isolation
-    counters: DashMap<Address, AtomicU64>,
-    /// Global counter for addresses without isolation
-    global_counter: AtomicU64,
+    /// Nonce sequence per address for subaccount isolation
+    sequences: DashMap<Address, Sequence>,
+    /// Sequence for addresses without isolation
+    global: Sequence,
     /// Whether to isolate nonces per address
     isolate_per_address: bool,
 }
 
+/// Nonces issued for one key
+#[derive(Debug, Default)]
+struct Sequence {
+    /// Last nonce issued, 0 if none
+    last: AtomicU64,
+    /// Number of nonces issued, for monitoring
+    issued: AtomicU64,
+}
+
+impl Sequence {
+    fn next(&self, now: u64) -> u64 {
+        self.issued.fetch_add(1, Ordering::Relaxed);
+        let prev = self
+            .last
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+                Some(now.max(last + 1))
+            })
+            .expect("update closure always returns Some");
+        now.max(prev + 1)
+    }
+}
+
 impl NonceManager {
     /// Create a new nonce manager
     pub fn new(isolate_per_address: bool) -> Self {
         Self {
-            counters: DashMap::new(),
-            global_counter: AtomicU64::new(0),
+            sequences: DashMap::new(),
+            global: Sequence::default(),
             isolate_per_address,
         }
     }
@@ -38,41 +64,44 @@ impl NonceManager {
             .expect("system time before UNIX epoch")
             .as_millis() as u64;
 
-        // Get counter increment
-        let counter = match (self.isolate_per_address, address) {
+        match (self.isolate_per_address, address) {
+            (true, Some(addr)) => self.sequences.entry(addr).or_default().next(now),
+            _ => self.global.next(now),
+        }
+    }
+
+    /// Last nonce issued for an optional address, `None` if none was issued
+    pub fn last_nonce(&self, address: Option<Address>) -> Option<u64> {
+        let last = match (self.isolate_per_address, address) {
             (true, Some(addr)) => self
-                .counters
-                .entry(addr)
-                .or_insert_with(|| AtomicU64::new(0))
-                .fetch_add(1, Ordering::Relaxed),
-            _ => self.global_counter.fetch_add(1, Ordering::Relaxed),
+                .sequences
+                .get(&addr)
+                .map(|sequence| sequence.last.load(Ordering::SeqCst))
+                .unwrap_or(0),
+            _ => self.global.last.load(Ordering::SeqCst),
         };
-
-        // Add sub-millisecond offset to ensure uniqueness
-        // This handles rapid-fire orders within the same millisecond
-        now.saturating_add(counter % 1000)
+        (last != 0).then_some(last)
     }
 
     /// Reset counter for a specific address (useful after agent rotation)
+    ///
+    /// The count of issued nonces restarts at zero; nonces for the address keep
+    /// increasing past the last one issued, so none is ever reused.
     pub fn reset_address(&self, address: Address) {
-        if let Some(counter) = self.counters.get_mut(&address) {
-            counter.store(0, Ordering::Relaxed);
+        if let Some(sequence) = self.sequences.get(&address) {
+            sequence.issued.store(0, Ordering::Relaxed);
         }
     }
 
     /// Get current counter value for monitoring
     pub fn get_counter(&self, address: Option<Address>) -> u64 {
-        if let Some(addr) = address {
-            if self.isolate_per_address {
-                self.counters
-                    .get(&addr)
-                    .map(|c| c.load(Ordering::Relaxed))
-                    .unwrap_or(0)
-            } else {
-                self.global_counter.load(Ordering::Relaxed)
-            }
-        } else {
-            self.global_counter.load(Ordering::Relaxed)
+        match (self.isolate_per_address, address) {
+            (true, Some(addr)) => self
+                .sequences
+                .get(&addr)
+                .map(|sequence| sequence.issued.load(Ordering::Relaxed))
+                .unwrap_or(0),
+            _ => self.global.issued.load(Ordering::Relaxed),
         }
     }
 
@@ -0,0 +1,202 @@
+//! Locally maintained L2 order book
+//!
+//! Hyperliquid publishes every `l2Book` update as a full snapshot of the top
+//! levels, so [`OrderBook`] replaces both sides on each message rather than
+//! applying deltas. Prices and sizes are parsed once here so consumers can work
+//! with numbers. A live book is obtained from
+//! [`ManagedWsProvider::subscribe_order_book`](crate::providers::ManagedWsProvider::subscribe_order_book).
+
+use crate::{errors::HyperliquidError, types::ws::L2BookData};
+
+/// One aggregated price level.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PriceLevel {
+    /// Price
+    pub px: f64,
+    /// Total size resting at this price
+    pub sz: f64,
+    /// Number of orders at this price
+    pub n: u64,
+}
+
+/// Sorted in-memory book for one coin.
+///
+/// Bids are ordered best (highest) first and asks best (lowest) first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OrderBook {
+    coin: String,
+    time: u64,
+    bids: Vec<PriceLevel>,
+    asks: Vec<PriceLevel>,
+}
+
+impl OrderBook {
+    /// Create an empty book for `coin`.
+    pub fn new(coin: impl Into<String>) -> Self {
+        Self {
+            coin: coin.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Build a book from an `l2Book` message.
+    pub fn from_snapshot(data: &L2BookData) -> Result<Self, HyperliquidError> {
+        let mut book = Self::new(data.coin.clone());
+        book.apply_snapshot(data)?;
+        Ok(book)
+    }
+
+    /// Replace both sides with an `l2Book` snapshot.
+    ///
+    /// The book is left untouched if any level fails to parse.
+    pub fn apply_snapshot(&mut self, data: &L2BookData) -> Result<(), HyperliquidError> {
+        let side = |index: usize| -> Result<Vec<PriceLevel>, HyperliquidError> {
+            data.levels
+                .get(index)
+                .map(|levels| {
+                    levels
+                        .iter()
+                        .map(|level| {
+                            Ok(PriceLevel {
+                                px: level.px.parse_f64("book px")?,
+                                sz: level.sz.parse_f64("book sz")?,
+                                n: level.n,
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_else(|| Ok(Vec::new()))
+        };
+
+        let mut bids = side(0)?;
+        let mut asks = side(1)?;
+        bids.sort_by(|a, b| b.px.total_cmp(&a.px));
+        asks.sort_by(|a, b| a.px.total_cmp(&b.px));
+
+        self.coin.clone_from(&data.coin);
+        self.time = data.time;
+        self.bids = bids;
+        self.asks = asks;
+        Ok(())
+    }
+
+    /// Drop all levels, e.g. when the feed is interrupted.
+    pub fn clear(&mut self) {
+        self.time = 0;
+        self.bids.clear();
+        self.asks.clear();
+    }
+
+    /// Coin this book tracks
+    pub fn coin(&self) -> &str {
+        &self.coin
+    }
+
+    /// Exchange timestamp (ms) of the last snapshot, 0 if none
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    /// Bid levels, best first
+    pub fn bids(&self) -> &[PriceLevel] {
+        &self.bids
+    }
+
+    /// Ask levels, best first
+    pub fn asks(&self) -> &[PriceLevel] {
+        &self.asks
+    }
+
+    /// Whether both sides are empty
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
+    /// Highest bid
+    pub fn best_bid(&self) -> Option<&PriceLevel> {
+        self.bids.first()
+    }
+
+    /// Lowest ask
+    pub fn best_ask(&self) -> Option<&PriceLevel> {
+        self.asks.first()
+    }
+
+    /// Midpoint of the best bid and ask
+    pub fn mid(&self) -> Option<f64> {
+        Some((self.best_bid()?.px + self.best_ask()?.px) / 2.0)
+    }
+
+    /// Best ask minus best bid
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()?.px - self.best_bid()?.px)
+    }
+
+    /// Size resting at exactly `px` on either side, 0 if there is no such level.
+    pub fn depth_at_price(&self, px: f64) -> f64 {
+        self.bids
+            .iter()
+            .chain(&self.asks)
+            .filter(|level| level.px == px)
+            .map(|level| level.sz)
+            .sum()
+    }
+
+    /// Size a taker would receive for spending `notional` (in quote currency).
+    ///
+    /// Buys walk the asks and sells walk the bids. Returns `None` if the visible
+    /// book cannot absorb the full notional.
+    pub fn size_for_notional(&self, is_buy: bool, notional: f64) -> Option<f64> {
+        let mut remaining = notional;
+        let mut size = 0.0;
+        for level in self.taker_levels(is_buy) {
+            let level_notional = level.px * level.sz;
+            if level_notional >= remaining {
+                return Some(size + remaining / level.px);
+            }
+            size += level.sz;
+            remaining -= level_notional;
+        }
+        None
+    }
+
+    /// Average price a taker would pay (buy) or receive (sell) for `sz`.
+    ///
+    /// Returns `None` if `sz` is not positive or exceeds the visible depth.
+    pub fn vwap(&self, is_buy: bool, sz: f64) -> Option<f64> {
+        if sz <= 0.0 {
+            return None;
+        }
+
+        let mut remaining = sz;
+        let mut cost = 0.0;
+        for level in self.taker_levels(is_buy) {
+            let take = remaining.min(level.sz);
+            cost += take * level.px;
+            remaining -= take;
+            if remaining <= 0.0 {
+                return Some(cost / sz);
+            }
+        }
+        None
+    }
+
+    /// Size imbalance over the top `depth` levels of each side.
+    ///
+    /// `(bid_size - ask_size) / (bid_size + ask_size)`, ranging from -1 (asks
+    /// only) to 1 (bids only). `None` when both sides are empty.
+    pub fn imbalance(&self, depth: usize) -> Option<f64> {
+        let bid: f64 = self.bids.iter().take(depth).map(|level| level.sz).sum();
+        let ask: f64 = self.asks.iter().take(depth).map(|level| level.sz).sum();
+        let total = bid + ask;
+        (total > 0.0).then(|| (bid - ask) / total)
+    }
+
+    fn taker_levels(&self, is_buy: bool) -> &[PriceLevel] {
+        if is_buy {
+            &self.asks
+        } else {
+            &self.bids
+        }
+    }
+}
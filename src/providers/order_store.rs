@@ -0,0 +1,250 @@
+//! Persistence for tracked orders.
+//!
+//! An [`OrderStore`] keeps the latest state of every tracked order so an
+//! [`OrderTracker`](super::OrderTracker) survives restarts. [`JsonlOrderStore`]
+//! appends one JSON line per change; with the `sqlite` feature,
+//! [`SqliteOrderStore`] keeps one row per order.
+//!
+//! # Example
+//! ```ignore
+//! let store = Arc::new(JsonlOrderStore::open("orders.jsonl")?);
+//! let exchange = ExchangeProvider::mainnet(signer)
+//!     .with_order_tracker(OrderTracker::with_store(store)?);
+//!
+//! // Settle orders that were in flight when the process stopped
+//! exchange.reconcile_orders().await?;
+//! ```
+
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use uuid::Uuid;
+
+use crate::{errors::HyperliquidError, providers::order_tracker::TrackedOrder};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// Storage for the latest state of tracked orders
+pub trait OrderStore: Send + Sync {
+    /// Persist the current state of `order`, replacing any earlier state
+    fn save(&self, order: &TrackedOrder) -> Result<()>;
+
+    /// The latest saved state of every order, oldest first
+    fn load(&self) -> Result<Vec<TrackedOrder>>;
+
+    /// Remove every order
+    fn clear(&self) -> Result<()>;
+}
+
+/// Append-only JSON Lines file of order states.
+///
+/// Every save appends the whole order; the last line for a cloid wins on load.
+/// A line cut short by a crash is dropped when the file is reopened. Call
+/// [`compact`](Self::compact) now and then to drop superseded lines.
+pub struct JsonlOrderStore {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl JsonlOrderStore {
+    /// Open or create the file at `path`
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&path)
+            .map_err(|e| storage_error("open", &path, e))?;
+
+        // Drop a partial last line so new lines start on their own
+        let contents =
+            std::fs::read(&path).map_err(|e| storage_error("read", &path, e))?;
+        if contents.last().is_some_and(|&byte| byte != b'\n') {
+            let keep = contents
+                .iter()
+                .rposition(|&byte| byte == b'\n')
+                .map_or(0, |i| i + 1);
+            tracing::warn!(
+                "Dropping {} bytes of a partial order record in {}",
+                contents.len() - keep,
+                path.display()
+            );
+            file.set_len(keep as u64)
+                .map_err(|e| storage_error("truncate", &path, e))?;
+        }
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Path of the backing file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Rewrite the file with a single line per order.
+    pub fn compact(&self) -> Result<()> {
+        let mut file = self.file.lock().expect("order store mutex poisoned");
+        let orders = self.read_latest()?;
+
+        let tmp = self.path.with_extension("tmp");
+        let mut contents = Vec::new();
+        for order in &orders {
+            serde_json::to_writer(&mut contents, order)?;
+            contents.push(b'\n');
+        }
+        std::fs::write(&tmp, contents).map_err(|e| storage_error("write", &tmp, e))?;
+        std::fs::rename(&tmp, &self.path)
+            .map_err(|e| storage_error("replace", &self.path, e))?;
+
+        *file = OpenOptions::new()
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| storage_error("open", &self.path, e))?;
+        Ok(())
+    }
+
+    fn read_latest(&self) -> Result<Vec<TrackedOrder>> {
+        let contents = std::fs::read_to_string(&self.path)
+            .map_err(|e| storage_error("read", &self.path, e))?;
+
+        let mut latest: HashMap<Uuid, TrackedOrder> = HashMap::new();
+        for (number, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let order: TrackedOrder = serde_json::from_str(line).map_err(|e| {
+                HyperliquidError::Storage(format!(
+                    "{} line {}: {}",
+                    self.path.display(),
+                    number + 1,
+                    e
+                ))
+            })?;
+            latest.insert(order.cloid, order);
+        }
+
+        let mut orders: Vec<_> = latest.into_values().collect();
+        orders.sort_by_key(|order| order.timestamp);
+        Ok(orders)
+    }
+}
+
+impl OrderStore for JsonlOrderStore {
+    fn save(&self, order: &TrackedOrder) -> Result<()> {
+        let mut line = serde_json::to_vec(order)?;
+        line.push(b'\n');
+        let mut file = self.file.lock().expect("order store mutex poisoned");
+        file.write_all(&line)
+            .map_err(|e| storage_error("append to", &self.path, e))
+    }
+
+    fn load(&self) -> Result<Vec<TrackedOrder>> {
+        let _file = self.file.lock().expect("order store mutex poisoned");
+        self.read_latest()
+    }
+
+    fn clear(&self) -> Result<()> {
+        let file = self.file.lock().expect("order store mutex poisoned");
+        file.set_len(0)
+            .map_err(|e| storage_error("truncate", &self.path, e))
+    }
+}
+
+fn storage_error(action: &str, path: &Path, e: std::io::Error) -> HyperliquidError {
+    HyperliquidError::Storage(format!("Failed to {} {}: {}", action, path.display(), e))
+}
+
+#[cfg(feature = "sqlite")]
+pub use self::sqlite::SqliteOrderStore;
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use std::{path::Path, sync::Mutex};
+
+    use rusqlite::{params, Connection};
+
+    use super::{OrderStore, Result};
+    use crate::{errors::HyperliquidError, providers::order_tracker::TrackedOrder};
+
+    /// SQLite table of order states, one row per cloid.
+    pub struct SqliteOrderStore {
+        conn: Mutex<Connection>,
+    }
+
+    impl SqliteOrderStore {
+        /// Open or create the database at `path`
+        pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+            Self::init(Connection::open(path).map_err(sqlite_error)?)
+        }
+
+        /// Database that lives only as long as the store
+        pub fn open_in_memory() -> Result<Self> {
+            Self::init(Connection::open_in_memory().map_err(sqlite_error)?)
+        }
+
+        fn init(conn: Connection) -> Result<Self> {
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS tracked_orders (
+                    cloid TEXT PRIMARY KEY,
+                    timestamp INTEGER NOT NULL,
+                    data TEXT NOT NULL
+                )",
+                [],
+            )
+            .map_err(sqlite_error)?;
+            Ok(Self {
+                conn: Mutex::new(conn),
+            })
+        }
+    }
+
+    impl OrderStore for SqliteOrderStore {
+        fn save(&self, order: &TrackedOrder) -> Result<()> {
+            let data = serde_json::to_string(order)?;
+            let conn = self.conn.lock().expect("order store mutex poisoned");
+            conn.execute(
+                "INSERT INTO tracked_orders (cloid, timestamp, data) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(cloid) DO UPDATE SET data = excluded.data",
+                params![order.cloid.to_string(), order.timestamp as i64, data],
+            )
+            .map_err(sqlite_error)?;
+            Ok(())
+        }
+
+        fn load(&self) -> Result<Vec<TrackedOrder>> {
+            let conn = self.conn.lock().expect("order store mutex poisoned");
+            let mut statement = conn
+                .prepare("SELECT data FROM tracked_orders ORDER BY timestamp")
+                .map_err(sqlite_error)?;
+            let rows = statement
+                .query_map([], |row| row.get::<_, String>(0))
+                .map_err(sqlite_error)?;
+
+            let mut orders = Vec::new();
+            for data in rows {
+                orders.push(serde_json::from_str(&data.map_err(sqlite_error)?)?);
+            }
+            Ok(orders)
+        }
+
+        fn clear(&self) -> Result<()> {
+            let conn = self.conn.lock().expect("order store mutex poisoned");
+            conn.execute("DELETE FROM tracked_orders", [])
+                .map_err(sqlite_error)?;
+            Ok(())
+        }
+    }
+
+    fn sqlite_error(e: rusqlite::Error) -> HyperliquidError {
+        HyperliquidError::Storage(e.to_string())
+    }
+}
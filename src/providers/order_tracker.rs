@@ -1,38 +1,160 @@
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{mpsc as std_mpsc, Arc, RwLock};
+use std::thread;
+
+use alloy::primitives::Address;
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    task::JoinHandle,
+};
 use uuid::Uuid;
 
+use crate::errors::HyperliquidError;
+use crate::providers::{
+    info::InfoProvider, order_store::OrderStore, websocket::ManagedWsProvider,
+};
+use crate::types::decimal::Num;
 use crate::types::requests::OrderRequest;
-use crate::types::responses::ExchangeResponseStatus;
+use crate::types::responses::{ExchangeResponseStatus, OrderOutcome};
+use crate::types::ws::{Message, OrderUpdate, Subscription, TradeInfo};
 
-#[derive(Clone, Debug)]
+/// Capacity of the transition channel; slow receivers skip older transitions
+const TRANSITION_CAPACITY: usize = 1024;
+
+/// Sizes within this of the order size count as fully filled
+const SIZE_EPSILON: f64 = 1e-9;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TrackedOrder {
     pub cloid: Uuid,
     pub order: OrderRequest,
     pub timestamp: u64,
     pub status: OrderStatus,
+    /// Response to the order's submission; not persisted
+    #[serde(skip)]
     pub response: Option<ExchangeResponseStatus>,
+    /// Exchange order id, once known
+    pub oid: Option<u64>,
+    /// Total size filled so far
+    pub filled_sz: f64,
+    /// Size-weighted average fill price
+    pub avg_px: Option<f64>,
+    /// Fills received over the WebSocket, in arrival order
+    pub fills: Vec<TradeInfo>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum OrderStatus {
     Pending,
     Submitted,
     Failed(String),
+    /// On the book with nothing filled
+    Resting,
+    /// Partly filled; the rest is on the book
+    PartiallyFilled,
+    Filled,
+    /// Canceled, with the exchange's status (e.g. `"canceled"`, `"marginCanceled"`)
+    Canceled(String),
+    /// Rejected, with the exchange's message or status
+    Rejected(String),
+    /// Trigger order whose trigger price was reached
+    Triggered,
+}
+
+impl OrderStatus {
+    /// Whether the order can no longer change status
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Failed(_) | Self::Filled | Self::Canceled(_) | Self::Rejected(_)
+        )
+    }
+}
+
+/// A change to a tracked order's status or filled size
+#[derive(Clone, Debug)]
+pub struct OrderTransition {
+    /// Status before the change
+    pub from: OrderStatus,
+    /// The order after the change
+    pub order: TrackedOrder,
+}
+
+/// Transitions of a single tracked order, from [`OrderTracker::transitions`].
+pub struct OrderTransitions {
+    cloid: Uuid,
+    rx: broadcast::Receiver<OrderTransition>,
+}
+
+impl OrderTransitions {
+    /// Next transition of the order.
+    ///
+    /// Fill updates keep arriving after a terminal status if fills are
+    /// reported late. Transitions dropped because the receiver lagged are
+    /// skipped; check [`OrderTracker::get_order`] for the current state.
+    pub async fn next(&mut self) -> Option<OrderTransition> {
+        loop {
+            match self.rx.recv().await {
+                Ok(transition) if transition.order.cloid == self.cloid => {
+                    return Some(transition)
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct OrderTracker {
     orders: Arc<RwLock<HashMap<Uuid, TrackedOrder>>>,
+    oids: Arc<RwLock<HashMap<u64, Uuid>>>,
+    transitions: broadcast::Sender<OrderTransition>,
+    store: Option<Arc<StoreWriter>>,
 }
 
 impl OrderTracker {
     pub fn new() -> Self {
         Self {
             orders: Arc::new(RwLock::new(HashMap::new())),
+            oids: Arc::new(RwLock::new(HashMap::new())),
+            transitions: broadcast::channel(TRANSITION_CAPACITY).0,
+            store: None,
         }
     }
 
+    /// Tracker persisting every change to `store`, starting from the orders
+    /// already saved there.
+    ///
+    /// Changes are written on a background thread in the order they happen;
+    /// use [`flush`](Self::flush) to wait for them. Submission responses are
+    /// not persisted.
+    ///
+    /// Call [`reconcile`](Self::reconcile) afterwards to settle orders that
+    /// were in flight when the process stopped.
+    pub fn with_store(store: Arc<dyn OrderStore>) -> Result<Self, HyperliquidError> {
+        let saved = store.load()?;
+        let tracker = Self {
+            store: Some(Arc::new(StoreWriter::spawn(store))),
+            ..Self::new()
+        };
+        {
+            let mut orders = tracker
+                .orders
+                .write()
+                .expect("order tracker rwlock poisoned");
+            let mut oids = tracker.oids.write().expect("order tracker rwlock poisoned");
+            for order in saved {
+                if let Some(oid) = order.oid {
+                    oids.insert(oid, order.cloid);
+                }
+                orders.insert(order.cloid, order);
+            }
+        }
+        Ok(tracker)
+    }
+
     /// Track a new order
     pub fn track_order(&self, cloid: Uuid, order: OrderRequest, timestamp: u64) {
         let tracked = TrackedOrder {
@@ -41,9 +163,14 @@ impl OrderTracker {
             timestamp,
             status: OrderStatus::Pending,
             response: None,
+            oid: None,
+            filled_sz: 0.0,
+            avg_px: None,
+            fills: Vec::new(),
         };
 
         let mut orders = self.orders.write().expect("order tracker rwlock poisoned");
+        self.persist(&tracked);
         orders.insert(cloid, tracked);
     }
 
@@ -54,10 +181,284 @@ impl OrderTracker {
         status: OrderStatus,
         response: Option<ExchangeResponseStatus>,
     ) {
-        let mut orders = self.orders.write().expect("order tracker rwlock poisoned");
-        if let Some(order) = orders.get_mut(cloid) {
+        self.modify(cloid, |order| {
             order.status = status;
             order.response = response;
+        });
+    }
+
+    /// Record the exchange's response to an order's submission.
+    ///
+    /// The status moves to `Resting`, `Filled` or `Rejected` from the response,
+    /// unless WebSocket updates have already moved the order past `Submitted`.
+    pub fn record_response(&self, cloid: &Uuid, response: ExchangeResponseStatus) {
+        let outcome = response
+            .order_outcomes()
+            .ok()
+            .and_then(|outcomes| outcomes.0.into_iter().next());
+        let error = response.error().map(str::to_string);
+
+        self.modify(cloid, |order| {
+            order.response = Some(response);
+            let settled =
+                !matches!(order.status, OrderStatus::Pending | OrderStatus::Submitted);
+
+            let status = match outcome {
+                Some(OrderOutcome::Resting { oid, .. }) => {
+                    order.oid.get_or_insert(oid);
+                    OrderStatus::Resting
+                }
+                Some(OrderOutcome::Filled {
+                    oid,
+                    avg_px,
+                    total_sz,
+                    ..
+                }) => {
+                    order.oid.get_or_insert(oid);
+                    // WebSocket fills that arrived first are more detailed
+                    if order.fills.is_empty() {
+                        order.filled_sz = to_f64(&total_sz);
+                        order.avg_px = Some(to_f64(&avg_px));
+                    }
+                    // Nothing rests after a fill response; an IOC remainder is canceled
+                    if order.filled_sz + SIZE_EPSILON >= order_size(order) {
+                        OrderStatus::Filled
+                    } else {
+                        OrderStatus::Canceled("canceled".to_string())
+                    }
+                }
+                Some(OrderOutcome::Rejected { message, .. }) => {
+                    OrderStatus::Rejected(message)
+                }
+                Some(_) => OrderStatus::Submitted,
+                None => match error {
+                    Some(message) => OrderStatus::Rejected(message),
+                    None => OrderStatus::Submitted,
+                },
+            };
+            if !settled {
+                order.status = status;
+            }
+        });
+    }
+
+    /// Apply an `orderUpdates` entry to the order it refers to.
+    ///
+    /// Orders are matched by cloid, then by oid; updates for untracked orders
+    /// are ignored.
+    pub fn apply_order_update(&self, update: &OrderUpdate) {
+        let Some(cloid) = self.resolve(update.order.cloid.as_deref(), update.order.oid)
+        else {
+            return;
+        };
+        let partly_filled = to_f64(&update.order.sz) < to_f64(&update.order.orig_sz);
+
+        self.modify(&cloid, |order| {
+            order.oid = Some(update.order.oid);
+            if order.status.is_terminal() {
+                return;
+            }
+            let partly_filled = partly_filled || order.filled_sz > 0.0;
+            if let Some(status) = exchange_status(&update.status, partly_filled) {
+                order.status = status;
+            }
+        });
+    }
+
+    /// Apply a fill to the order it belongs to.
+    ///
+    /// Fills are deduplicated by trade id, so replayed snapshots are harmless.
+    pub fn apply_fill(&self, fill: &TradeInfo) {
+        let Some(cloid) = self.resolve(fill.cloid.as_deref(), fill.oid) else {
+            return;
+        };
+
+        self.modify(&cloid, |order| {
+            if order.fills.iter().any(|seen| seen.tid == fill.tid) {
+                return;
+            }
+            order.oid.get_or_insert(fill.oid);
+            order.fills.push(fill.clone());
+
+            let (sz, notional) =
+                order.fills.iter().fold((0.0, 0.0), |(sz, notional), f| {
+                    let fill_sz = to_f64(&f.sz);
+                    (sz + fill_sz, notional + fill_sz * to_f64(&f.px))
+                });
+            order.filled_sz = sz;
+            order.avg_px = (sz > 0.0).then(|| notional / sz);
+
+            if !order.status.is_terminal() {
+                order.status = if sz + SIZE_EPSILON >= order_size(order) {
+                    OrderStatus::Filled
+                } else {
+                    OrderStatus::PartiallyFilled
+                };
+            }
+        });
+    }
+
+    /// Apply an `orderUpdates` or `userFills` message; others are ignored.
+    pub fn apply_message(&self, message: &Message) {
+        match message {
+            Message::OrderUpdates(updates) => {
+                for update in &updates.data {
+                    self.apply_order_update(update);
+                }
+            }
+            Message::UserFills(fills) => {
+                for fill in &fills.data.fills {
+                    self.apply_fill(fill);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Subscribe to `orderUpdates` and `userFills` for `user` and apply them
+    /// in a background task.
+    ///
+    /// Call [`ManagedWsProvider::start_reading`] if the provider is not reading
+    /// yet. The task ends when both subscriptions close; abort the handle to
+    /// stop it earlier.
+    pub async fn follow(
+        &self,
+        ws: &ManagedWsProvider,
+        user: Address,
+    ) -> Result<JoinHandle<()>, HyperliquidError> {
+        let (_, mut updates) = ws.subscribe(Subscription::OrderUpdates { user }).await?;
+        let (_, mut fills) = ws.subscribe(Subscription::UserFills { user }).await?;
+
+        let tracker = self.clone();
+        Ok(tokio::spawn(async move {
+            loop {
+                let message = tokio::select! {
+                    Some(message) = updates.recv() => message,
+                    Some(message) = fills.recv() => message,
+                    else => break,
+                };
+                tracker.apply_message(&message);
+            }
+        }))
+    }
+
+    /// Settle live orders from the exchange's view of them.
+    ///
+    /// Meant for startup after [`with_store`](Self::with_store): orders left
+    /// pending, resting or partly filled are matched by cloid or oid against
+    /// `openOrders`, then `historicalOrders`, and any still unmatched are
+    /// looked up one by one with `orderStatus`. Orders the exchange never saw
+    /// are marked failed. Returns the number of orders whose status changed.
+    pub async fn reconcile(
+        &self,
+        info: &InfoProvider,
+        user: Address,
+    ) -> Result<usize, HyperliquidError> {
+        let mut live = self.get_live_orders();
+        if live.is_empty() {
+            return Ok(0);
+        }
+        let mut changed = 0;
+
+        let open = info.open_orders(user).await?;
+        live.retain(|order| {
+            let Some(open) = open
+                .iter()
+                .find(|open| refers_to(order, open.cloid.as_deref(), open.oid))
+            else {
+                return true;
+            };
+            let orig_sz = open.orig_sz.as_ref().map(to_f64);
+            if self.settle(&order.cloid, open.oid, "open", orig_sz, to_f64(&open.sz)) {
+                changed += 1;
+            }
+            false
+        });
+        if live.is_empty() {
+            return Ok(changed);
+        }
+
+        let history = info.historical_orders(user).await?;
+        live.retain(|order| {
+            let latest = history
+                .iter()
+                .filter(|past| {
+                    refers_to(order, past.order.cloid.as_deref(), past.order.oid)
+                })
+                .max_by_key(|past| past.status_timestamp);
+            // An order open in history but not in openOrders changed in between
+            let Some(past) = latest.filter(|past| past.status != "open") else {
+                return true;
+            };
+            let orig_sz = Some(to_f64(&past.order.orig_sz));
+            let remaining = to_f64(&past.order.sz);
+            if self.settle(
+                &order.cloid,
+                past.order.oid,
+                &past.status,
+                orig_sz,
+                remaining,
+            ) {
+                changed += 1;
+            }
+            false
+        });
+
+        for order in live {
+            let response = match order.oid {
+                Some(oid) => info.order_status(user, oid).await?,
+                None => info.order_status_by_cloid(user, order.cloid).await?,
+            };
+            match response.order {
+                Some(found) => {
+                    let orig_sz = Some(to_f64(&found.order.orig_sz));
+                    let remaining = to_f64(&found.order.sz);
+                    if self.settle(
+                        &order.cloid,
+                        found.order.oid,
+                        &found.status,
+                        orig_sz,
+                        remaining,
+                    ) {
+                        changed += 1;
+                    }
+                }
+                // Never reached the exchange
+                None if order.oid.is_none() => {
+                    self.modify(&order.cloid, |order| {
+                        order.status =
+                            OrderStatus::Failed("unknown to the exchange".to_string());
+                    });
+                    changed += 1;
+                }
+                None => {}
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Stream the transitions of one order.
+    pub fn transitions(&self, cloid: Uuid) -> OrderTransitions {
+        OrderTransitions {
+            cloid,
+            rx: self.transitions.subscribe(),
+        }
+    }
+
+    /// Wait until the order reaches a terminal status.
+    ///
+    /// Returns `None` if the order is not tracked.
+    pub async fn wait_for_terminal(&self, cloid: &Uuid) -> Option<TrackedOrder> {
+        let mut rx = self.transitions.subscribe();
+        loop {
+            let order = self.get_order(cloid)?;
+            if order.status.is_terminal() {
+                return Some(order);
+            }
+            // Any transition, or a lag, is a cue to re-check
+            if let Err(RecvError::Closed) = rx.recv().await {
+                return Some(order);
+            }
         }
     }
 
@@ -67,6 +468,14 @@ impl OrderTracker {
         orders.get(cloid).cloned()
     }
 
+    /// Get a specific order by exchange order id
+    pub fn get_order_by_oid(&self, oid: u64) -> Option<TrackedOrder> {
+        let oids = self.oids.read().expect("order tracker rwlock poisoned");
+        let cloid = oids.get(&oid).copied();
+        drop(oids);
+        self.get_order(&cloid?)
+    }
+
     /// Get all tracked orders
     pub fn get_all_orders(&self) -> Vec<TrackedOrder> {
         let orders = self.orders.read().expect("order tracker rwlock poisoned");
@@ -103,10 +512,40 @@ impl OrderTracker {
             .collect()
     }
 
+    /// Get orders that have not reached a terminal status
+    pub fn get_live_orders(&self) -> Vec<TrackedOrder> {
+        let orders = self.orders.read().expect("order tracker rwlock poisoned");
+        orders
+            .values()
+            .filter(|order| !order.status.is_terminal())
+            .cloned()
+            .collect()
+    }
+
     /// Clear all tracked orders
     pub fn clear(&self) {
         let mut orders = self.orders.write().expect("order tracker rwlock poisoned");
         orders.clear();
+        self.oids
+            .write()
+            .expect("order tracker rwlock poisoned")
+            .clear();
+        if let Some(store) = &self.store {
+            store.send(StoreOp::Clear);
+        }
+    }
+
+    /// Block until every change made so far has been written to the store.
+    ///
+    /// Changes are written on a background thread so the tracker's locks are
+    /// never held during I/O; they are also flushed when the last clone of
+    /// the tracker is dropped.
+    pub fn flush(&self) {
+        if let Some(store) = &self.store {
+            let (done, wait) = std_mpsc::channel();
+            store.send(StoreOp::Flush(done));
+            let _ = wait.recv();
+        }
     }
 
     /// Get the number of tracked orders
@@ -120,6 +559,151 @@ impl OrderTracker {
         let orders = self.orders.read().expect("order tracker rwlock poisoned");
         orders.is_empty()
     }
+
+    /// The tracked order a cloid or oid refers to
+    fn resolve(&self, cloid: Option<&str>, oid: u64) -> Option<Uuid> {
+        if let Some(cloid) = cloid.and_then(parse_cloid) {
+            let orders = self.orders.read().expect("order tracker rwlock poisoned");
+            if orders.contains_key(&cloid) {
+                return Some(cloid);
+            }
+        }
+        let oids = self.oids.read().expect("order tracker rwlock poisoned");
+        oids.get(&oid).copied()
+    }
+
+    /// Apply `change` to a tracked order and announce it if its status or
+    /// filled size changed
+    fn modify(&self, cloid: &Uuid, change: impl FnOnce(&mut TrackedOrder)) {
+        let transition = {
+            let mut orders = self.orders.write().expect("order tracker rwlock poisoned");
+            let Some(order) = orders.get_mut(cloid) else {
+                return;
+            };
+            let from = order.status.clone();
+            let filled_sz = order.filled_sz;
+            change(order);
+
+            if let Some(oid) = order.oid {
+                self.oids
+                    .write()
+                    .expect("order tracker rwlock poisoned")
+                    .insert(oid, *cloid);
+            }
+            self.persist(order);
+            (order.status != from || order.filled_sz != filled_sz).then(|| {
+                OrderTransition {
+                    from,
+                    order: order.clone(),
+                }
+            })
+        };
+
+        if let Some(transition) = transition {
+            // No receivers is fine
+            let _ = self.transitions.send(transition);
+        }
+    }
+
+    /// Apply a status and sizes reported by the info API, returning whether
+    /// the status changed
+    fn settle(
+        &self,
+        cloid: &Uuid,
+        oid: u64,
+        status: &str,
+        orig_sz: Option<f64>,
+        remaining: f64,
+    ) -> bool {
+        let mut changed = false;
+        self.modify(cloid, |order| {
+            order.oid = Some(oid);
+            // Fills may have been missed while the process was down
+            let filled = orig_sz.unwrap_or_else(|| order_size(order)) - remaining;
+            order.filled_sz = order.filled_sz.max(filled);
+
+            let partly_filled = order.filled_sz > SIZE_EPSILON;
+            if let Some(status) = exchange_status(status, partly_filled) {
+                changed = order.status != status;
+                order.status = status;
+            }
+        });
+        changed
+    }
+
+    /// Queue a snapshot of `order` for the store writer. Called under the
+    /// orders lock so snapshots reach the store in the order they were taken;
+    /// the write itself happens on the writer thread.
+    fn persist(&self, order: &TrackedOrder) {
+        if let Some(store) = &self.store {
+            store.send(StoreOp::Save(Box::new(order.clone())));
+        }
+    }
+}
+
+enum StoreOp {
+    Save(Box<TrackedOrder>),
+    Clear,
+    Flush(std_mpsc::Sender<()>),
+}
+
+/// Writes tracker changes to an [`OrderStore`] on a dedicated thread
+struct StoreWriter {
+    ops: Option<std_mpsc::Sender<StoreOp>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl StoreWriter {
+    fn spawn(store: Arc<dyn OrderStore>) -> Self {
+        let (ops, rx) = std_mpsc::channel();
+        let thread = thread::Builder::new()
+            .name("order-store".to_string())
+            .spawn(move || {
+                for op in rx {
+                    match op {
+                        StoreOp::Save(order) => {
+                            if let Err(e) = store.save(&order) {
+                                tracing::warn!(
+                                    "Failed to persist order {}: {}",
+                                    order.cloid,
+                                    e
+                                );
+                            }
+                        }
+                        StoreOp::Clear => {
+                            if let Err(e) = store.clear() {
+                                tracing::warn!("Failed to clear order store: {}", e);
+                            }
+                        }
+                        StoreOp::Flush(done) => {
+                            let _ = done.send(());
+                        }
+                    }
+                }
+            })
+            .expect("failed to spawn order store writer");
+        Self {
+            ops: Some(ops),
+            thread: Some(thread),
+        }
+    }
+
+    fn send(&self, op: StoreOp) {
+        if let Some(ops) = &self.ops {
+            // The writer only stops once this sender is dropped
+            let _ = ops.send(op);
+        }
+    }
+}
+
+impl Drop for StoreWriter {
+    /// Write out queued changes before the last tracker goes away
+    fn drop(&mut self) {
+        self.ops.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
 }
 
 impl Default for OrderTracker {
@@ -127,3 +711,40 @@ impl Default for OrderTracker {
         Self::new()
     }
 }
+
+/// Cloids are 128-bit hex, with or without a `0x` prefix
+fn parse_cloid(cloid: &str) -> Option<Uuid> {
+    u128::from_str_radix(cloid.trim_start_matches("0x"), 16)
+        .ok()
+        .map(Uuid::from_u128)
+}
+
+/// Tracked status for an `orderUpdates` or info API status string
+fn exchange_status(status: &str, partly_filled: bool) -> Option<OrderStatus> {
+    let status = match status {
+        "open" if partly_filled => OrderStatus::PartiallyFilled,
+        "open" => OrderStatus::Resting,
+        "filled" => OrderStatus::Filled,
+        "triggered" => OrderStatus::Triggered,
+        s if s == "rejected" || s.ends_with("Rejected") => {
+            OrderStatus::Rejected(s.to_string())
+        }
+        s if s == "canceled" || s.ends_with("Canceled") || s == "scheduledCancel" => {
+            OrderStatus::Canceled(s.to_string())
+        }
+        _ => return None,
+    };
+    Some(status)
+}
+
+fn refers_to(order: &TrackedOrder, cloid: Option<&str>, oid: u64) -> bool {
+    cloid.and_then(parse_cloid) == Some(order.cloid) || order.oid == Some(oid)
+}
+
+fn to_f64(value: &Num) -> f64 {
+    value.parse::<f64>().unwrap_or(0.0)
+}
+
+fn order_size(order: &TrackedOrder) -> f64 {
+    order.order.sz.parse::<f64>().unwrap_or(f64::INFINITY)
+}
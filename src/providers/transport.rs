@@ -0,0 +1,318 @@
+//! Pluggable HTTP transport for the info and exchange providers
+//!
+//! [`InfoProvider`](crate::providers::InfoProvider) and
+//! [`RawExchangeProvider`](crate::providers::RawExchangeProvider) send every request
+//! through a [`Transport`]. The default [`HyperTransport`] uses a pooled hyper client;
+//! [`WsTransport`] sends the same bodies as WebSocket `post` messages on an open
+//! [`ManagedWsProvider`] connection; [`MockTransport`] answers from memory so strategy
+//! code can be unit-tested against canned `/info` and `/exchange` responses without
+//! opening sockets.
+//!
+//! # Example
+//! ```ignore
+//! use std::sync::Arc;
+//! use hyperliquid_sdk_rs::providers::{InfoProvider, MockTransport};
+//! use serde_json::json;
+//!
+//! let transport = MockTransport::new()
+//!     .with_info_response("allMids", json!({"BTC": "50000"}));
+//! let info = InfoProvider::testnet().with_transport(Arc::new(transport));
+//! let mids = info.all_mids().await?;
+//! ```
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use http::{Method, Request};
+use http_body_util::{BodyExt, Full};
+use hyper::body::Bytes;
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_util::client::legacy::{connect::HttpConnector, Client};
+use hyper_util::rt::TokioExecutor;
+use serde_json::Value;
+
+use crate::{
+    errors::HyperliquidError,
+    providers::ManagedWsProvider,
+    types::ws::{PostRequest, PostResponsePayload},
+};
+
+/// Sends a JSON body to a URL and returns the raw HTTP status and response body.
+///
+/// Implement this to inject proxies, mTLS, custom connection pools or test doubles.
+/// Non-2xx statuses must be returned as `Ok` so providers can decode error bodies.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    async fn post(
+        &self,
+        url: &str,
+        body: Bytes,
+    ) -> Result<(u16, Bytes), HyperliquidError>;
+}
+
+// ==================== Hyper Transport ====================
+
+/// Default transport backed by a pooled hyper client with rustls.
+pub struct HyperTransport {
+    client: Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
+}
+
+impl HyperTransport {
+    pub fn new() -> Self {
+        // Initialize rustls crypto provider if not already set
+        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+
+        let https = HttpsConnectorBuilder::new()
+            .with_native_roots()
+            .expect("TLS initialization failed")
+            .https_or_http()
+            .enable_http1()
+            .build();
+
+        let client = Client::builder(TokioExecutor::new()).build(https);
+
+        Self { client }
+    }
+}
+
+impl Default for HyperTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Transport for HyperTransport {
+    async fn post(
+        &self,
+        url: &str,
+        body: Bytes,
+    ) -> Result<(u16, Bytes), HyperliquidError> {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url)
+            .header("Content-Type", "application/json")
+            .body(Full::new(body))?;
+
+        let response = self
+            .client
+            .request(request)
+            .await
+            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
+        let status = response.status();
+
+        let body_bytes = response
+            .into_body()
+            .collect()
+            .await
+            .map_err(|e| HyperliquidError::Network(e.to_string()))?
+            .to_bytes();
+
+        Ok((status.as_u16(), body_bytes))
+    }
+}
+
+// ==================== WebSocket Transport ====================
+
+/// Transport that sends requests as `post` messages over a WebSocket connection.
+///
+/// Bodies for URLs ending in `/info` become info requests and bodies for
+/// `/exchange` become actions, so signing is unchanged. Server error replies are
+/// reported as status 400 with the message as body.
+pub struct WsTransport {
+    ws: Arc<ManagedWsProvider>,
+    timeout: Duration,
+}
+
+impl WsTransport {
+    /// Use `ws`, waiting up to its `WsConfig::post_timeout` for each response.
+    pub fn new(ws: Arc<ManagedWsProvider>) -> Self {
+        let timeout = ws.post_timeout();
+        Self { ws, timeout }
+    }
+
+    /// Override the per-request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+#[async_trait]
+impl Transport for WsTransport {
+    async fn post(
+        &self,
+        url: &str,
+        body: Bytes,
+    ) -> Result<(u16, Bytes), HyperliquidError> {
+        let body: Value = serde_json::from_slice(&body)?;
+        let request = if url.ends_with("/info") {
+            PostRequest::Info(body)
+        } else if url.ends_with("/exchange") {
+            PostRequest::Action(body)
+        } else {
+            return Err(HyperliquidError::InvalidRequest(format!(
+                "WebSocket transport cannot post to {}",
+                url
+            )));
+        };
+
+        let (status, body) = match self.ws.post(request, self.timeout).await? {
+            PostResponsePayload::Info(mut payload) => {
+                (200, serde_json::to_vec(&payload["data"].take())?)
+            }
+            PostResponsePayload::Action(payload) => (200, serde_json::to_vec(&payload)?),
+            PostResponsePayload::Error(message) => (400, message.into_bytes()),
+        };
+        Ok((status, Bytes::from(body)))
+    }
+}
+
+// ==================== Mock Transport ====================
+
+/// A request captured by [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub url: String,
+    pub body: Value,
+}
+
+type MockHandler = Box<dyn Fn(&str, &Value) -> Option<(u16, Value)> + Send + Sync>;
+
+enum MockRoute {
+    Info {
+        request_type: String,
+        response: Value,
+    },
+    Exchange {
+        action_type: String,
+        response: Value,
+    },
+}
+
+/// In-memory transport that serves canned responses and records every request.
+///
+/// Info routes match on the request `type`; exchange routes match on the action
+/// `type`. Routes registered later take precedence. Unmatched requests fall
+/// through to the handler, if any, and otherwise get a 404.
+#[derive(Default)]
+pub struct MockTransport {
+    routes: Vec<MockRoute>,
+    handler: Option<MockHandler>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `/info` requests of the given `type` with `response`.
+    pub fn with_info_response(
+        mut self,
+        request_type: impl Into<String>,
+        response: Value,
+    ) -> Self {
+        self.routes.push(MockRoute::Info {
+            request_type: request_type.into(),
+            response,
+        });
+        self
+    }
+
+    /// Answer `/exchange` requests carrying the given action `type` with `response`.
+    pub fn with_exchange_response(
+        mut self,
+        action_type: impl Into<String>,
+        response: Value,
+    ) -> Self {
+        self.routes.push(MockRoute::Exchange {
+            action_type: action_type.into(),
+            response,
+        });
+        self
+    }
+
+    /// Fallback for requests no route matches; receives the URL and parsed body.
+    pub fn with_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str, &Value) -> Option<(u16, Value)> + Send + Sync + 'static,
+    {
+        self.handler = Some(Box::new(handler));
+        self
+    }
+
+    /// All requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests
+            .lock()
+            .expect("mock transport mutex poisoned")
+            .clone()
+    }
+
+    /// Clear the recorded requests.
+    pub fn clear_requests(&self) {
+        self.requests
+            .lock()
+            .expect("mock transport mutex poisoned")
+            .clear();
+    }
+
+    fn route(&self, url: &str, body: &Value) -> Option<(u16, Value)> {
+        let path = url.rsplit('/').next().unwrap_or_default();
+        let action_type = body
+            .get("action")
+            .and_then(|action| action.get("type"))
+            .and_then(Value::as_str);
+
+        let routed = self.routes.iter().rev().find_map(|route| match route {
+            MockRoute::Info {
+                request_type,
+                response,
+            } if path == "info"
+                && body.get("type").and_then(Value::as_str) == Some(request_type) =>
+            {
+                Some((200, response.clone()))
+            }
+            MockRoute::Exchange {
+                action_type: expected,
+                response,
+            } if path == "exchange" && action_type == Some(expected.as_str()) => {
+                Some((200, response.clone()))
+            }
+            _ => None,
+        });
+
+        routed.or_else(|| self.handler.as_ref().and_then(|h| h(url, body)))
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn post(
+        &self,
+        url: &str,
+        body: Bytes,
+    ) -> Result<(u16, Bytes), HyperliquidError> {
+        let body: Value = serde_json::from_slice(&body)?;
+        let response = self.route(url, &body);
+
+        self.requests
+            .lock()
+            .expect("mock transport mutex poisoned")
+            .push(RecordedRequest {
+                url: url.to_string(),
+                body,
+            });
+
+        match response {
+            Some((status, value)) => {
+                Ok((status, Bytes::from(serde_json::to_vec(&value)?)))
+            }
+            None => Ok((404, Bytes::from_static(b"no mock response"))),
+        }
+    }
+}
@@ -1,8 +1,13 @@
 //! WebSocket provider for real-time market data and user events
 
-use std::sync::{
-    atomic::{AtomicU32, Ordering},
-    Arc,
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
 };
 
 use alloy::primitives::Address;
@@ -11,66 +16,234 @@ use fastwebsockets::{handshake, Frame, OpCode, Role, WebSocket};
 use http_body_util::Empty;
 use hyper::{body::Bytes, header, upgrade::Upgraded, Request, StatusCode};
 use hyper_util::rt::TokioIo;
-use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf, ReadHalf, WriteHalf},
+    sync::{mpsc, oneshot, Mutex},
+};
 
 use crate::{
     errors::HyperliquidError,
-    types::ws::{Message, Subscription, WsRequest},
+    providers::{
+        asset_registry::AssetRegistry,
+        candles::{BarPeriod, CandleSeries, CandleStream},
+        order_book::OrderBook,
+        ws_channel::{self, OverflowPolicy, SubscriptionReceiver, SubscriptionSender},
+    },
+    types::info_types::CandleInterval,
+    types::responses::ExchangeResponseStatus,
+    types::ws::{
+        Message, PostRequest, PostResponsePayload, RoutingKey, Subscription,
+        WsPostRequest, WsRequest,
+    },
     types::Symbol,
+    utils::now_ms,
     Network,
 };
 
 pub type SubscriptionId = u32;
 
+pub(crate) type WsReader =
+    WebSocket<HalfDuplex<ReadHalf<TokioIo<Upgraded>>, tokio::io::Sink>>;
+pub(crate) type WsWriter =
+    WebSocket<HalfDuplex<tokio::io::Empty, WriteHalf<TokioIo<Upgraded>>>>;
+
+/// Split an upgraded socket so reads and writes can proceed concurrently.
+///
+/// Control frames the reader would answer automatically are discarded; the
+/// Hyperliquid protocol keeps the connection alive with JSON pings instead.
+pub(crate) fn split_socket(
+    ws: WebSocket<TokioIo<Upgraded>>,
+    role: Role,
+) -> (WsReader, WsWriter) {
+    let (read, write) = tokio::io::split(ws.into_inner());
+    let reader = WebSocket::after_handshake(
+        HalfDuplex {
+            read,
+            write: tokio::io::sink(),
+        },
+        role,
+    );
+    let writer = WebSocket::after_handshake(
+        HalfDuplex {
+            read: tokio::io::empty(),
+            write,
+        },
+        role,
+    );
+    (reader, writer)
+}
+
+/// Joins an independent reader and writer into one duplex stream, so each half of
+/// a split socket can drive its own [`WebSocket`].
+pub(crate) struct HalfDuplex<R, W> {
+    read: R,
+    write: W,
+}
+
+impl<R: AsyncRead + Unpin, W: Unpin> AsyncRead for HalfDuplex<R, W> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.read).poll_read(cx, buf)
+    }
+}
+
+impl<R: Unpin, W: AsyncWrite + Unpin> AsyncWrite for HalfDuplex<R, W> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.write).poll_write(cx, buf)
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.write).poll_flush(cx)
+    }
+
+    fn poll_shutdown(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.write).poll_shutdown(cx)
+    }
+}
+
+type PendingPosts = Arc<DashMap<u64, oneshot::Sender<PostResponsePayload>>>;
+
+/// A post request awaiting its response.
+///
+/// Dropping it forgets the request; a late response is then discarded.
+pub struct PendingPost {
+    id: u64,
+    rx: oneshot::Receiver<PostResponsePayload>,
+    pending: PendingPosts,
+}
+
+impl PendingPost {
+    /// Request id sent to the server
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Wait for the response, failing after `timeout`
+    pub async fn response(
+        mut self,
+        timeout: Duration,
+    ) -> Result<PostResponsePayload, HyperliquidError> {
+        match tokio::time::timeout(timeout, &mut self.rx).await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(_)) => Err(HyperliquidError::WebSocket(
+                "Connection closed before post response".to_string(),
+            )),
+            Err(_) => Err(HyperliquidError::WebSocket(format!(
+                "Post request {} timed out after {:?}",
+                self.id, timeout
+            ))),
+        }
+    }
+}
+
+impl Drop for PendingPost {
+    fn drop(&mut self) {
+        self.pending.remove(&self.id);
+    }
+}
+
 #[derive(Clone)]
 struct SubscriptionHandle {
     subscription: Subscription,
-    tx: UnboundedSender<Message>,
+    key: RoutingKey,
+    tx: SubscriptionSender,
 }
 
 /// Raw WebSocket provider for Hyperliquid
 ///
 /// This is a thin wrapper around fastwebsockets that provides:
 /// - Type-safe subscriptions
-/// - Simple message routing
+/// - Message routing by channel, coin, user and interval
+/// - Bounded per-subscription queues (see [`OverflowPolicy`])
+/// - Info requests and signed actions sent as `post` messages, matched by id
 /// - No automatic reconnection (user controls retry logic)
 pub struct RawWsProvider {
     _network: Network,
-    ws: Option<WebSocket<TokioIo<Upgraded>>>,
+    reader: Option<WsReader>,
+    writer: Arc<Mutex<WsWriter>>,
+    connected: Arc<AtomicBool>,
     subscriptions: Arc<DashMap<SubscriptionId, SubscriptionHandle>>,
     next_id: Arc<AtomicU32>,
-    message_tx: Option<UnboundedSender<String>>,
+    message_tx: Option<mpsc::Sender<String>>,
     task_handle: Option<tokio::task::JoinHandle<()>>,
+    read_handle: Option<tokio::task::JoinHandle<()>>,
+    pending_posts: PendingPosts,
+    next_post_id: u64,
+    channel_capacity: usize,
+    overflow_policy: OverflowPolicy,
 }
 
 impl RawWsProvider {
     /// Connect to Hyperliquid WebSocket
     pub async fn connect(network: Network) -> Result<Self, HyperliquidError> {
-        let url = match network {
-            Network::Mainnet => "https://api.hyperliquid.xyz/ws",
-            Network::Testnet => "https://api.hyperliquid-testnet.xyz/ws",
+        Self::connect_with_config(network, &WsConfig::default()).await
+    }
+
+    /// Connect using the channel capacity and overflow policy from `config`
+    pub async fn connect_with_config(
+        network: Network,
+        config: &WsConfig,
+    ) -> Result<Self, HyperliquidError> {
+        // The upgrade request goes over HTTP(S), so map the ws scheme accordingly
+        let ws_url = network.ws_url();
+        let url = if let Some(rest) = ws_url.strip_prefix("wss://") {
+            format!("https://{}", rest)
+        } else if let Some(rest) = ws_url.strip_prefix("ws://") {
+            format!("http://{}", rest)
+        } else {
+            ws_url.to_string()
         };
 
-        let ws = Self::establish_connection(url).await?;
+        let ws = Self::establish_connection(&url).await?;
+        let (reader, writer) = split_socket(ws, Role::Client);
         let subscriptions = Arc::new(DashMap::new());
         let next_id = Arc::new(AtomicU32::new(1));
 
-        // Create message routing channel
-        let (message_tx, message_rx) = mpsc::unbounded_channel();
+        // Bounded so a blocked router pushes back on the socket reader
+        let (message_tx, message_rx) = mpsc::channel(config.channel_capacity.max(1));
+
+        let writer = Arc::new(Mutex::new(writer));
+        let connected = Arc::new(AtomicBool::new(true));
 
         // Spawn message routing task
-        let subscriptions_clone = subscriptions.clone();
-        let task_handle = tokio::spawn(async move {
-            Self::message_router(message_rx, subscriptions_clone).await;
-        });
+        let router = Router {
+            subscriptions: subscriptions.clone(),
+            pending_posts: PendingPosts::default(),
+            writer: writer.clone(),
+            connected: connected.clone(),
+        };
+        let pending_posts = router.pending_posts.clone();
+        let task_handle = tokio::spawn(router.run(message_rx));
 
         Ok(Self {
             _network: network,
-            ws: Some(ws),
+            reader: Some(reader),
+            writer,
+            connected,
             subscriptions,
             next_id,
             message_tx: Some(message_tx),
             task_handle: Some(task_handle),
+            read_handle: None,
+            pending_posts,
+            next_post_id: 1,
+            channel_capacity: config.channel_capacity,
+            overflow_policy: config.overflow_policy,
         })
     }
 
@@ -90,7 +263,7 @@ impl RawWsProvider {
             .map_err(|e| {
                 HyperliquidError::WebSocket(format!("Failed to load native roots: {}", e))
             })?
-            .https_only()
+            .https_or_http()
             .enable_http1()
             .build();
 
@@ -99,7 +272,8 @@ impl RawWsProvider {
 
         // Create WebSocket upgrade request
         let host = uri
-            .host()
+            .authority()
+            .map(|a| a.as_str())
             .ok_or_else(|| HyperliquidError::WebSocket("No host in URL".to_string()))?;
 
         let req = Request::builder()
@@ -140,7 +314,7 @@ impl RawWsProvider {
     pub async fn subscribe_l2_book(
         &mut self,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let symbol = coin.into();
         let subscription = Subscription::L2Book {
             coin: symbol.as_str().to_string(),
@@ -152,7 +326,7 @@ impl RawWsProvider {
     pub async fn subscribe_trades(
         &mut self,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let symbol = coin.into();
         let subscription = Subscription::Trades {
             coin: symbol.as_str().to_string(),
@@ -160,10 +334,24 @@ impl RawWsProvider {
         self.subscribe(subscription).await
     }
 
+    /// Subscribe to candle updates for a coin
+    pub async fn subscribe_candles(
+        &mut self,
+        coin: impl Into<Symbol>,
+        interval: CandleInterval,
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
+        let symbol = coin.into();
+        let subscription = Subscription::Candle {
+            coin: symbol.as_str().to_string(),
+            interval,
+        };
+        self.subscribe(subscription).await
+    }
+
     /// Subscribe to all mid prices
     pub async fn subscribe_all_mids(
         &mut self,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         self.subscribe(Subscription::AllMids).await
     }
 
@@ -173,7 +361,7 @@ impl RawWsProvider {
     pub async fn subscribe_bbo(
         &mut self,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let symbol = coin.into();
         let subscription = Subscription::Bbo {
             coin: symbol.as_str().to_string(),
@@ -185,7 +373,7 @@ impl RawWsProvider {
     pub async fn subscribe_open_orders(
         &mut self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::OpenOrders { user };
         self.subscribe(subscription).await
     }
@@ -194,7 +382,7 @@ impl RawWsProvider {
     pub async fn subscribe_clearinghouse_state(
         &mut self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::ClearinghouseState { user };
         self.subscribe(subscription).await
     }
@@ -205,7 +393,7 @@ impl RawWsProvider {
     pub async fn subscribe_web_data3(
         &mut self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::WebData3 { user };
         self.subscribe(subscription).await
     }
@@ -214,7 +402,7 @@ impl RawWsProvider {
     pub async fn subscribe_twap_states(
         &mut self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::TwapStates { user };
         self.subscribe(subscription).await
     }
@@ -223,7 +411,7 @@ impl RawWsProvider {
     pub async fn subscribe_active_asset_ctx(
         &mut self,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let symbol = coin.into();
         let subscription = Subscription::ActiveAssetCtx {
             coin: symbol.as_str().to_string(),
@@ -236,7 +424,7 @@ impl RawWsProvider {
         &mut self,
         user: Address,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let symbol = coin.into();
         let subscription = Subscription::ActiveAssetData {
             user,
@@ -249,7 +437,7 @@ impl RawWsProvider {
     pub async fn subscribe_user_twap_slice_fills(
         &mut self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::UserTwapSliceFills { user };
         self.subscribe(subscription).await
     }
@@ -258,7 +446,7 @@ impl RawWsProvider {
     pub async fn subscribe_user_twap_history(
         &mut self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::UserTwapHistory { user };
         self.subscribe(subscription).await
     }
@@ -267,31 +455,48 @@ impl RawWsProvider {
     pub async fn subscribe(
         &mut self,
         subscription: Subscription,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
-        let ws = self
-            .ws
-            .as_mut()
-            .ok_or_else(|| HyperliquidError::WebSocket("Not connected".to_string()))?;
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
+        let (tx, rx) = ws_channel::channel(self.channel_capacity, self.overflow_policy);
+        let id = self.subscribe_with_sender(subscription, tx).await?;
+        Ok((id, rx))
+    }
 
-        // Send subscription request
+    /// Subscribe, delivering messages into an existing channel
+    pub(crate) async fn subscribe_with_sender(
+        &mut self,
+        subscription: Subscription,
+        tx: SubscriptionSender,
+    ) -> Result<SubscriptionId, HyperliquidError> {
         let request = WsRequest::subscribe(subscription.clone());
         let payload = serde_json::to_string(&request)
             .map_err(|e| HyperliquidError::Serialize(e.to_string()))?;
 
-        ws.write_frame(Frame::text(payload.into_bytes().into()))
-            .await
-            .map_err(|e| {
-                HyperliquidError::WebSocket(format!("Failed to send subscription: {}", e))
-            })?;
-
-        // Create channel for this subscription
-        let (tx, rx) = mpsc::unbounded_channel();
+        // Register before sending so a snapshot arriving on an already
+        // running reader is not lost
         let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let key = subscription.routing_key();
+        self.subscriptions.insert(
+            id,
+            SubscriptionHandle {
+                subscription,
+                key,
+                tx,
+            },
+        );
 
-        self.subscriptions
-            .insert(id, SubscriptionHandle { subscription, tx });
+        if let Err(e) = self.send_text(payload).await {
+            self.subscriptions.remove(&id);
+            return Err(HyperliquidError::WebSocket(format!(
+                "Failed to send subscription: {}",
+                e
+            )));
+        }
 
-        Ok((id, rx))
+        Ok(id)
+    }
+
+    async fn send_text(&mut self, payload: String) -> Result<(), HyperliquidError> {
+        write_text(&self.writer, &self.connected, payload).await
     }
 
     /// Unsubscribe from a subscription
@@ -300,22 +505,13 @@ impl RawWsProvider {
         id: SubscriptionId,
     ) -> Result<(), HyperliquidError> {
         if let Some((_, handle)) = self.subscriptions.remove(&id) {
-            let ws = self.ws.as_mut().ok_or_else(|| {
-                HyperliquidError::WebSocket("Not connected".to_string())
-            })?;
-
             let request = WsRequest::unsubscribe(handle.subscription);
             let payload = serde_json::to_string(&request)
                 .map_err(|e| HyperliquidError::Serialize(e.to_string()))?;
 
-            ws.write_frame(Frame::text(payload.into_bytes().into()))
-                .await
-                .map_err(|e| {
-                    HyperliquidError::WebSocket(format!(
-                        "Failed to send unsubscribe: {}",
-                        e
-                    ))
-                })?;
+            self.send_text(payload).await.map_err(|e| {
+                HyperliquidError::WebSocket(format!("Failed to send unsubscribe: {}", e))
+            })?;
         }
 
         Ok(())
@@ -323,46 +519,94 @@ impl RawWsProvider {
 
     /// Send a ping to keep connection alive
     pub async fn ping(&mut self) -> Result<(), HyperliquidError> {
-        let ws = self
-            .ws
-            .as_mut()
-            .ok_or_else(|| HyperliquidError::WebSocket("Not connected".to_string()))?;
-
         let request = WsRequest::ping();
         let payload = serde_json::to_string(&request)
             .map_err(|e| HyperliquidError::Serialize(e.to_string()))?;
 
-        ws.write_frame(Frame::text(payload.into_bytes().into()))
-            .await
-            .map_err(|e| {
-                HyperliquidError::WebSocket(format!("Failed to send ping: {}", e))
-            })?;
+        self.send_text(payload).await.map_err(|e| {
+            HyperliquidError::WebSocket(format!("Failed to send ping: {}", e))
+        })
+    }
 
-        Ok(())
+    /// Send an info request or signed action as a `post` message
+    ///
+    /// The response arrives through the reader, so [`start_reading`](Self::start_reading)
+    /// must have been called before awaiting it.
+    pub async fn send_post(
+        &mut self,
+        request: PostRequest,
+    ) -> Result<PendingPost, HyperliquidError> {
+        let id = self.next_post_id;
+        self.next_post_id += 1;
+
+        let payload = serde_json::to_string(&WsPostRequest::new(id, request))
+            .map_err(|e| HyperliquidError::Serialize(e.to_string()))?;
+
+        let (tx, rx) = oneshot::channel();
+        self.pending_posts.insert(id, tx);
+        let pending = PendingPost {
+            id,
+            rx,
+            pending: self.pending_posts.clone(),
+        };
+
+        self.send_text(payload).await.map_err(|e| {
+            HyperliquidError::WebSocket(format!("Failed to send post request: {}", e))
+        })?;
+
+        Ok(pending)
+    }
+
+    /// Send a `post` message and wait up to `timeout` for its response
+    pub async fn post(
+        &mut self,
+        request: PostRequest,
+        timeout: Duration,
+    ) -> Result<PostResponsePayload, HyperliquidError> {
+        self.send_post(request).await?.response(timeout).await
     }
 
     /// Check if connected
+    ///
+    /// Turns false once the server closes the socket or a write fails.
     pub fn is_connected(&self) -> bool {
-        self.ws.is_some()
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Messages discarded by the overflow policy for one subscription
+    pub fn dropped_messages(&self, id: SubscriptionId) -> Option<u64> {
+        self.subscriptions
+            .get(&id)
+            .map(|handle| handle.tx.dropped())
+    }
+
+    /// Messages discarded by the overflow policy across all subscriptions
+    pub fn total_dropped_messages(&self) -> u64 {
+        self.subscriptions
+            .iter()
+            .map(|entry| entry.value().tx.dropped())
+            .sum()
     }
 
     /// Start reading messages (must be called after connecting)
     pub async fn start_reading(&mut self) -> Result<(), HyperliquidError> {
-        let mut ws = self
-            .ws
-            .take()
-            .ok_or_else(|| HyperliquidError::WebSocket("Not connected".to_string()))?;
+        let mut ws = self.reader.take().ok_or_else(|| {
+            HyperliquidError::WebSocket("Reading already started".to_string())
+        })?;
 
         let message_tx = self.message_tx.clone().ok_or_else(|| {
             HyperliquidError::WebSocket("Message channel not initialized".to_string())
         })?;
 
-        tokio::spawn(async move {
+        let connected = self.connected.clone();
+        self.read_handle = Some(tokio::spawn(async move {
             while let Ok(frame) = ws.read_frame().await {
                 match frame.opcode {
                     OpCode::Text => {
                         if let Ok(text) = String::from_utf8(frame.payload.to_vec()) {
-                            let _ = message_tx.send(text);
+                            if message_tx.send(text).await.is_err() {
+                                break;
+                            }
                         }
                     }
                     OpCode::Close => {
@@ -371,24 +615,79 @@ impl RawWsProvider {
                     _ => {}
                 }
             }
-        });
+            connected.store(false, Ordering::SeqCst);
+        }));
 
         Ok(())
     }
+}
+
+/// Write a text frame, marking the connection closed if the write fails
+async fn write_text(
+    writer: &Mutex<WsWriter>,
+    connected: &AtomicBool,
+    payload: String,
+) -> Result<(), HyperliquidError> {
+    if !connected.load(Ordering::SeqCst) {
+        return Err(HyperliquidError::WebSocket("Not connected".to_string()));
+    }
+
+    let result = writer
+        .lock()
+        .await
+        .write_frame(Frame::text(payload.into_bytes().into()))
+        .await;
+    if let Err(e) = result {
+        connected.store(false, Ordering::SeqCst);
+        return Err(HyperliquidError::WebSocket(e.to_string()));
+    }
+    Ok(())
+}
+
+/// Routes incoming messages to subscribers and pending posts
+struct Router {
+    subscriptions: Arc<DashMap<SubscriptionId, SubscriptionHandle>>,
+    pending_posts: PendingPosts,
+    writer: Arc<Mutex<WsWriter>>,
+    connected: Arc<AtomicBool>,
+}
 
-    async fn message_router(
-        mut rx: UnboundedReceiver<String>,
-        subscriptions: Arc<DashMap<SubscriptionId, SubscriptionHandle>>,
-    ) {
+impl Router {
+    async fn run(self, mut rx: mpsc::Receiver<String>) {
         while let Some(text) = rx.recv().await {
             // Use simd-json for fast parsing
             let mut text_bytes = text.into_bytes();
             match simd_json::from_slice::<Message>(&mut text_bytes) {
+                Ok(Message::Post(post)) => {
+                    if let Some((_, tx)) = self.pending_posts.remove(&post.data.id) {
+                        let _ = tx.send(post.data.response);
+                    }
+                }
                 Ok(message) => {
-                    // Route to all active subscriptions
-                    // In a more sophisticated implementation, we'd match by subscription type
-                    for entry in subscriptions.iter() {
-                        let _ = entry.value().tx.send(message.clone());
+                    // Protocol messages (subscription acks, pongs) have no subscriber
+                    let Some(key) = message.routing_key() else {
+                        continue;
+                    };
+
+                    let targets: Vec<(SubscriptionId, SubscriptionSender)> = self
+                        .subscriptions
+                        .iter()
+                        .filter(|entry| entry.value().key == key)
+                        .map(|entry| (*entry.key(), entry.value().tx.clone()))
+                        .collect();
+
+                    // Only clone when several subscriptions share a key;
+                    // the last one takes ownership of the message.
+                    // Sending may wait on a full channel under `OverflowPolicy::Block`
+                    if let Some(((last_id, last), rest)) = targets.split_last() {
+                        for (id, tx) in rest {
+                            if tx.send(message.clone()).await.is_err() {
+                                self.prune(*id).await;
+                            }
+                        }
+                        if last.send(message).await.is_err() {
+                            self.prune(*last_id).await;
+                        }
                     }
                 }
                 Err(_) => {
@@ -397,6 +696,29 @@ impl RawWsProvider {
             }
         }
     }
+
+    /// Drop a route whose receiver is gone, unsubscribing from the server
+    /// unless another subscription still uses the same feed
+    async fn prune(&self, id: SubscriptionId) {
+        let Some((_, handle)) = self.subscriptions.remove(&id) else {
+            return;
+        };
+        let shared = self
+            .subscriptions
+            .iter()
+            .any(|entry| entry.value().subscription == handle.subscription);
+        if shared {
+            return;
+        }
+
+        let request = WsRequest::unsubscribe(handle.subscription);
+        let Ok(payload) = serde_json::to_string(&request) else {
+            return;
+        };
+        if let Err(e) = write_text(&self.writer, &self.connected, payload).await {
+            tracing::warn!("Failed to unsubscribe pruned subscription {}: {}", id, e);
+        }
+    }
 }
 
 impl Drop for RawWsProvider {
@@ -405,13 +727,17 @@ impl Drop for RawWsProvider {
         if let Some(handle) = self.task_handle.take() {
             handle.abort();
         }
+        if let Some(handle) = self.read_handle.take() {
+            handle.abort();
+        }
+        // Fail outstanding posts instead of leaving them to time out
+        self.pending_posts.clear();
     }
 }
 
 // ==================== Enhanced WebSocket Provider ====================
 
-use std::time::Duration;
-use tokio::sync::Mutex;
+use tokio::sync::watch;
 use tokio::time::sleep;
 
 /// Configuration for managed WebSocket provider
@@ -431,6 +757,12 @@ pub struct WsConfig {
     pub exponential_backoff: bool,
     /// Maximum backoff delay when using exponential backoff
     pub max_reconnect_delay: Duration,
+    /// Maximum queued messages per subscription
+    pub channel_capacity: usize,
+    /// What a full subscription queue does with new messages
+    pub overflow_policy: OverflowPolicy,
+    /// How long `post_info` and `post_action` wait for a response
+    pub post_timeout: Duration,
 }
 
 impl Default for WsConfig {
@@ -443,6 +775,9 @@ impl Default for WsConfig {
             max_reconnect_attempts: None,
             exponential_backoff: true,
             max_reconnect_delay: Duration::from_secs(60),
+            channel_capacity: 1024,
+            overflow_policy: OverflowPolicy::Block,
+            post_timeout: Duration::from_secs(10),
         }
     }
 }
@@ -450,7 +785,9 @@ impl Default for WsConfig {
 #[derive(Clone)]
 struct ManagedSubscription {
     subscription: Subscription,
-    tx: UnboundedSender<Message>,
+    tx: SubscriptionSender,
+    /// Id on the current raw connection, replaced on every reconnect
+    raw_id: Option<SubscriptionId>,
 }
 
 /// Managed WebSocket provider with automatic keep-alive and reconnection
@@ -460,12 +797,16 @@ struct ManagedSubscription {
 /// - Automatic reconnection with subscription replay
 /// - Connection state monitoring
 /// - Configurable retry behavior
+/// - Locally maintained order books
+/// - Info requests and signed actions over the socket (`post_info`, `post_action`)
 pub struct ManagedWsProvider {
     network: Network,
     inner: Arc<Mutex<Option<RawWsProvider>>>,
     subscriptions: Arc<DashMap<SubscriptionId, ManagedSubscription>>,
     config: WsConfig,
     next_id: Arc<AtomicU32>,
+    disconnections: watch::Sender<u64>,
+    asset_registry: std::sync::RwLock<Option<Arc<AssetRegistry>>>,
 }
 
 impl ManagedWsProvider {
@@ -475,7 +816,8 @@ impl ManagedWsProvider {
         config: WsConfig,
     ) -> Result<Arc<Self>, HyperliquidError> {
         // Create initial connection
-        let raw_provider = RawWsProvider::connect(network).await?;
+        let raw_provider =
+            RawWsProvider::connect_with_config(network.clone(), &config).await?;
 
         let provider = Arc::new(Self {
             network,
@@ -483,6 +825,8 @@ impl ManagedWsProvider {
             subscriptions: Arc::new(DashMap::new()),
             config,
             next_id: Arc::new(AtomicU32::new(1)),
+            disconnections: watch::channel(0).0,
+            asset_registry: std::sync::RwLock::new(None),
         });
 
         // Start keep-alive task if configured
@@ -511,12 +855,52 @@ impl ManagedWsProvider {
         Self::connect(network, WsConfig::default()).await
     }
 
+    /// Resolve the coin of coin-based subscriptions through a shared registry,
+    /// so spot pairs can be named `BASE/QUOTE`.
+    ///
+    /// Applies to subscriptions made after the call.
+    pub fn with_asset_registry(
+        self: Arc<Self>,
+        registry: Arc<AssetRegistry>,
+    ) -> Arc<Self> {
+        *self
+            .asset_registry
+            .write()
+            .expect("asset registry lock poisoned") = Some(registry);
+        self
+    }
+
+    /// Get the attached asset registry.
+    pub fn asset_registry(&self) -> Option<Arc<AssetRegistry>> {
+        self.asset_registry
+            .read()
+            .expect("asset registry lock poisoned")
+            .clone()
+    }
+
+    /// Coin name the API expects for `symbol`, resolved through the asset
+    /// registry when one is attached.
+    async fn coin(&self, symbol: Symbol) -> Result<String, HyperliquidError> {
+        match self.asset_registry() {
+            Some(registry) => Ok(registry.resolve(symbol).await?.coin),
+            None => Ok(symbol.as_str().to_string()),
+        }
+    }
+
     /// Check if currently connected
     pub async fn is_connected(&self) -> bool {
         let inner = self.inner.lock().await;
         inner.as_ref().map(|p| p.is_connected()).unwrap_or(false)
     }
 
+    /// Watch the number of connection losses detected so far
+    ///
+    /// The counter changes as soon as a dropped connection is noticed, before
+    /// subscriptions are replayed on the new one.
+    pub fn disconnections(&self) -> watch::Receiver<u64> {
+        self.disconnections.subscribe()
+    }
+
     /// Get mutable access to the raw provider
     pub async fn raw(
         &self,
@@ -529,30 +913,141 @@ impl ManagedWsProvider {
     pub async fn subscribe_l2_book(
         &self,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
-        let symbol = coin.into();
-        let subscription = Subscription::L2Book {
-            coin: symbol.as_str().to_string(),
-        };
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
+        let coin = self.coin(coin.into()).await?;
+        let subscription = Subscription::L2Book { coin };
         self.subscribe(subscription).await
     }
 
+    /// Maintain a local order book for a coin from `l2Book` updates
+    ///
+    /// The returned receiver always holds the latest book. It is cleared when
+    /// the connection drops and refilled by the snapshot that follows the
+    /// reconnect, so an empty book means the feed is not live.
+    pub async fn subscribe_order_book(
+        &self,
+        coin: impl Into<Symbol>,
+    ) -> Result<(SubscriptionId, watch::Receiver<OrderBook>), HyperliquidError> {
+        let coin = self.coin(coin.into()).await?;
+        let (id, mut rx) = self
+            .subscribe(Subscription::L2Book { coin: coin.clone() })
+            .await?;
+
+        let (tx, book_rx) = watch::channel(OrderBook::new(coin));
+        let mut disconnections = self.disconnections.subscribe();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = tx.closed() => break,
+                    changed = disconnections.changed() => {
+                        if changed.is_err() {
+                            break;
+                        }
+                        // Anything still queued predates the disconnect
+                        while rx.try_recv().is_ok() {}
+                        tx.send_modify(OrderBook::clear);
+                    }
+                    message = rx.recv() => match message {
+                        Some(Message::L2Book(book)) => {
+                            match OrderBook::from_snapshot(&book.data) {
+                                Ok(book) => {
+                                    tx.send_replace(book);
+                                }
+                                Err(e) => tracing::warn!("Ignoring order book update: {}", e),
+                            }
+                        }
+                        Some(_) => {}
+                        None => break,
+                    },
+                }
+            }
+        });
+
+        Ok((id, book_rx))
+    }
+
     /// Subscribe to trades with automatic replay on reconnect
     pub async fn subscribe_trades(
         &self,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
-        let symbol = coin.into();
-        let subscription = Subscription::Trades {
-            coin: symbol.as_str().to_string(),
-        };
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
+        let coin = self.coin(coin.into()).await?;
+        let subscription = Subscription::Trades { coin };
+        self.subscribe(subscription).await
+    }
+
+    /// Subscribe to candle updates for a coin with automatic replay on reconnect
+    pub async fn subscribe_candles(
+        &self,
+        coin: impl Into<Symbol>,
+        interval: CandleInterval,
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
+        let coin = self.coin(coin.into()).await?;
+        let subscription = Subscription::Candle { coin, interval };
         self.subscribe(subscription).await
     }
 
+    /// Keep a candle series current from the `candle` feed, or from `trades`
+    /// for a custom period
+    ///
+    /// Seed the series first, e.g. with [`CandleSeries::load_history`]. The
+    /// forming bar is closed once its period has passed even if no update
+    /// arrives. Bars missed while disconnected are not backfilled.
+    pub async fn subscribe_candle_series(
+        &self,
+        mut series: CandleSeries,
+    ) -> Result<(SubscriptionId, CandleStream), HyperliquidError> {
+        let coin = self.coin(series.coin().to_string().into()).await?;
+        series.set_coin(coin.clone());
+        let subscription = match series.period() {
+            BarPeriod::Interval(interval) => Subscription::Candle { coin, interval },
+            BarPeriod::Custom(_) => Subscription::Trades { coin },
+        };
+        let (id, mut rx) = self.subscribe(subscription).await?;
+
+        let (tx, series_rx) = watch::channel(series);
+        let (closed_tx, closed_rx) = ws_channel::channel(
+            self.config.channel_capacity,
+            self.config.overflow_policy,
+        );
+        tokio::spawn(async move {
+            let mut expiry = tokio::time::interval(Duration::from_secs(1));
+            loop {
+                let mut closed = Vec::new();
+                tokio::select! {
+                    biased;
+                    _ = tx.closed() => break,
+                    message = rx.recv() => match message {
+                        Some(message) => {
+                            tx.send_modify(|series| match series.apply_message(&message) {
+                                Ok(bars) => closed = bars,
+                                Err(e) => tracing::warn!("Ignoring candle update: {}", e),
+                            });
+                        }
+                        None => break,
+                    },
+                    _ = expiry.tick() => {
+                        tx.send_if_modified(|series| {
+                            closed.extend(series.close_expired(now_ms()));
+                            !closed.is_empty()
+                        });
+                    }
+                }
+                for bar in closed {
+                    // Waits for the consumer under `OverflowPolicy::Block`
+                    let _ = closed_tx.send(bar).await;
+                }
+            }
+        });
+
+        Ok((id, CandleStream::new(series_rx, closed_rx)))
+    }
+
     /// Subscribe to all mid prices with automatic replay on reconnect
     pub async fn subscribe_all_mids(
         &self,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         self.subscribe(Subscription::AllMids).await
     }
 
@@ -562,11 +1057,9 @@ impl ManagedWsProvider {
     pub async fn subscribe_bbo(
         &self,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
-        let symbol = coin.into();
-        let subscription = Subscription::Bbo {
-            coin: symbol.as_str().to_string(),
-        };
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
+        let coin = self.coin(coin.into()).await?;
+        let subscription = Subscription::Bbo { coin };
         self.subscribe(subscription).await
     }
 
@@ -574,7 +1067,7 @@ impl ManagedWsProvider {
     pub async fn subscribe_open_orders(
         &self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::OpenOrders { user };
         self.subscribe(subscription).await
     }
@@ -583,7 +1076,7 @@ impl ManagedWsProvider {
     pub async fn subscribe_clearinghouse_state(
         &self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::ClearinghouseState { user };
         self.subscribe(subscription).await
     }
@@ -594,7 +1087,7 @@ impl ManagedWsProvider {
     pub async fn subscribe_web_data3(
         &self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::WebData3 { user };
         self.subscribe(subscription).await
     }
@@ -603,7 +1096,7 @@ impl ManagedWsProvider {
     pub async fn subscribe_twap_states(
         &self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::TwapStates { user };
         self.subscribe(subscription).await
     }
@@ -612,11 +1105,9 @@ impl ManagedWsProvider {
     pub async fn subscribe_active_asset_ctx(
         &self,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
-        let symbol = coin.into();
-        let subscription = Subscription::ActiveAssetCtx {
-            coin: symbol.as_str().to_string(),
-        };
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
+        let coin = self.coin(coin.into()).await?;
+        let subscription = Subscription::ActiveAssetCtx { coin };
         self.subscribe(subscription).await
     }
 
@@ -625,12 +1116,9 @@ impl ManagedWsProvider {
         &self,
         user: Address,
         coin: impl Into<Symbol>,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
-        let symbol = coin.into();
-        let subscription = Subscription::ActiveAssetData {
-            user,
-            coin: symbol.as_str().to_string(),
-        };
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
+        let coin = self.coin(coin.into()).await?;
+        let subscription = Subscription::ActiveAssetData { user, coin };
         self.subscribe(subscription).await
     }
 
@@ -638,7 +1126,7 @@ impl ManagedWsProvider {
     pub async fn subscribe_user_twap_slice_fills(
         &self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::UserTwapSliceFills { user };
         self.subscribe(subscription).await
     }
@@ -647,7 +1135,7 @@ impl ManagedWsProvider {
     pub async fn subscribe_user_twap_history(
         &self,
         user: Address,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let subscription = Subscription::UserTwapHistory { user };
         self.subscribe(subscription).await
     }
@@ -656,58 +1144,129 @@ impl ManagedWsProvider {
     pub async fn subscribe(
         &self,
         subscription: Subscription,
-    ) -> Result<(SubscriptionId, UnboundedReceiver<Message>), HyperliquidError> {
+    ) -> Result<(SubscriptionId, SubscriptionReceiver), HyperliquidError> {
         let mut inner = self.inner.lock().await;
         let raw_provider = inner
             .as_mut()
             .ok_or_else(|| HyperliquidError::WebSocket("Not connected".to_string()))?;
 
-        // Subscribe using the raw provider
-        let (_raw_id, rx) = raw_provider.subscribe(subscription.clone()).await?;
+        // The raw provider writes straight into our channel, so the same
+        // receiver keeps working across reconnects
+        let (tx, rx) = ws_channel::channel(
+            self.config.channel_capacity,
+            self.config.overflow_policy,
+        );
+        let raw_id = raw_provider
+            .subscribe_with_sender(subscription.clone(), tx.clone())
+            .await?;
 
         // Generate our own ID for tracking
         let managed_id = self.next_id.fetch_add(1, Ordering::SeqCst);
 
-        // Create channel for managed subscription
-        let (tx, managed_rx) = mpsc::unbounded_channel();
-
         // Store subscription for replay
         self.subscriptions.insert(
             managed_id,
             ManagedSubscription {
                 subscription,
-                tx: tx.clone(),
+                tx,
+                raw_id: Some(raw_id),
             },
         );
 
-        // Forward messages from raw to managed
-        let subscriptions = self.subscriptions.clone();
-        tokio::spawn(async move {
-            let mut rx = rx;
-            while let Some(msg) = rx.recv().await {
-                if let Some(entry) = subscriptions.get(&managed_id) {
-                    let _ = entry.tx.send(msg);
-                }
-            }
-            // Clean up when channel closes
-            subscriptions.remove(&managed_id);
-        });
-
-        Ok((managed_id, managed_rx))
+        Ok((managed_id, rx))
     }
 
     /// Unsubscribe and stop automatic replay
     pub async fn unsubscribe(&self, id: SubscriptionId) -> Result<(), HyperliquidError> {
-        // Remove from our tracking
-        self.subscriptions.remove(&id);
+        let Some((_, managed)) = self.subscriptions.remove(&id) else {
+            return Ok(());
+        };
 
-        // Note: We can't unsubscribe from the raw provider because we don't
-        // track the mapping between our IDs and raw IDs. This is fine since
-        // the subscription will be cleaned up on reconnect anyway.
+        let mut inner = self.inner.lock().await;
+        if let (Some(raw_provider), Some(raw_id)) = (inner.as_mut(), managed.raw_id) {
+            raw_provider.unsubscribe(raw_id).await?;
+        }
 
         Ok(())
     }
 
+    /// Messages discarded by the overflow policy for one subscription
+    pub fn dropped_messages(&self, id: SubscriptionId) -> Option<u64> {
+        self.subscriptions.get(&id).map(|entry| entry.tx.dropped())
+    }
+
+    /// Messages discarded by the overflow policy across all subscriptions
+    pub fn total_dropped_messages(&self) -> u64 {
+        self.subscriptions
+            .iter()
+            .map(|entry| entry.value().tx.dropped())
+            .sum()
+    }
+
+    /// Send a `post` message and wait up to `timeout` for its response
+    ///
+    /// The connection lock is only held while sending, so concurrent posts are
+    /// answered independently.
+    pub async fn post(
+        &self,
+        request: PostRequest,
+        timeout: Duration,
+    ) -> Result<PostResponsePayload, HyperliquidError> {
+        let pending = {
+            let mut inner = self.inner.lock().await;
+            let raw_provider = inner.as_mut().ok_or_else(|| {
+                HyperliquidError::WebSocket("Not connected".to_string())
+            })?;
+            raw_provider.send_post(request).await?
+        };
+        pending.response(timeout).await
+    }
+
+    /// Send an info request over the socket
+    ///
+    /// `request` is the body that would be posted to `/info`, e.g.
+    /// `{"type": "l2Book", "coin": "BTC"}`.
+    pub async fn post_info<T: DeserializeOwned>(
+        &self,
+        request: Value,
+    ) -> Result<T, HyperliquidError> {
+        match self
+            .post(PostRequest::Info(request), self.config.post_timeout)
+            .await?
+        {
+            PostResponsePayload::Info(mut payload) => {
+                serde_json::from_value(payload["data"].take()).map_err(Into::into)
+            }
+            other => Err(unexpected_post_response(other)),
+        }
+    }
+
+    /// Submit a signed exchange payload over the socket
+    ///
+    /// `payload` is the `{action, nonce, signature, vaultAddress}` body that
+    /// would be posted to `/exchange`. To sign and send in one step, give a
+    /// [`RawExchangeProvider`](crate::providers::RawExchangeProvider) this
+    /// connection with `with_ws_transport`.
+    pub async fn post_action(
+        &self,
+        payload: Value,
+    ) -> Result<ExchangeResponseStatus, HyperliquidError> {
+        match self
+            .post(PostRequest::Action(payload), self.config.post_timeout)
+            .await?
+        {
+            PostResponsePayload::Action(payload) => {
+                serde_json::from_value(payload).map_err(Into::into)
+            }
+            other => Err(unexpected_post_response(other)),
+        }
+    }
+
+    /// Timeout applied by `post_info`, `post_action` and the WebSocket transport
+    pub(crate) fn post_timeout(&self) -> Duration {
+        self.config.post_timeout
+    }
+
     /// Start reading messages (must be called after connecting)
     pub async fn start_reading(&self) -> Result<(), HyperliquidError> {
         let mut inner = self.inner.lock().await;
@@ -739,6 +1298,7 @@ impl ManagedWsProvider {
     async fn reconnect_loop(self: Arc<Self>) {
         let mut reconnect_attempts = 0u32;
         let mut current_delay = self.config.reconnect_delay;
+        let mut outage = false;
 
         loop {
             // Wait a bit before checking
@@ -746,6 +1306,11 @@ impl ManagedWsProvider {
 
             // Check if we need to reconnect
             if !self.is_connected().await {
+                if !outage {
+                    outage = true;
+                    self.disconnections.send_modify(|count| *count += 1);
+                }
+
                 // Check max attempts
                 if let Some(max) = self.config.max_reconnect_attempts {
                     if reconnect_attempts >= max {
@@ -756,7 +1321,12 @@ impl ManagedWsProvider {
 
                 tracing::info!("Attempting reconnection #{}", reconnect_attempts + 1);
 
-                match RawWsProvider::connect(self.network).await {
+                match RawWsProvider::connect_with_config(
+                    self.network.clone(),
+                    &self.config,
+                )
+                .await
+                {
                     Ok(mut new_provider) => {
                         // Start reading before replaying subscriptions
                         if let Err(e) = new_provider.start_reading().await {
@@ -767,20 +1337,48 @@ impl ManagedWsProvider {
                             continue;
                         }
 
-                        // Replay all subscriptions
+                        // Forget subscriptions whose receiver has been dropped
+                        self.subscriptions.retain(|_, entry| !entry.tx.is_closed());
+
+                        // Replay all subscriptions into their existing channels
+                        let pending: Vec<_> = self
+                            .subscriptions
+                            .iter()
+                            .map(|entry| {
+                                (
+                                    *entry.key(),
+                                    entry.subscription.clone(),
+                                    entry.tx.clone(),
+                                )
+                            })
+                            .collect();
                         let mut replay_errors = 0;
-                        for entry in self.subscriptions.iter() {
-                            if let Err(e) =
-                                new_provider.subscribe(entry.subscription.clone()).await
+                        for (id, subscription, tx) in pending {
+                            match new_provider
+                                .subscribe_with_sender(subscription, tx)
+                                .await
                             {
-                                tracing::warn!("Failed to replay subscription: {}", e);
-                                replay_errors += 1;
+                                Ok(raw_id) => {
+                                    if let Some(mut entry) =
+                                        self.subscriptions.get_mut(&id)
+                                    {
+                                        entry.raw_id = Some(raw_id);
+                                    }
+                                }
+                                Err(e) => {
+                                    tracing::warn!(
+                                        "Failed to replay subscription: {}",
+                                        e
+                                    );
+                                    replay_errors += 1;
+                                }
                             }
                         }
 
                         if replay_errors == 0 {
                             // Success! Reset counters
                             *self.inner.lock().await = Some(new_provider);
+                            outage = false;
                             reconnect_attempts = 0;
                             current_delay = self.config.reconnect_delay;
                             tracing::info!(
@@ -815,6 +1413,18 @@ impl ManagedWsProvider {
     }
 }
 
+fn unexpected_post_response(response: PostResponsePayload) -> HyperliquidError {
+    match response {
+        PostResponsePayload::Error(message) => {
+            HyperliquidError::WebSocket(format!("Post request failed: {}", message))
+        }
+        other => HyperliquidError::InvalidResponse(format!(
+            "Unexpected post response: {:?}",
+            other
+        )),
+    }
+}
+
 // Note: Background tasks (keepalive and reconnect loops) will automatically
 // terminate when all Arc references to the provider are dropped, since they
 // hold Arc<Self> and will exit when is_connected() returns false.
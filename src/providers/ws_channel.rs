@@ -0,0 +1,285 @@
+//! Bounded subscription channels for the WebSocket providers
+//!
+//! Each subscription gets its own queue of at most `capacity` messages (or other
+//! items, such as the bars of a candle series). What
+//! happens when a consumer falls behind is chosen by [`OverflowPolicy`]; every
+//! message discarded by the policy is counted so slow consumers can be detected.
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+};
+
+use tokio::sync::{mpsc::error::TryRecvError, Notify};
+
+use crate::types::ws::{Message, RoutingKey};
+
+/// What a subscription channel does with a new message when it is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Wait for the consumer, which stalls the connection reader and with it
+    /// every other subscription on the same connection
+    #[default]
+    Block,
+    /// Discard the oldest queued message to make room
+    DropOldest,
+    /// Discard the incoming message
+    DropNewest,
+    /// Keep only the latest queued `l2Book`, `bbo` and `allMids` message per
+    /// routing key; other channels block when full
+    ConflateLatest,
+}
+
+/// Items a subscription channel can queue.
+pub(crate) trait Queued {
+    /// Key under which [`OverflowPolicy::ConflateLatest`] keeps only the latest
+    /// item, `None` for items that must all be delivered
+    fn conflation_key(&self) -> Option<RoutingKey>;
+}
+
+impl Queued for Message {
+    fn conflation_key(&self) -> Option<RoutingKey> {
+        if is_conflatable(self) {
+            self.routing_key()
+        } else {
+            None
+        }
+    }
+}
+
+struct Inner<T> {
+    queue: VecDeque<(Option<RoutingKey>, T)>,
+    senders: usize,
+    receiver_alive: bool,
+}
+
+struct Shared<T> {
+    inner: Mutex<Inner<T>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: AtomicU64,
+    /// Signalled when a message is queued or the last sender goes away
+    items: Notify,
+    /// Signalled when space frees up or the receiver goes away
+    space: Notify,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, Inner<T>> {
+        self.inner
+            .lock()
+            .expect("subscription channel mutex poisoned")
+    }
+}
+
+/// Create a subscription channel holding at most `capacity` messages.
+pub(crate) fn channel<T>(
+    capacity: usize,
+    policy: OverflowPolicy,
+) -> (SubscriptionSender<T>, SubscriptionReceiver<T>) {
+    let shared = Arc::new(Shared {
+        inner: Mutex::new(Inner {
+            queue: VecDeque::new(),
+            senders: 1,
+            receiver_alive: true,
+        }),
+        capacity: capacity.max(1),
+        policy,
+        dropped: AtomicU64::new(0),
+        items: Notify::new(),
+        space: Notify::new(),
+    });
+    (
+        SubscriptionSender {
+            shared: shared.clone(),
+        },
+        SubscriptionReceiver { shared },
+    )
+}
+
+/// Sending half of a subscription channel, owned by the message router.
+pub(crate) struct SubscriptionSender<T = Message> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: Queued> SubscriptionSender<T> {
+    /// Queue a message according to the channel's overflow policy.
+    ///
+    /// Returns the message back if the receiver has been dropped.
+    pub(crate) async fn send(&self, message: T) -> Result<(), T> {
+        let key = match self.shared.policy {
+            OverflowPolicy::ConflateLatest => message.conflation_key(),
+            _ => None,
+        };
+        let mut entry = (key, message);
+
+        loop {
+            let space = self.shared.space.notified();
+            tokio::pin!(space);
+            space.as_mut().enable();
+
+            {
+                let mut inner = self.shared.lock();
+                if !inner.receiver_alive {
+                    return Err(entry.1);
+                }
+                match self.push(&mut inner, entry) {
+                    None => {
+                        drop(inner);
+                        self.shared.items.notify_one();
+                        return Ok(());
+                    }
+                    Some(rejected) => entry = rejected,
+                }
+            }
+
+            space.await;
+        }
+    }
+
+    /// Apply the overflow policy; hands the entry back when the caller must wait.
+    fn push(
+        &self,
+        inner: &mut Inner<T>,
+        entry: (Option<RoutingKey>, T),
+    ) -> Option<(Option<RoutingKey>, T)> {
+        if let Some(key) = &entry.0 {
+            if let Some(slot) = inner
+                .queue
+                .iter_mut()
+                .find(|(queued, _)| queued.as_ref() == Some(key))
+            {
+                *slot = entry;
+                self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+                return None;
+            }
+        }
+
+        if inner.queue.len() < self.shared.capacity {
+            inner.queue.push_back(entry);
+            return None;
+        }
+
+        match self.shared.policy {
+            OverflowPolicy::Block | OverflowPolicy::ConflateLatest => Some(entry),
+            OverflowPolicy::DropNewest => {
+                self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+            OverflowPolicy::DropOldest => {
+                inner.queue.pop_front();
+                inner.queue.push_back(entry);
+                self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+}
+
+impl<T> SubscriptionSender<T> {
+    /// Whether the receiving half has been dropped.
+    pub(crate) fn is_closed(&self) -> bool {
+        !self.shared.lock().receiver_alive
+    }
+
+    /// Messages discarded by the overflow policy so far.
+    pub(crate) fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Clone for SubscriptionSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for SubscriptionSender<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.lock();
+        inner.senders -= 1;
+        if inner.senders == 0 {
+            drop(inner);
+            self.shared.items.notify_one();
+        }
+    }
+}
+
+/// Receiving half of a subscription, returned by the `subscribe_*` methods.
+pub struct SubscriptionReceiver<T = Message> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> SubscriptionReceiver<T> {
+    /// Wait for the next message; `None` once the subscription is closed and drained.
+    pub async fn recv(&mut self) -> Option<T> {
+        let shared = self.shared.clone();
+        loop {
+            let items = shared.items.notified();
+            tokio::pin!(items);
+            items.as_mut().enable();
+
+            match self.try_recv() {
+                Ok(message) => return Some(message),
+                Err(TryRecvError::Disconnected) => return None,
+                Err(TryRecvError::Empty) => {}
+            }
+
+            items.await;
+        }
+    }
+
+    /// Take the next message without waiting.
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let mut inner = self.shared.lock();
+        match inner.queue.pop_front() {
+            Some((_, message)) => {
+                drop(inner);
+                self.shared.space.notify_one();
+                Ok(message)
+            }
+            None if inner.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Number of messages waiting to be received.
+    pub fn len(&self) -> usize {
+        self.shared.lock().queue.len()
+    }
+
+    /// Whether no messages are waiting.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of queued messages.
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity
+    }
+
+    /// Messages discarded by the overflow policy so far.
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Drop for SubscriptionReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.lock().receiver_alive = false;
+        self.shared.space.notify_waiters();
+    }
+}
+
+fn is_conflatable(message: &Message) -> bool {
+    matches!(
+        message,
+        Message::L2Book(_) | Message::Bbo(_) | Message::AllMids(_)
+    )
+}
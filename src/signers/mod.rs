@@ -1,5 +1,6 @@
 pub mod privy;
 pub mod signer;
+pub mod verify;
 
 pub use privy::{PrivyError, PrivySigner};
 pub use signer::{AlloySigner, HyperliquidSignature, HyperliquidSigner, SignerError};
@@ -27,6 +27,9 @@ pub enum SignerError {
 
     #[error("signer unavailable")]
     Unavailable,
+
+    #[error("invalid signature: {0}")]
+    InvalidSignature(String),
 }
 
 pub struct AlloySigner<S: Signer> {
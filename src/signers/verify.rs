@@ -0,0 +1,191 @@
+//! Recovering and checking the signers of Hyperliquid actions.
+//!
+//! L1 actions are re-hashed the way they were signed: the action is encoded
+//! with MessagePack, hashed with the nonce and vault address into a connection
+//! id, and wrapped in the EIP-712 `Agent` struct. User actions (`usdSend`,
+//! `withdraw3`, ...) are rebuilt from their fields and hashed with their own
+//! EIP-712 type. For `multiSig` actions the outer signer is recovered. JSON
+//! actions are deserialized into their action structs by `type` first, so they
+//! re-hash in the field order they were signed with.
+//!
+//! # Example
+//! ```ignore
+//! use hyperliquid_sdk_rs::signers::verify;
+//!
+//! let signed: SignedAction = serde_json::from_str(&payload)?;
+//! let signer = verify::recover_signer(&signed, &Network::Mainnet)?;
+//! println!("signed by {:#x}", signer);
+//! ```
+
+use alloy::primitives::{Address, Parity, Signature, B256, U256};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    constants::Network,
+    errors::HyperliquidError,
+    providers::exchange::{hash_action, hash_msgpack, ActionSignature, SignedAction},
+    signers::SignerError,
+    types::{
+        actions::{Agent, MultiSig, SendMultiSig, TaggedAction},
+        eip712::HyperliquidAction,
+    },
+};
+
+type Result<T> = std::result::Result<T, HyperliquidError>;
+
+/// Address that signed `payload`.
+///
+/// `network` must be the one the payload was signed for: L1 signatures
+/// commit to its agent source.
+pub fn recover_signer(payload: &SignedAction, network: &Network) -> Result<Address> {
+    recover_from_hash(signing_hash(payload, network)?, &payload.signature)
+}
+
+/// Whether `payload` was signed by `expected`.
+pub fn verify_signer(
+    payload: &SignedAction,
+    network: &Network,
+    expected: Address,
+) -> Result<bool> {
+    Ok(recover_signer(payload, network)? == expected)
+}
+
+/// The digest the signer of `payload` signed.
+pub fn signing_hash(payload: &SignedAction, network: &Network) -> Result<B256> {
+    action_signing_hash(
+        &payload.action,
+        payload.nonce,
+        payload.vault_address,
+        network,
+    )
+}
+
+/// The digest signed for a JSON `action` with its `type` tag.
+///
+/// The action is deserialized into its action struct by type, so it hashes in
+/// the field order it was signed with.
+pub(crate) fn action_signing_hash(
+    action: &Value,
+    nonce: u64,
+    vault_address: Option<Address>,
+    network: &Network,
+) -> Result<B256> {
+    let action: TaggedAction = serde_json::from_value(action.clone())?;
+    if let Some(hash) = typed_user_action_hash(&action, None) {
+        return Ok(hash);
+    }
+    if let TaggedAction::MultiSig(multi_sig) = &action {
+        return multi_sig_hash(multi_sig, nonce, vault_address, network);
+    }
+    let connection_id = hash_msgpack(&action, nonce, vault_address)?;
+    Ok(agent_signing_hash(connection_id, network))
+}
+
+/// The EIP-712 digest signed for an L1 action.
+///
+/// `action` is serialized without its `type` tag, in the field order it was
+/// signed with.
+pub fn l1_signing_hash<T: Serialize>(
+    action_type: &str,
+    action: &T,
+    nonce: u64,
+    vault_address: Option<Address>,
+    network: &Network,
+) -> Result<B256> {
+    let connection_id = hash_action(action_type, action, nonce, vault_address)?;
+    Ok(agent_signing_hash(connection_id, network))
+}
+
+fn agent_signing_hash(connection_id: B256, network: &Network) -> B256 {
+    let agent = Agent {
+        source: network.agent_source().to_string(),
+        connection_id,
+    };
+    agent.eip712_signing_hash(&agent.domain())
+}
+
+/// Address that produced `signature` over `hash`.
+pub fn recover_from_hash(hash: B256, signature: &ActionSignature) -> Result<Address> {
+    let invalid = |e: String| HyperliquidError::Signer(SignerError::InvalidSignature(e));
+    let component = |hex: &str| {
+        hex.parse::<U256>()
+            .map_err(|e| invalid(format!("bad component {:?}: {}", hex, e)))
+    };
+    let parity = Parity::try_from(signature.v).map_err(|e| invalid(e.to_string()))?;
+    Signature::from_rs_and_parity(
+        component(&signature.r)?,
+        component(&signature.s)?,
+        parity,
+    )
+    .and_then(|signature| signature.recover_address_from_prehash(&hash))
+    .map_err(|e| invalid(e.to_string()))
+}
+
+/// Whether `action_type` is signed as an EIP-712 user action rather than an
+/// L1 action.
+pub(crate) fn is_user_action(action_type: &str) -> bool {
+    matches!(
+        action_type,
+        "usdSend" | "withdraw3" | "spotSend" | "approveAgent" | "approveBuilderFee"
+    )
+}
+
+/// EIP-712 digest of a user action given its untagged fields, or the digest a
+/// co-signer signs when `multi_sig` holds the multi-sig user and outer signer.
+pub(crate) fn user_action_hash(
+    action_type: &str,
+    fields: Map<String, Value>,
+    multi_sig: Option<(Address, Address)>,
+) -> Result<B256> {
+    let mut tagged = fields;
+    tagged.insert("type".to_string(), Value::from(action_type));
+    let action: TaggedAction = serde_json::from_value(Value::Object(tagged))?;
+    typed_user_action_hash(&action, multi_sig).ok_or_else(|| {
+        HyperliquidError::InvalidRequest(format!("{} is not a user action", action_type))
+    })
+}
+
+/// EIP-712 digest of `action` if it is a user action
+fn typed_user_action_hash(
+    action: &TaggedAction,
+    multi_sig: Option<(Address, Address)>,
+) -> Option<B256> {
+    match action {
+        TaggedAction::UsdSend(action) => Some(typed_hash(action, multi_sig)),
+        TaggedAction::Withdraw3(action) => Some(typed_hash(action, multi_sig)),
+        TaggedAction::SpotSend(action) => Some(typed_hash(action, multi_sig)),
+        TaggedAction::ApproveAgent(action) => Some(typed_hash(action, multi_sig)),
+        TaggedAction::ApproveBuilderFee(action) => Some(typed_hash(action, multi_sig)),
+        _ => None,
+    }
+}
+
+fn typed_hash<T: HyperliquidAction>(
+    action: &T,
+    multi_sig: Option<(Address, Address)>,
+) -> B256 {
+    let domain = action.domain();
+    match multi_sig {
+        Some((user, outer_signer)) => {
+            action.multi_sig_signing_hash(&domain, user, outer_signer)
+        }
+        None => action.eip712_signing_hash(&domain),
+    }
+}
+
+/// Digest the outer signer of a `multiSig` action signs.
+fn multi_sig_hash(
+    action: &MultiSig,
+    nonce: u64,
+    vault_address: Option<Address>,
+    network: &Network,
+) -> Result<B256> {
+    let envelope = SendMultiSig {
+        signature_chain_id: action.signature_chain_id,
+        hyperliquid_chain: network.hyperliquid_chain().to_string(),
+        multi_sig_action_hash: hash_msgpack(action, nonce, vault_address)?,
+        nonce,
+    };
+    Ok(envelope.eip712_signing_hash(&envelope.domain()))
+}
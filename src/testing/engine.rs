@@ -0,0 +1,914 @@
+//! In-memory matching engine backing the mock server
+
+use std::collections::HashMap;
+
+use alloy::primitives::Address;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    types::{
+        requests::{OrderRequest, OrderType},
+        ws::Subscription,
+    },
+    utils::now_ms,
+};
+
+/// Tolerance for float comparisons on sizes and prices.
+const EPSILON: f64 = 1e-9;
+
+/// Smallest order notional accepted for non reduce-only orders, as on the real venue.
+const MIN_ORDER_VALUE: f64 = 10.0;
+
+/// Default leverage applied to an asset until the user updates it.
+const DEFAULT_LEVERAGE: u32 = 20;
+
+/// A perpetual listed on the mock server.
+#[derive(Debug, Clone)]
+pub struct MockAsset {
+    pub name: String,
+    pub sz_decimals: u32,
+    pub max_leverage: u32,
+}
+
+impl MockAsset {
+    pub fn new(name: impl Into<String>, sz_decimals: u32, max_leverage: u32) -> Self {
+        Self {
+            name: name.into(),
+            sz_decimals,
+            max_leverage,
+        }
+    }
+}
+
+/// Format a float for use in API requests.
+/// Formats to 8 decimal places and removes trailing zeros.
+fn format_float_string(value: f64) -> String {
+    let mut x = format!("{:.8}", value);
+    while x.ends_with('0') {
+        x.pop();
+    }
+    if x.ends_with('.') {
+        x.pop();
+    }
+    if x == "-0" {
+        "0".to_string()
+    } else {
+        x
+    }
+}
+
+/// Messages to publish to WebSocket subscribers after an engine mutation.
+pub(crate) type Events = Vec<(Subscription, Value)>;
+
+#[derive(Debug, Clone)]
+struct RestingOrder {
+    oid: u64,
+    user: Address,
+    is_buy: bool,
+    px: f64,
+    sz: f64,
+    orig_sz: f64,
+    cloid: Option<String>,
+    timestamp: u64,
+}
+
+#[derive(Debug, Default)]
+struct Book {
+    /// Sorted best (highest) price first, FIFO within a level.
+    bids: Vec<RestingOrder>,
+    /// Sorted best (lowest) price first, FIFO within a level.
+    asks: Vec<RestingOrder>,
+}
+
+impl Book {
+    fn insert(&mut self, order: RestingOrder) {
+        let side = if order.is_buy {
+            &mut self.bids
+        } else {
+            &mut self.asks
+        };
+        let is_buy = order.is_buy;
+        let idx = side
+            .iter()
+            .position(|o| {
+                if is_buy {
+                    o.px < order.px
+                } else {
+                    o.px > order.px
+                }
+            })
+            .unwrap_or(side.len());
+        side.insert(idx, order);
+    }
+
+    fn best_bid(&self) -> Option<f64> {
+        self.bids.first().map(|o| o.px)
+    }
+
+    fn best_ask(&self) -> Option<f64> {
+        self.asks.first().map(|o| o.px)
+    }
+
+    fn remove(&mut self, oid: u64) -> Option<RestingOrder> {
+        for side in [&mut self.bids, &mut self.asks] {
+            if let Some(idx) = side.iter().position(|o| o.oid == oid) {
+                return Some(side.remove(idx));
+            }
+        }
+        None
+    }
+
+    fn find(&self, predicate: impl Fn(&RestingOrder) -> bool) -> Option<&RestingOrder> {
+        self.bids
+            .iter()
+            .chain(self.asks.iter())
+            .find(|o| predicate(o))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    szi: f64,
+    entry_px: f64,
+}
+
+#[derive(Debug, Default)]
+struct Account {
+    balance: f64,
+    positions: HashMap<u32, Position>,
+    leverage: HashMap<u32, (u32, bool)>,
+    /// Newest first, as returned by `userFills`.
+    fills: Vec<Value>,
+}
+
+/// A single execution between a resting maker and an incoming taker.
+struct Match {
+    maker: RestingOrder,
+    sz: f64,
+    px: f64,
+    maker_done: bool,
+}
+
+pub(crate) struct Engine {
+    assets: Vec<MockAsset>,
+    books: Vec<Book>,
+    last_px: Vec<Option<f64>>,
+    accounts: HashMap<Address, Account>,
+    /// Approved API wallets and the account each one trades for.
+    agents: HashMap<Address, Address>,
+    next_oid: u64,
+    next_tid: u64,
+}
+
+impl Engine {
+    pub(crate) fn new(assets: Vec<MockAsset>) -> Self {
+        let books = assets.iter().map(|_| Book::default()).collect();
+        let last_px = vec![None; assets.len()];
+        Self {
+            assets,
+            books,
+            last_px,
+            accounts: HashMap::new(),
+            agents: HashMap::new(),
+            next_oid: 1,
+            next_tid: 1,
+        }
+    }
+
+    // ==================== Accounts ====================
+
+    pub(crate) fn fund(&mut self, user: Address, usdc: f64) {
+        self.accounts.entry(user).or_default().balance += usdc;
+    }
+
+    pub(crate) fn has_account(&self, user: &Address) -> bool {
+        self.accounts.contains_key(user)
+    }
+
+    pub(crate) fn approve_agent(&mut self, agent: Address, master: Address) {
+        self.agents.insert(agent, master);
+    }
+
+    /// Account an action signed by `signer` trades for: the master of an
+    /// approved agent, otherwise the signer itself.
+    pub(crate) fn account_for(&self, signer: Address) -> Address {
+        self.agents.get(&signer).copied().unwrap_or(signer)
+    }
+
+    pub(crate) fn update_leverage(
+        &mut self,
+        user: Address,
+        asset: u32,
+        is_cross: bool,
+        leverage: u32,
+    ) -> Result<(), String> {
+        let max_leverage = self
+            .assets
+            .get(asset as usize)
+            .map(|a| a.max_leverage)
+            .ok_or_else(|| format!("Invalid asset: {}", asset))?;
+        if leverage == 0 || leverage > max_leverage {
+            return Err(format!(
+                "Invalid leverage value: {} (max {})",
+                leverage, max_leverage
+            ));
+        }
+        self.accounts
+            .entry(user)
+            .or_default()
+            .leverage
+            .insert(asset, (leverage, is_cross));
+        Ok(())
+    }
+
+    // ==================== Info ====================
+
+    pub(crate) fn asset_index(&self, coin: &str) -> Option<u32> {
+        self.assets
+            .iter()
+            .position(|a| a.name == coin)
+            .map(|i| i as u32)
+    }
+
+    pub(crate) fn meta(&self) -> Value {
+        let universe: Vec<Value> = self
+            .assets
+            .iter()
+            .map(|a| {
+                json!({
+                    "name": a.name,
+                    "szDecimals": a.sz_decimals,
+                    "maxLeverage": a.max_leverage,
+                    "onlyIsolated": false,
+                })
+            })
+            .collect();
+        json!({ "universe": universe })
+    }
+
+    pub(crate) fn all_mids(&self) -> Value {
+        let mids: Map<String, Value> = self
+            .assets
+            .iter()
+            .enumerate()
+            .filter_map(|(i, a)| {
+                self.mark_px(i as u32)
+                    .map(|px| (a.name.clone(), json!(format_float_string(px))))
+            })
+            .collect();
+        Value::Object(mids)
+    }
+
+    pub(crate) fn l2_book(&self, coin: &str) -> Option<Value> {
+        let asset = self.asset_index(coin)?;
+        let book = &self.books[asset as usize];
+        Some(json!({
+            "coin": coin,
+            "time": now_ms(),
+            "levels": [aggregate(&book.bids), aggregate(&book.asks)],
+        }))
+    }
+
+    pub(crate) fn clearinghouse_state(&self, user: &Address) -> Value {
+        let account = self.accounts.get(user);
+        let balance = account.map(|a| a.balance).unwrap_or_default();
+
+        let mut asset_positions = Vec::new();
+        let mut total_ntl = 0.0;
+        let mut total_margin = 0.0;
+        let mut total_upnl = 0.0;
+        let mut signed_ntl = 0.0;
+
+        if let Some(account) = account {
+            let mut positions: Vec<_> = account.positions.iter().collect();
+            positions.sort_by_key(|(asset, _)| **asset);
+            for (&asset, position) in positions {
+                let meta = &self.assets[asset as usize];
+                let (leverage, is_cross) = account
+                    .leverage
+                    .get(&asset)
+                    .copied()
+                    .unwrap_or((DEFAULT_LEVERAGE.min(meta.max_leverage), true));
+                let mark = self.mark_px(asset).unwrap_or(position.entry_px);
+                let value = position.szi.abs() * mark;
+                let upnl = position.szi * (mark - position.entry_px);
+                let margin = value / leverage as f64;
+                let roe = if position.entry_px > 0.0 {
+                    upnl * leverage as f64 / (position.szi.abs() * position.entry_px)
+                } else {
+                    0.0
+                };
+
+                total_ntl += value;
+                total_margin += margin;
+                total_upnl += upnl;
+                signed_ntl += position.szi * mark;
+
+                asset_positions.push(json!({
+                    "type": "oneWay",
+                    "position": {
+                        "coin": meta.name,
+                        "entryPx": format_float_string(position.entry_px),
+                        "leverage": {
+                            "type": if is_cross { "cross" } else { "isolated" },
+                            "value": leverage,
+                            "rawUsd": Value::Null,
+                        },
+                        "liquidationPx": Value::Null,
+                        "marginUsed": format_float_string(margin),
+                        "positionValue": format_float_string(value),
+                        "returnOnEquity": format_float_string(roe),
+                        "szi": format_float_string(position.szi),
+                        "unrealizedPnl": format_float_string(upnl),
+                        "maxLeverage": meta.max_leverage,
+                        "cumFunding": {
+                            "allTime": "0",
+                            "sinceOpen": "0",
+                            "sinceChange": "0",
+                        },
+                    },
+                }));
+            }
+        }
+
+        let account_value = balance + total_upnl;
+        let summary = json!({
+            "accountValue": format_float_string(account_value),
+            "totalMarginUsed": format_float_string(total_margin),
+            "totalNtlPos": format_float_string(total_ntl),
+            "totalRawUsd": format_float_string(account_value - signed_ntl),
+        });
+
+        json!({
+            "assetPositions": asset_positions,
+            "marginSummary": summary,
+            "crossMarginSummary": summary,
+            "withdrawable": format_float_string((account_value - total_margin).max(0.0)),
+        })
+    }
+
+    pub(crate) fn open_orders(&self, user: &Address) -> Value {
+        let mut orders = Vec::new();
+        for (asset, book) in self.books.iter().enumerate() {
+            for order in book.bids.iter().chain(book.asks.iter()) {
+                if order.user == *user {
+                    orders.push(self.order_json(asset as u32, order));
+                }
+            }
+        }
+        Value::Array(orders)
+    }
+
+    /// Spot balances; the mock only holds USDC.
+    pub(crate) fn spot_clearinghouse_state(&self, user: &Address) -> Value {
+        let balance = self
+            .accounts
+            .get(user)
+            .map(|a| a.balance)
+            .unwrap_or_default();
+        json!({
+            "balances": [{
+                "coin": "USDC",
+                "hold": "0",
+                "total": format_float_string(balance),
+                "entryNtl": "0",
+            }],
+        })
+    }
+
+    pub(crate) fn user_fills(&self, user: &Address) -> Value {
+        let fills = self
+            .accounts
+            .get(user)
+            .map(|a| a.fills.clone())
+            .unwrap_or_default();
+        Value::Array(fills)
+    }
+
+    /// Initial message sent right after a WebSocket subscription is acknowledged.
+    pub(crate) fn snapshot(&self, subscription: &Subscription) -> Option<Value> {
+        match subscription {
+            Subscription::AllMids => Some(self.all_mids_message()),
+            Subscription::L2Book { coin } => self
+                .l2_book(coin)
+                .map(|data| json!({ "channel": "l2Book", "data": data })),
+            Subscription::UserFills { user } => Some(json!({
+                "channel": "userFills",
+                "data": {
+                    "isSnapshot": true,
+                    "user": user,
+                    "fills": self.user_fills(user),
+                },
+            })),
+            Subscription::ClearinghouseState { user } => {
+                Some(self.clearinghouse_state_message(user))
+            }
+            Subscription::OpenOrders { user } => Some(self.open_orders_message(user)),
+            _ => None,
+        }
+    }
+
+    // ==================== Trading ====================
+
+    /// Place an order, returning its status object as sent in `/exchange` responses.
+    pub(crate) fn place_order(
+        &mut self,
+        user: Address,
+        order: &OrderRequest,
+        events: &mut Events,
+    ) -> Value {
+        match self.try_place_order(user, order, events) {
+            Ok(status) => status,
+            Err(e) => json!({ "error": e }),
+        }
+    }
+
+    fn try_place_order(
+        &mut self,
+        user: Address,
+        order: &OrderRequest,
+        events: &mut Events,
+    ) -> Result<Value, String> {
+        let asset = order.asset;
+        if asset as usize >= self.assets.len() {
+            return Err(format!("Invalid asset: {}", asset));
+        }
+        let tif = match &order.order_type {
+            OrderType::Limit(limit) => limit.tif.as_str(),
+            OrderType::Trigger(_) => {
+                return Err("Trigger orders are not supported by the mock server".into())
+            }
+        };
+        if !matches!(tif, "Gtc" | "Ioc" | "Alo") {
+            return Err(format!("Invalid TIF: {}", tif));
+        }
+
+        let px: f64 = order
+            .limit_px
+            .parse()
+            .map_err(|_| "Order has invalid price.".to_string())?;
+        let mut sz: f64 = order
+            .sz
+            .parse()
+            .map_err(|_| "Order has invalid size.".to_string())?;
+        if px <= 0.0 {
+            return Err("Order has invalid price.".into());
+        }
+        if sz <= EPSILON {
+            return Err("Order has zero size.".into());
+        }
+
+        if order.reduce_only {
+            let szi = self.position(&user, asset).map(|p| p.szi).unwrap_or(0.0);
+            let reduces = (order.is_buy && szi < 0.0) || (!order.is_buy && szi > 0.0);
+            if !reduces {
+                return Err("Reduce only order would increase position.".into());
+            }
+            sz = sz.min(szi.abs());
+        } else if sz * px < MIN_ORDER_VALUE {
+            return Err(format!(
+                "Order must have minimum value of ${}. asset={}",
+                MIN_ORDER_VALUE, asset
+            ));
+        }
+
+        let book = &self.books[asset as usize];
+        let crosses = if order.is_buy {
+            book.best_ask().is_some_and(|ask| px >= ask)
+        } else {
+            book.best_bid().is_some_and(|bid| px <= bid)
+        };
+        if tif == "Alo" && crosses {
+            return Err(format!(
+                "Post only order would have immediately matched, bbo was {}@{}. asset={}",
+                book.best_bid().map(format_float_string).unwrap_or_default(),
+                book.best_ask().map(format_float_string).unwrap_or_default(),
+                asset
+            ));
+        }
+        if tif == "Ioc" && !crosses {
+            return Err(format!(
+                "Order could not immediately match against any resting orders. asset={}",
+                asset
+            ));
+        }
+
+        let oid = self.next_oid;
+        self.next_oid += 1;
+        let timestamp = now_ms();
+        let taker = RestingOrder {
+            oid,
+            user,
+            is_buy: order.is_buy,
+            px,
+            sz,
+            orig_sz: sz,
+            cloid: order.cloid.clone(),
+            timestamp,
+        };
+
+        let matches = self.match_against_book(asset, &taker);
+        let filled: f64 = matches.iter().map(|m| m.sz).sum();
+        let notional: f64 = matches.iter().map(|m| m.sz * m.px).sum();
+        let remaining = sz - filled;
+
+        self.settle(asset, &taker, &matches, events);
+        let mut users: Vec<Address> = matches.iter().map(|m| m.maker.user).collect();
+        users.push(user);
+        users.sort();
+        users.dedup();
+
+        let status = if remaining > EPSILON && tif != "Ioc" {
+            let resting = RestingOrder {
+                sz: remaining,
+                ..taker
+            };
+            events.push(self.order_update(asset, &resting, "open"));
+            self.books[asset as usize].insert(resting);
+            json!({ "resting": { "oid": oid } })
+        } else {
+            let done = RestingOrder { sz: 0.0, ..taker };
+            let final_status = if remaining > EPSILON {
+                "canceled"
+            } else {
+                "filled"
+            };
+            events.push(self.order_update(asset, &done, final_status));
+            json!({
+                "filled": {
+                    "totalSz": format_float_string(filled),
+                    "avgPx": format_float_string(notional / filled),
+                    "oid": oid,
+                }
+            })
+        };
+
+        self.push_book_events(asset, events);
+        for user in users {
+            self.push_account_events(&user, events);
+        }
+        Ok(status)
+    }
+
+    pub(crate) fn cancel(
+        &mut self,
+        user: Address,
+        asset: u32,
+        oid: u64,
+        events: &mut Events,
+    ) -> Value {
+        let owned = self
+            .books
+            .get(asset as usize)
+            .and_then(|b| b.find(|o| o.oid == oid && o.user == user))
+            .is_some();
+        if !owned {
+            return json!({
+                "error": format!(
+                    "Order was never placed, already canceled, or filled. asset={}",
+                    asset
+                )
+            });
+        }
+        if let Some(order) = self.books[asset as usize].remove(oid) {
+            events.push(self.order_update(asset, &order, "canceled"));
+        }
+        self.push_book_events(asset, events);
+        self.push_account_events(&user, events);
+        json!("success")
+    }
+
+    pub(crate) fn cancel_by_cloid(
+        &mut self,
+        user: Address,
+        asset: u32,
+        cloid: &str,
+        events: &mut Events,
+    ) -> Value {
+        let oid = self
+            .books
+            .get(asset as usize)
+            .and_then(|b| b.find(|o| o.user == user && o.cloid.as_deref() == Some(cloid)))
+            .map(|o| o.oid);
+        match oid {
+            Some(oid) => self.cancel(user, asset, oid, events),
+            None => json!({
+                "error": format!(
+                    "Order was never placed, already canceled, or filled. asset={}",
+                    asset
+                )
+            }),
+        }
+    }
+
+    /// Cancel `oid` and place `order` in its place.
+    pub(crate) fn modify(
+        &mut self,
+        user: Address,
+        oid: u64,
+        order: &OrderRequest,
+        events: &mut Events,
+    ) -> Value {
+        let cancelled = self.cancel(user, order.asset, oid, events);
+        if cancelled.get("error").is_some() {
+            return json!({ "error": "Cannot modify canceled or filled order" });
+        }
+        self.place_order(user, order, events)
+    }
+
+    // ==================== Internals ====================
+
+    fn position(&self, user: &Address, asset: u32) -> Option<Position> {
+        self.accounts
+            .get(user)
+            .and_then(|a| a.positions.get(&asset))
+            .copied()
+    }
+
+    fn mark_px(&self, asset: u32) -> Option<f64> {
+        let book = &self.books[asset as usize];
+        match (book.best_bid(), book.best_ask()) {
+            (Some(bid), Some(ask)) => Some((bid + ask) / 2.0),
+            _ => self.last_px[asset as usize],
+        }
+    }
+
+    /// Consume crossing liquidity for `taker`, removing fully filled makers.
+    fn match_against_book(&mut self, asset: u32, taker: &RestingOrder) -> Vec<Match> {
+        let book = &mut self.books[asset as usize];
+        let opposite = if taker.is_buy {
+            &mut book.asks
+        } else {
+            &mut book.bids
+        };
+
+        let mut remaining = taker.sz;
+        let mut matches = Vec::new();
+        while remaining > EPSILON {
+            let Some(maker) = opposite.first_mut() else {
+                break;
+            };
+            let crosses = if taker.is_buy {
+                taker.px >= maker.px
+            } else {
+                taker.px <= maker.px
+            };
+            if !crosses {
+                break;
+            }
+
+            let sz = remaining.min(maker.sz);
+            remaining -= sz;
+            maker.sz -= sz;
+            let maker_done = maker.sz <= EPSILON;
+            let snapshot = maker.clone();
+            if maker_done {
+                opposite.remove(0);
+            }
+            matches.push(Match {
+                px: snapshot.px,
+                maker: snapshot,
+                sz,
+                maker_done,
+            });
+        }
+        matches
+    }
+
+    /// Apply executions to both accounts and queue the resulting events.
+    fn settle(
+        &mut self,
+        asset: u32,
+        taker: &RestingOrder,
+        matches: &[Match],
+        events: &mut Events,
+    ) {
+        let coin = self.assets[asset as usize].name.clone();
+        let mut trades = Vec::new();
+        // A sweep executes at a single time, like on the exchange
+        let time = now_ms();
+
+        for m in matches {
+            let tid = self.next_tid;
+            self.next_tid += 1;
+            let hash = format!("0x{:064x}", tid);
+            self.last_px[asset as usize] = Some(m.px);
+
+            trades.push(json!({
+                "coin": coin,
+                "side": side_str(taker.is_buy),
+                "px": format_float_string(m.px),
+                "sz": format_float_string(m.sz),
+                "time": time,
+                "hash": hash,
+                "tid": tid,
+            }));
+
+            for (order, crossed) in [(&m.maker, false), (taker, true)] {
+                let fill = self.apply_fill(asset, order, m.sz, m.px, crossed, tid, time);
+                events.push((
+                    Subscription::UserFills { user: order.user },
+                    json!({
+                        "channel": "userFills",
+                        "data": { "user": order.user, "fills": [fill] },
+                    }),
+                ));
+            }
+
+            let status = if m.maker_done { "filled" } else { "open" };
+            events.push(self.order_update(asset, &m.maker, status));
+        }
+
+        if !trades.is_empty() {
+            events.push((
+                Subscription::Trades { coin },
+                json!({ "channel": "trades", "data": trades }),
+            ));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn apply_fill(
+        &mut self,
+        asset: u32,
+        order: &RestingOrder,
+        sz: f64,
+        px: f64,
+        crossed: bool,
+        tid: u64,
+        time: u64,
+    ) -> Value {
+        let coin = self.assets[asset as usize].name.clone();
+        let account = self.accounts.entry(order.user).or_default();
+        let start = account.positions.get(&asset).copied().unwrap_or(Position {
+            szi: 0.0,
+            entry_px: 0.0,
+        });
+
+        let delta = if order.is_buy { sz } else { -sz };
+        let new_szi = start.szi + delta;
+        let reducing = start.szi != 0.0 && start.szi.signum() != delta.signum();
+        let closed_sz = if reducing {
+            sz.min(start.szi.abs())
+        } else {
+            0.0
+        };
+        let closed_pnl = closed_sz * (px - start.entry_px) * start.szi.signum();
+        account.balance += closed_pnl;
+
+        let entry_px = if new_szi.abs() <= EPSILON {
+            0.0
+        } else if !reducing {
+            (start.szi.abs() * start.entry_px + sz * px) / new_szi.abs()
+        } else if new_szi.signum() != start.szi.signum() {
+            px
+        } else {
+            start.entry_px
+        };
+
+        if new_szi.abs() <= EPSILON {
+            account.positions.remove(&asset);
+        } else {
+            account.positions.insert(
+                asset,
+                Position {
+                    szi: new_szi,
+                    entry_px,
+                },
+            );
+        }
+
+        let dir = match (start.szi, new_szi) {
+            (s, n) if s > EPSILON && n < -EPSILON => "Long > Short",
+            (s, n) if s < -EPSILON && n > EPSILON => "Short > Long",
+            _ if reducing && start.szi > 0.0 => "Close Long",
+            _ if reducing => "Close Short",
+            _ if order.is_buy => "Open Long",
+            _ => "Open Short",
+        };
+
+        let fill = json!({
+            "coin": coin,
+            "px": format_float_string(px),
+            "sz": format_float_string(sz),
+            "side": side_str(order.is_buy),
+            "time": time,
+            "startPosition": format_float_string(start.szi),
+            "dir": dir,
+            "closedPnl": format_float_string(closed_pnl),
+            "hash": format!("0x{:064x}", tid),
+            "oid": order.oid,
+            "crossed": crossed,
+            "fee": "0",
+            "feeToken": "USDC",
+            "tid": tid,
+            "cloid": order.cloid,
+        });
+        account.fills.insert(0, fill.clone());
+        fill
+    }
+
+    fn order_json(&self, asset: u32, order: &RestingOrder) -> Value {
+        json!({
+            "coin": self.assets[asset as usize].name,
+            "side": side_str(order.is_buy),
+            "limitPx": format_float_string(order.px),
+            "sz": format_float_string(order.sz),
+            "oid": order.oid,
+            "timestamp": order.timestamp,
+            "origSz": format_float_string(order.orig_sz),
+            "cloid": order.cloid,
+        })
+    }
+
+    fn order_update(
+        &self,
+        asset: u32,
+        order: &RestingOrder,
+        status: &str,
+    ) -> (Subscription, Value) {
+        (
+            Subscription::OrderUpdates { user: order.user },
+            json!({
+                "channel": "orderUpdates",
+                "data": [{
+                    "order": self.order_json(asset, order),
+                    "status": status,
+                    "statusTimestamp": now_ms(),
+                }],
+            }),
+        )
+    }
+
+    fn clearinghouse_state_message(&self, user: &Address) -> Value {
+        let mut data = self.clearinghouse_state(user);
+        data["user"] = json!(user);
+        json!({ "channel": "clearinghouseState", "data": data })
+    }
+
+    fn open_orders_message(&self, user: &Address) -> Value {
+        json!({
+            "channel": "openOrders",
+            "data": { "user": user, "orders": self.open_orders(user) },
+        })
+    }
+
+    /// Queue `clearinghouseState` and `openOrders` updates for `user`.
+    fn push_account_events(&self, user: &Address, events: &mut Events) {
+        events.push((
+            Subscription::ClearinghouseState { user: *user },
+            self.clearinghouse_state_message(user),
+        ));
+        events.push((
+            Subscription::OpenOrders { user: *user },
+            self.open_orders_message(user),
+        ));
+    }
+
+    fn all_mids_message(&self) -> Value {
+        json!({ "channel": "allMids", "data": { "mids": self.all_mids() } })
+    }
+
+    fn push_book_events(&self, asset: u32, events: &mut Events) {
+        let coin = self.assets[asset as usize].name.clone();
+        if let Some(data) = self.l2_book(&coin) {
+            events.push((
+                Subscription::L2Book { coin },
+                json!({ "channel": "l2Book", "data": data }),
+            ));
+        }
+        events.push((Subscription::AllMids, self.all_mids_message()));
+    }
+}
+
+/// Collapse resting orders into `{px, sz, n}` levels, preserving book order.
+fn aggregate(orders: &[RestingOrder]) -> Vec<Value> {
+    let mut levels: Vec<(f64, f64, u64)> = Vec::new();
+    for order in orders {
+        match levels.last_mut() {
+            Some(level) if (level.0 - order.px).abs() <= EPSILON => {
+                level.1 += order.sz;
+                level.2 += 1;
+            }
+            _ => levels.push((order.px, order.sz, 1)),
+        }
+    }
+    levels
+        .into_iter()
+        .map(|(px, sz, n)| {
+            json!({
+                "px": format_float_string(px),
+                "sz": format_float_string(sz),
+                "n": n,
+            })
+        })
+        .collect()
+}
+
+fn side_str(is_buy: bool) -> &'static str {
+    if is_buy {
+        "B"
+    } else {
+        "A"
+    }
+}
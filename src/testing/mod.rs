@@ -0,0 +1,645 @@
+//! In-process mock Hyperliquid server for offline integration tests
+//!
+//! Enabled with the `testing` cargo feature. [`MockServer`] binds a local port and
+//! serves the subset of the API the SDK needs to be exercised end to end:
+//!
+//! - `/info`: `meta`, `allMids`, `l2Book`, `clearinghouseState`, `openOrders`,
+//!   `spotClearinghouseState`, `userFills`
+//! - `/exchange`: `order`, `cancel`, `cancelByCloid`, `batchModify`, `updateLeverage`,
+//!   `approveAgent`
+//! - `/ws`: subscriptions to `allMids`, `l2Book`, `trades`, `orderUpdates`, `userFills`,
+//!   `clearinghouseState`, `openOrders`, and `post` requests carrying any of the above
+//!   info or exchange bodies
+//!
+//! Orders are matched by a simple price-time priority engine. Exchange requests are
+//! authenticated like the real API: the L1 action is re-hashed with the same
+//! msgpack encoding the SDK signs, and the account is the address recovered from
+//! the EIP-712 signature (or the vault, when one is given). Signers approved with
+//! `approveAgent` trade for the account that approved them.
+//!
+//! # Example
+//! ```ignore
+//! use hyperliquid_sdk_rs::{testing::MockServer, InfoProvider, providers::RawExchangeProvider};
+//!
+//! let server = MockServer::start().await?;
+//! server.seed_order("BTC", false, 50_000.0, 1.0)?;
+//!
+//! let info = InfoProvider::new(server.network());
+//! let exchange = RawExchangeProvider::new(signer, server.network());
+//! ```
+
+mod engine;
+
+pub use engine::MockAsset;
+
+use std::{
+    collections::HashSet,
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use alloy::primitives::{address, Address, Signature, U256};
+use fastwebsockets::{upgrade, Frame, OpCode, Role, WebSocket};
+use http_body_util::{BodyExt, Full};
+use hyper::{
+    body::{Bytes, Incoming},
+    server::conn::http1,
+    service::service_fn,
+    Method, Request, Response, StatusCode,
+};
+use hyper_util::rt::TokioIo;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::{
+    net::TcpListener,
+    sync::{broadcast, mpsc, watch},
+    task::JoinHandle,
+};
+
+use self::engine::{Engine, Events};
+use crate::{
+    errors::HyperliquidError,
+    providers::websocket::split_socket,
+    signers::verify,
+    types::{
+        actions::{
+            ApproveAgent, BulkCancel, BulkCancelCloid, BulkModify, BulkOrder,
+            UpdateLeverage,
+        },
+        requests::{Limit, OrderRequest, OrderType},
+        ws::{PostRequest, Subscription},
+    },
+    Network,
+};
+
+/// Configuration for [`MockServer`]
+#[derive(Clone, Debug)]
+pub struct MockServerConfig {
+    /// Network whose signing parameters (agent source, chain) the server expects
+    pub base_network: Network,
+    /// Perpetuals listed by `meta`, indexed by position
+    pub assets: Vec<MockAsset>,
+    /// Reject exchange requests from accounts that were never funded
+    pub require_known_users: bool,
+}
+
+impl Default for MockServerConfig {
+    fn default() -> Self {
+        Self {
+            base_network: Network::Testnet,
+            assets: vec![
+                MockAsset::new("BTC", 5, 50),
+                MockAsset::new("ETH", 4, 50),
+                MockAsset::new("SOL", 2, 20),
+            ],
+            require_known_users: false,
+        }
+    }
+}
+
+/// A broadcast WebSocket message, keyed by the serialized subscription it belongs to.
+#[derive(Clone)]
+struct Published {
+    key: String,
+    text: String,
+}
+
+struct Shared {
+    config: MockServerConfig,
+    engine: Mutex<Engine>,
+    used_nonces: Mutex<HashSet<(Address, u64)>>,
+    events: broadcast::Sender<Published>,
+    /// Bumped to close every open WebSocket connection
+    kicks: watch::Sender<u64>,
+}
+
+/// Local HTTP + WebSocket server emulating the Hyperliquid API.
+///
+/// The server runs on the current tokio runtime and stops accepting connections
+/// when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    task: JoinHandle<()>,
+}
+
+impl MockServer {
+    /// Address that owns liquidity added with [`seed_order`](Self::seed_order).
+    pub const MAKER: Address = address!("00000000000000000000000000000000000000aa");
+
+    /// Start a server with the default configuration on an ephemeral port.
+    pub async fn start() -> Result<Self, HyperliquidError> {
+        Self::start_with_config(MockServerConfig::default()).await
+    }
+
+    /// Start a server with a custom configuration on an ephemeral port.
+    pub async fn start_with_config(
+        config: MockServerConfig,
+    ) -> Result<Self, HyperliquidError> {
+        let listener = TcpListener::bind(("127.0.0.1", 0))
+            .await
+            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| HyperliquidError::Network(e.to_string()))?;
+
+        let (events, _) = broadcast::channel(1024);
+        let shared = Arc::new(Shared {
+            engine: Mutex::new(Engine::new(config.assets.clone())),
+            used_nonces: Mutex::new(HashSet::new()),
+            config,
+            events,
+            kicks: watch::channel(0).0,
+        });
+
+        let accept_shared = shared.clone();
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let shared = accept_shared.clone();
+                tokio::spawn(async move {
+                    let service = service_fn(move |req| handle(req, shared.clone()));
+                    let _ = http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .with_upgrades()
+                        .await;
+                });
+            }
+        });
+
+        Ok(Self { addr, shared, task })
+    }
+
+    /// Socket address the server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Network pointing every provider at this server.
+    pub fn network(&self) -> Network {
+        Network::custom(
+            format!("http://{}", self.addr),
+            format!("ws://{}/ws", self.addr),
+            &self.shared.config.base_network,
+        )
+    }
+
+    /// Close every open WebSocket connection, as if the server had dropped them.
+    pub fn disconnect_websockets(&self) {
+        self.shared.kicks.send_modify(|kicks| *kicks += 1);
+    }
+
+    /// Credit `usdc` to `user`, creating the account if needed.
+    pub fn fund(&self, user: Address, usdc: f64) {
+        self.shared.engine().fund(user, usdc);
+    }
+
+    /// Rest a GTC order owned by [`MAKER`](Self::MAKER) and return its oid.
+    ///
+    /// Fails if the coin is unknown or the order does not rest in full.
+    pub fn seed_order(
+        &self,
+        coin: &str,
+        is_buy: bool,
+        px: f64,
+        sz: f64,
+    ) -> Result<u64, HyperliquidError> {
+        let mut events = Events::new();
+        let status = {
+            let mut engine = self.shared.engine();
+            let asset = engine
+                .asset_index(coin)
+                .ok_or_else(|| HyperliquidError::AssetNotFound(coin.to_string()))?;
+            let order = OrderRequest {
+                asset,
+                is_buy,
+                limit_px: px.to_string(),
+                sz: sz.to_string(),
+                reduce_only: false,
+                order_type: OrderType::Limit(Limit {
+                    tif: "Gtc".to_string(),
+                }),
+                cloid: None,
+            };
+            engine.place_order(Self::MAKER, &order, &mut events)
+        };
+        self.shared.publish(events);
+
+        status["resting"]["oid"].as_u64().ok_or_else(|| {
+            HyperliquidError::InvalidRequest(format!(
+                "seed order did not rest: {}",
+                status
+            ))
+        })
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+// ==================== HTTP ====================
+
+type HttpResponse = Response<Full<Bytes>>;
+
+async fn handle(
+    mut req: Request<Incoming>,
+    shared: Arc<Shared>,
+) -> Result<HttpResponse, Infallible> {
+    let path = req.uri().path().to_string();
+
+    if path == "/ws" && upgrade::is_upgrade_request(&req) {
+        return Ok(match upgrade::upgrade(&mut req) {
+            Ok((response, fut)) => {
+                tokio::spawn(async move {
+                    if let Ok(ws) = fut.await {
+                        serve_ws(ws, shared).await;
+                    }
+                });
+                response.map(|_| Full::new(Bytes::new()))
+            }
+            Err(e) => text_response(StatusCode::BAD_REQUEST, e.to_string()),
+        });
+    }
+
+    if req.method() != Method::POST {
+        return Ok(text_response(
+            StatusCode::NOT_FOUND,
+            "not found".to_string(),
+        ));
+    }
+
+    let body = match req.into_body().collect().await {
+        Ok(collected) => collected.to_bytes(),
+        Err(e) => return Ok(text_response(StatusCode::BAD_REQUEST, e.to_string())),
+    };
+    let body: Value = match serde_json::from_slice(&body) {
+        Ok(body) => body,
+        Err(e) => {
+            return Ok(text_response(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("Failed to deserialize the JSON body: {}", e),
+            ))
+        }
+    };
+
+    let result = match path.as_str() {
+        "/info" => shared.info(&body),
+        "/exchange" => Ok(shared.exchange(&body)),
+        _ => Err((StatusCode::NOT_FOUND, "not found".to_string())),
+    };
+
+    Ok(match result {
+        Ok(value) => Response::builder()
+            .status(StatusCode::OK)
+            .header("Content-Type", "application/json")
+            .body(Full::new(Bytes::from(value.to_string())))
+            .expect("valid response"),
+        Err((status, message)) => text_response(status, message),
+    })
+}
+
+fn text_response(status: StatusCode, message: String) -> HttpResponse {
+    Response::builder()
+        .status(status)
+        .body(Full::new(Bytes::from(message)))
+        .expect("valid response")
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExchangePayload {
+    action: Value,
+    nonce: u64,
+    signature: SignaturePayload,
+    vault_address: Option<Address>,
+}
+
+#[derive(Deserialize)]
+struct SignaturePayload {
+    r: U256,
+    s: U256,
+    v: u64,
+}
+
+impl Shared {
+    fn engine(&self) -> std::sync::MutexGuard<'_, Engine> {
+        self.engine.lock().expect("mock engine mutex poisoned")
+    }
+
+    fn publish(&self, events: Events) {
+        for (subscription, message) in events {
+            let key = serde_json::to_string(&subscription).unwrap_or_default();
+            let _ = self.events.send(Published {
+                key,
+                text: message.to_string(),
+            });
+        }
+    }
+
+    fn info(&self, body: &Value) -> Result<Value, (StatusCode, String)> {
+        let request_type = body.get("type").and_then(Value::as_str).unwrap_or_default();
+        let user = || {
+            serde_json::from_value::<Address>(body["user"].clone()).map_err(|e| {
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    format!("invalid user: {}", e),
+                )
+            })
+        };
+
+        let engine = self.engine();
+        match request_type {
+            "meta" => Ok(engine.meta()),
+            "spotMeta" => Ok(json!({ "universe": [], "tokens": [] })),
+            "perpDexs" => Ok(json!([null])),
+            "allMids" => Ok(engine.all_mids()),
+            "l2Book" => Ok(engine
+                .l2_book(body["coin"].as_str().unwrap_or_default())
+                .unwrap_or(Value::Null)),
+            "clearinghouseState" => Ok(engine.clearinghouse_state(&user()?)),
+            "openOrders" => Ok(engine.open_orders(&user()?)),
+            "spotClearinghouseState" => Ok(engine.spot_clearinghouse_state(&user()?)),
+            "userFills" => Ok(engine.user_fills(&user()?)),
+            other => Err((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("unsupported info request type: {}", other),
+            )),
+        }
+    }
+
+    fn exchange(&self, body: &Value) -> Value {
+        match self.try_exchange(body) {
+            Ok(response) => json!({ "status": "ok", "response": response }),
+            Err(e) => json!({ "status": "err", "response": e }),
+        }
+    }
+
+    fn try_exchange(&self, body: &Value) -> Result<Value, String> {
+        let payload: ExchangePayload = serde_json::from_value(body.clone())
+            .map_err(|e| format!("Failed to deserialize exchange request: {}", e))?;
+
+        let action = &payload.action;
+        let action_type = action["type"].as_str().unwrap_or_default().to_string();
+
+        let signer = self.recover_signer(action, &payload)?;
+        if !self
+            .used_nonces
+            .lock()
+            .expect("mock nonce mutex poisoned")
+            .insert((signer, payload.nonce))
+        {
+            return Err(format!("Invalid nonce: {} was already used", payload.nonce));
+        }
+
+        let mut events = Events::new();
+        let response = {
+            let mut engine = self.engine();
+            // Approved agents trade for their master account; anyone else for themselves
+            let user = payload
+                .vault_address
+                .unwrap_or_else(|| engine.account_for(signer));
+            if self.config.require_known_users && !engine.has_account(&user) {
+                return Err(format!("User or API Wallet {:#x} does not exist.", user));
+            }
+            dispatch(&mut engine, user, &action_type, action, &mut events)?
+        };
+        self.publish(events);
+        Ok(response)
+    }
+
+    /// Re-hash the action exactly as the SDK does and recover who signed it.
+    fn recover_signer(
+        &self,
+        action: &Value,
+        payload: &ExchangePayload,
+    ) -> Result<Address, String> {
+        let signing_hash = verify::action_signing_hash(
+            action,
+            payload.nonce,
+            payload.vault_address,
+            &self.config.base_network,
+        )
+        .map_err(|e| e.to_string())?;
+
+        let sig = &payload.signature;
+        Signature::from_rs_and_parity(sig.r, sig.s, sig.v)
+            .and_then(|s| s.recover_address_from_prehash(&signing_hash))
+            .map_err(|e| format!("Invalid signature: {}", e))
+    }
+}
+
+fn parse<T: serde::de::DeserializeOwned>(action: &Value) -> Result<T, String> {
+    serde_json::from_value(action.clone())
+        .map_err(|e| format!("Failed to deserialize action: {}", e))
+}
+
+fn statuses(statuses: Vec<Value>, response_type: &str) -> Value {
+    json!({ "type": response_type, "data": { "statuses": statuses } })
+}
+
+fn dispatch(
+    engine: &mut Engine,
+    user: Address,
+    action_type: &str,
+    action: &Value,
+    events: &mut Events,
+) -> Result<Value, String> {
+    match action_type {
+        "order" => {
+            let bulk: BulkOrder = parse(action)?;
+            let results = bulk
+                .orders
+                .iter()
+                .map(|order| engine.place_order(user, order, events))
+                .collect();
+            Ok(statuses(results, "order"))
+        }
+        "cancel" => {
+            let bulk: BulkCancel = parse(action)?;
+            let results = bulk
+                .cancels
+                .iter()
+                .map(|c| engine.cancel(user, c.asset, c.oid, events))
+                .collect();
+            Ok(statuses(results, "cancel"))
+        }
+        "cancelByCloid" => {
+            let bulk: BulkCancelCloid = parse(action)?;
+            let results = bulk
+                .cancels
+                .iter()
+                .map(|c| engine.cancel_by_cloid(user, c.asset, &c.cloid, events))
+                .collect();
+            Ok(statuses(results, "cancel"))
+        }
+        "batchModify" => {
+            let bulk: BulkModify = parse(action)?;
+            let results = bulk
+                .modifies
+                .iter()
+                .map(|m| engine.modify(user, m.oid, &m.order, events))
+                .collect();
+            Ok(statuses(results, "order"))
+        }
+        "updateLeverage" => {
+            let update: UpdateLeverage = parse(action)?;
+            engine.update_leverage(
+                user,
+                update.asset,
+                update.is_cross,
+                update.leverage,
+            )?;
+            Ok(json!({ "type": "default" }))
+        }
+        "approveAgent" => {
+            let approval: ApproveAgent = parse(action)?;
+            engine.approve_agent(approval.agent_address, user);
+            Ok(json!({ "type": "default" }))
+        }
+        other => Err(format!("Unsupported action type: {}", other)),
+    }
+}
+
+// ==================== WebSocket ====================
+
+#[derive(Deserialize)]
+struct WsClientRequest {
+    method: String,
+    subscription: Option<Subscription>,
+    id: Option<u64>,
+    request: Option<PostRequest>,
+}
+
+/// Serve one WebSocket connection until the client goes away.
+///
+/// Reads and writes run on separate halves so broadcast messages are never
+/// delayed behind a pending read.
+async fn serve_ws(ws: WebSocket<TokioIo<hyper::upgrade::Upgraded>>, shared: Arc<Shared>) {
+    let (mut reader, mut writer) = split_socket(ws, Role::Server);
+
+    let subscriptions = Arc::new(Mutex::new(HashSet::<String>::new()));
+    let (out_tx, mut out_rx) = mpsc::unbounded_channel::<String>();
+    let mut events = shared.events.subscribe();
+    let mut kicks = shared.kicks.subscribe();
+
+    let writer_subscriptions = subscriptions.clone();
+    let writer_task = tokio::spawn(async move {
+        loop {
+            let text = tokio::select! {
+                text = out_rx.recv() => match text {
+                    Some(text) => text,
+                    None => break,
+                },
+                event = events.recv() => match event {
+                    Ok(event) => {
+                        let subscribed = writer_subscriptions
+                            .lock()
+                            .expect("mock subscriptions mutex poisoned")
+                            .contains(&event.key);
+                        if !subscribed {
+                            continue;
+                        }
+                        event.text
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            };
+            if writer
+                .write_frame(Frame::text(text.into_bytes().into()))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    loop {
+        let frame = tokio::select! {
+            frame = reader.read_frame() => match frame {
+                Ok(frame) => frame,
+                Err(_) => break,
+            },
+            _ = kicks.changed() => break,
+        };
+        match frame.opcode {
+            OpCode::Text => {
+                let Ok(request) =
+                    serde_json::from_slice::<WsClientRequest>(&frame.payload)
+                else {
+                    continue;
+                };
+                for reply in shared.ws_request(request, &subscriptions) {
+                    let _ = out_tx.send(reply.to_string());
+                }
+            }
+            OpCode::Close => break,
+            _ => {}
+        }
+    }
+
+    writer_task.abort();
+}
+
+impl Shared {
+    fn ws_request(
+        &self,
+        request: WsClientRequest,
+        subscriptions: &Mutex<HashSet<String>>,
+    ) -> Vec<Value> {
+        let (method, subscription) = match (request.method.as_str(), request.subscription)
+        {
+            ("ping", _) => return vec![json!({ "channel": "pong" })],
+            ("post", _) => {
+                let (Some(id), Some(post)) = (request.id, request.request) else {
+                    return Vec::new();
+                };
+                return vec![json!({
+                    "channel": "post",
+                    "data": { "id": id, "response": self.ws_post(post) },
+                })];
+            }
+            (method @ ("subscribe" | "unsubscribe"), Some(subscription)) => {
+                (method.to_string(), subscription)
+            }
+            _ => return Vec::new(),
+        };
+
+        let key = serde_json::to_string(&subscription).unwrap_or_default();
+        let mut replies = vec![json!({
+            "channel": "subscriptionResponse",
+            "data": { "method": method, "subscription": subscription },
+        })];
+
+        let mut subscriptions = subscriptions
+            .lock()
+            .expect("mock subscriptions mutex poisoned");
+        if method == "subscribe" {
+            subscriptions.insert(key);
+            replies.extend(self.engine().snapshot(&subscription));
+        } else {
+            subscriptions.remove(&key);
+        }
+        replies
+    }
+
+    /// Answer a post request the way the HTTP endpoints would.
+    fn ws_post(&self, request: PostRequest) -> Value {
+        match request {
+            PostRequest::Info(body) => match self.info(&body) {
+                Ok(data) => json!({
+                    "type": "info",
+                    "payload": { "type": body["type"], "data": data },
+                }),
+                Err((_, message)) => json!({ "type": "error", "payload": message }),
+            },
+            PostRequest::Action(body) => {
+                json!({ "type": "action", "payload": self.exchange(&body) })
+            }
+        }
+    }
+}
@@ -3,16 +3,19 @@ use serde;
 
 use crate::l1_action;
 use crate::types::requests::{
-    BuilderInfo, CancelRequest, CancelRequestCloid, ModifyRequest, OrderRequest,
+    BuilderInfo, CancelRequest, CancelRequestCloid, Grouping, ModifyRequest, OrderRequest,
 };
 
 // User Actions (with HyperliquidTransaction: prefix)
 
 // UsdSend needs custom serialization for signature_chain_id
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UsdSend {
-    #[serde(serialize_with = "serialize_chain_id")]
+    #[serde(
+        serialize_with = "serialize_chain_id",
+        deserialize_with = "deserialize_chain_id"
+    )]
     pub signature_chain_id: u64,
     pub hyperliquid_chain: String,
     pub destination: String,
@@ -42,10 +45,13 @@ impl crate::types::eip712::HyperliquidAction for UsdSend {
 }
 
 // Withdraw needs custom serialization for signature_chain_id
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Withdraw {
-    #[serde(serialize_with = "serialize_chain_id")]
+    #[serde(
+        serialize_with = "serialize_chain_id",
+        deserialize_with = "deserialize_chain_id"
+    )]
     pub signature_chain_id: u64,
     pub hyperliquid_chain: String,
     pub destination: String,
@@ -75,10 +81,13 @@ impl crate::types::eip712::HyperliquidAction for Withdraw {
 }
 
 // SpotSend needs custom serialization for signature_chain_id
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotSend {
-    #[serde(serialize_with = "serialize_chain_id")]
+    #[serde(
+        serialize_with = "serialize_chain_id",
+        deserialize_with = "deserialize_chain_id"
+    )]
     pub signature_chain_id: u64,
     pub hyperliquid_chain: String,
     pub destination: String,
@@ -109,10 +118,13 @@ impl crate::types::eip712::HyperliquidAction for SpotSend {
 }
 
 // ApproveAgent needs custom serialization for the address field
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApproveAgent {
-    #[serde(serialize_with = "serialize_chain_id")]
+    #[serde(
+        serialize_with = "serialize_chain_id",
+        deserialize_with = "deserialize_chain_id"
+    )]
     pub signature_chain_id: u64,
     pub hyperliquid_chain: String,
     #[serde(serialize_with = "serialize_address")]
@@ -142,6 +154,25 @@ where
     serializer.serialize_str(&format!("{:#x}", chain_id))
 }
 
+pub(crate) fn deserialize_chain_id<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    // Accept the hex string the SDK sends as well as a plain number
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum ChainId {
+        Hex(String),
+        Number(u64),
+    }
+
+    match <ChainId as serde::Deserialize>::deserialize(deserializer)? {
+        ChainId::Number(chain_id) => Ok(chain_id),
+        ChainId::Hex(hex) => u64::from_str_radix(hex.trim_start_matches("0x"), 16)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
 impl crate::types::eip712::HyperliquidAction for ApproveAgent {
     const TYPE_STRING: &'static str = "ApproveAgent(string hyperliquidChain,address agentAddress,string agentName,uint64 nonce)";
     const USE_PREFIX: bool = true;
@@ -165,10 +196,13 @@ impl crate::types::eip712::HyperliquidAction for ApproveAgent {
 }
 
 // ApproveBuilderFee needs custom serialization for signature_chain_id
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApproveBuilderFee {
-    #[serde(serialize_with = "serialize_chain_id")]
+    #[serde(
+        serialize_with = "serialize_chain_id",
+        deserialize_with = "deserialize_chain_id"
+    )]
     pub signature_chain_id: u64,
     pub hyperliquid_chain: String,
     pub max_fee_rate: String,
@@ -210,7 +244,7 @@ l1_action! {
 
 // Exchange Actions (these don't need EIP-712 signing but are included for completeness)
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateLeverage {
     pub asset: u32,
@@ -218,7 +252,7 @@ pub struct UpdateLeverage {
     pub leverage: u32,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateIsolatedMargin {
     pub asset: u32,
@@ -226,7 +260,7 @@ pub struct UpdateIsolatedMargin {
     pub ntli: i64,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VaultTransfer {
     pub vault_address: String,
@@ -234,20 +268,20 @@ pub struct VaultTransfer {
     pub usd: u64,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotUser {
     pub class_transfer: ClassTransfer,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClassTransfer {
     pub usd_size: u64,
     pub to_perp: bool,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetReferrer {
     pub code: String,
@@ -255,28 +289,28 @@ pub struct SetReferrer {
 
 // Bulk actions that contain other types
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BulkOrder {
     pub orders: Vec<OrderRequest>,
-    pub grouping: String,
+    pub grouping: Grouping,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub builder: Option<BuilderInfo>,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BulkCancel {
     pub cancels: Vec<CancelRequest>,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BulkModify {
     pub modifies: Vec<ModifyRequest>,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BulkCancelCloid {
     pub cancels: Vec<CancelRequestCloid>,
@@ -285,21 +319,23 @@ pub struct BulkCancelCloid {
 // ==================== Phase 1 New Actions ====================
 
 /// Schedule automatic order cancellation
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ScheduleCancel {
+    /// Omitted to remove the scheduled cancel
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub time: Option<u64>,
 }
 
 /// Create a sub-account
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateSubAccount {
     pub name: Option<String>,
 }
 
 /// Transfer USD to/from a sub-account
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubAccountTransfer {
     pub sub_account_user: String,
@@ -308,7 +344,7 @@ pub struct SubAccountTransfer {
 }
 
 /// Transfer spot tokens to/from a sub-account
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubAccountSpotTransfer {
     pub sub_account_user: String,
@@ -318,7 +354,7 @@ pub struct SubAccountSpotTransfer {
 }
 
 /// Transfer USD between perp and spot classes (different from spotUser classTransfer)
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UsdClassTransfer {
     pub amount: String,
@@ -328,7 +364,7 @@ pub struct UsdClassTransfer {
 // ==================== Phase 2 New Actions ====================
 
 /// TWAP order request
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TwapOrder {
     /// Asset index
@@ -352,14 +388,14 @@ pub struct TwapOrder {
 }
 
 /// Bulk TWAP order wrapper
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BulkTwapOrder {
     pub twap: TwapOrder,
 }
 
 /// Cancel TWAP order
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TwapCancel {
     /// Asset index
@@ -371,10 +407,13 @@ pub struct TwapCancel {
 }
 
 /// Convert account to multi-sig user
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConvertToMultiSigUser {
-    #[serde(serialize_with = "serialize_chain_id")]
+    #[serde(
+        serialize_with = "serialize_chain_id",
+        deserialize_with = "deserialize_chain_id"
+    )]
     pub signature_chain_id: u64,
     pub hyperliquid_chain: String,
     /// Sorted list of authorized user addresses
@@ -385,7 +424,7 @@ pub struct ConvertToMultiSigUser {
 }
 
 /// Multi-sig signer information
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MultiSigSigner {
     pub address: String,
@@ -427,24 +466,49 @@ impl crate::types::eip712::HyperliquidAction for ConvertToMultiSigUser {
 }
 
 /// Execute a multi-sig transaction
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MultiSig {
-    #[serde(serialize_with = "serialize_chain_id")]
+    #[serde(
+        serialize_with = "serialize_chain_id",
+        deserialize_with = "deserialize_chain_id"
+    )]
     pub signature_chain_id: u64,
-    /// The multi-sig user address
+    /// Signatures from authorized users over the payload
+    pub signatures: Vec<MultiSigSignature>,
+    pub payload: MultiSigPayload,
+}
+
+/// The action a multi-sig user executes, and who submits it
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiSigPayload {
+    /// The multi-sig user address (lowercase hex)
     pub multi_sig_user: String,
-    /// The outer signer (one of the authorized users)
+    /// The outer signer, one of the authorized users (lowercase hex)
     pub outer_signer: String,
-    /// The inner action to execute
-    pub inner_action: serde_json::Value,
-    /// Signatures from other authorized users
-    pub signatures: Vec<MultiSigSignature>,
-    pub nonce: u64,
+    /// The inner action with its `type` tag, hashed in the field order of its
+    /// action struct
+    #[serde(serialize_with = "serialize_tagged_action")]
+    pub action: serde_json::Value,
+}
+
+fn serialize_tagged_action<S>(
+    action: &serde_json::Value,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::Serialize;
+
+    let action: TaggedAction =
+        serde_json::from_value(action.clone()).map_err(serde::ser::Error::custom)?;
+    action.serialize(serializer)
 }
 
 /// Signature for multi-sig transaction
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MultiSigSignature {
     pub r: String,
@@ -452,8 +516,43 @@ pub struct MultiSigSignature {
     pub v: u8,
 }
 
+/// EIP-712 envelope the outer signer signs for a [`MultiSig`] action
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendMultiSig {
+    #[serde(
+        serialize_with = "serialize_chain_id",
+        deserialize_with = "deserialize_chain_id"
+    )]
+    pub signature_chain_id: u64,
+    pub hyperliquid_chain: String,
+    /// Msgpack hash of the `MultiSig` action, nonce and vault address
+    pub multi_sig_action_hash: B256,
+    pub nonce: u64,
+}
+
+impl crate::types::eip712::HyperliquidAction for SendMultiSig {
+    const TYPE_STRING: &'static str =
+        "SendMultiSig(string hyperliquidChain,bytes32 multiSigActionHash,uint64 nonce)";
+    const USE_PREFIX: bool = true;
+
+    fn chain_id(&self) -> Option<u64> {
+        Some(self.signature_chain_id)
+    }
+
+    fn encode_data(&self) -> Vec<u8> {
+        use crate::types::eip712::encode_value;
+        let mut encoded = Vec::new();
+        encoded.extend_from_slice(&Self::type_hash()[..]);
+        encoded.extend_from_slice(&encode_value(&self.hyperliquid_chain)[..]);
+        encoded.extend_from_slice(&encode_value(&self.multi_sig_action_hash)[..]);
+        encoded.extend_from_slice(&encode_value(&self.nonce)[..]);
+        encoded
+    }
+}
+
 /// Enable DEX abstraction for an agent
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AgentEnableDexAbstraction {
     // This action has no additional fields - just the type
@@ -464,7 +563,7 @@ pub struct AgentEnableDexAbstraction {
 // --- Spot Deployment Actions ---
 
 /// Register a new spot token
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployRegisterToken {
     /// Token name/symbol
@@ -481,7 +580,7 @@ pub struct SpotDeployRegisterToken {
 }
 
 /// User genesis for spot deployment
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployUserGenesis {
     /// Token identifier
@@ -494,7 +593,7 @@ pub struct SpotDeployUserGenesis {
 }
 
 /// Freeze or unfreeze a user in spot deployment
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployFreezeUser {
     /// Token identifier
@@ -506,7 +605,7 @@ pub struct SpotDeployFreezeUser {
 }
 
 /// Enable freeze privilege for a token
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployEnableFreezePrivilege {
     /// Token identifier
@@ -514,7 +613,7 @@ pub struct SpotDeployEnableFreezePrivilege {
 }
 
 /// Revoke freeze privilege for a token
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployRevokeFreezePrivilege {
     /// Token identifier
@@ -522,7 +621,7 @@ pub struct SpotDeployRevokeFreezePrivilege {
 }
 
 /// Enable quote token for spot deployment
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployEnableQuoteToken {
     /// Token identifier to enable as quote
@@ -530,7 +629,7 @@ pub struct SpotDeployEnableQuoteToken {
 }
 
 /// Genesis for spot deployment
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployGenesis {
     /// Token identifier
@@ -543,7 +642,7 @@ pub struct SpotDeployGenesis {
 }
 
 /// Register a spot trading pair
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployRegisterSpot {
     /// Base token identifier
@@ -553,7 +652,7 @@ pub struct SpotDeployRegisterSpot {
 }
 
 /// Register hyperliquidity for a spot pair
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeployRegisterHyperliquidity {
     /// Spot pair identifier
@@ -569,7 +668,7 @@ pub struct SpotDeployRegisterHyperliquidity {
 }
 
 /// Set deployer trading fee share for a token
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotDeploySetDeployerTradingFeeShare {
     /// Token identifier
@@ -581,7 +680,7 @@ pub struct SpotDeploySetDeployerTradingFeeShare {
 // --- Perp Deployment Actions ---
 
 /// Register a perpetual asset
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PerpDeployRegisterAsset {
     /// DEX identifier
@@ -606,7 +705,7 @@ pub struct PerpDeployRegisterAsset {
 }
 
 /// Set oracle for perpetual asset
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PerpDeploySetOracle {
     /// DEX identifier
@@ -623,21 +722,21 @@ pub struct PerpDeploySetOracle {
 // --- Validator/Staking Actions ---
 
 /// Unjail self (signer)
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CSignerUnjailSelf {
     // No additional fields - just the action type
 }
 
 /// Jail self (signer)
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CSignerJailSelf {
     // No additional fields - just the action type
 }
 
 /// Register as a validator
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CValidatorRegister {
     /// Node IP address
@@ -659,7 +758,7 @@ pub struct CValidatorRegister {
 }
 
 /// Change validator profile
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CValidatorChangeProfile {
     /// Node IP address
@@ -686,14 +785,14 @@ pub struct CValidatorChangeProfile {
 }
 
 /// Unregister as a validator
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CValidatorUnregister {
     // No additional fields - just the action type
 }
 
 /// Delegate tokens to a validator
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenDelegate {
     /// Validator address to delegate to
@@ -707,7 +806,7 @@ pub struct TokenDelegate {
 // --- Other Actions ---
 
 /// Enable or disable large block mode
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UseBigBlocks {
     /// Whether to enable (true) or disable (false) big blocks
@@ -715,13 +814,67 @@ pub struct UseBigBlocks {
 }
 
 /// No-operation action (useful for testing or keeping connection alive)
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Noop {
     /// Nonce for the action
     pub nonce: u64,
 }
 
+/// An action with its `type` tag, typed by that tag.
+///
+/// JSON objects do not keep field order, while signatures commit to the
+/// msgpack encoding of the action struct. Deserializing a JSON action into
+/// this and serializing it again restores the `type`-first struct order it was
+/// signed with.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub(crate) enum TaggedAction {
+    Order(BulkOrder),
+    Cancel(BulkCancel),
+    CancelByCloid(BulkCancelCloid),
+    BatchModify(BulkModify),
+    UpdateLeverage(UpdateLeverage),
+    UpdateIsolatedMargin(UpdateIsolatedMargin),
+    UsdSend(UsdSend),
+    SpotSend(SpotSend),
+    SpotUser(SpotUser),
+    VaultTransfer(VaultTransfer),
+    SetReferrer(SetReferrer),
+    ApproveAgent(ApproveAgent),
+    ApproveBuilderFee(ApproveBuilderFee),
+    Withdraw3(Withdraw),
+    ScheduleCancel(ScheduleCancel),
+    CreateSubAccount(CreateSubAccount),
+    SubAccountTransfer(SubAccountTransfer),
+    SubAccountSpotTransfer(SubAccountSpotTransfer),
+    UsdClassTransfer(UsdClassTransfer),
+    TwapOrder(BulkTwapOrder),
+    TwapCancel(TwapCancel),
+    MultiSig(MultiSig),
+    AgentEnableDexAbstraction(AgentEnableDexAbstraction),
+    SpotDeployRegisterToken(SpotDeployRegisterToken),
+    SpotDeployUserGenesis(SpotDeployUserGenesis),
+    SpotDeployFreezeUser(SpotDeployFreezeUser),
+    SpotDeployEnableFreezePrivilege(SpotDeployEnableFreezePrivilege),
+    SpotDeployRevokeFreezePrivilege(SpotDeployRevokeFreezePrivilege),
+    SpotDeployEnableQuoteToken(SpotDeployEnableQuoteToken),
+    SpotDeployGenesis(SpotDeployGenesis),
+    SpotDeployRegisterSpot(SpotDeployRegisterSpot),
+    SpotDeployRegisterHyperliquidity(SpotDeployRegisterHyperliquidity),
+    SpotDeploySetDeployerTradingFeeShare(SpotDeploySetDeployerTradingFeeShare),
+    PerpDeployRegisterAsset(PerpDeployRegisterAsset),
+    PerpDeploySetOracle(PerpDeploySetOracle),
+    CSignerUnjailSelf(CSignerUnjailSelf),
+    CSignerJailSelf(CSignerJailSelf),
+    CValidatorRegister(CValidatorRegister),
+    CValidatorChangeProfile(CValidatorChangeProfile),
+    CValidatorUnregister(CValidatorUnregister),
+    TokenDelegate(TokenDelegate),
+    UseBigBlocks(UseBigBlocks),
+    Noop(Noop),
+}
+
 // Types are now imported from requests.rs
 
 // The macros don't handle signature_chain_id, so we need to remove the duplicate trait impls
@@ -0,0 +1,370 @@
+//! Numeric values in API types
+//!
+//! Hyperliquid sends prices, sizes and balances as decimal strings. Fields holding
+//! them are typed as [`Num`], which keeps the string exactly as received and
+//! serializes back to it, so nothing is lost to `f64` on the way through. `Num`
+//! is the same type with or without the `decimal` feature; the feature adds
+//! [`Decimal`], an exact decimal with arithmetic, and [`Num::to_decimal`].
+//!
+//! Request types keep `String` fields because the exact string is part of the
+//! signed payload. Both `Num` and `Decimal` convert into that string, so they can
+//! be passed anywhere a price or size string is accepted:
+//!
+//! ```ignore
+//! use hyperliquid_sdk_rs::types::{Decimal, OrderRequest};
+//!
+//! let px: Decimal = "50000.10".parse()?;
+//! let order = OrderRequest::limit(0, true, px, "0.01", "Gtc"); // limit_px == "50000.1"
+//! ```
+
+use std::{convert::Infallible, fmt, ops::Deref, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A decimal number as received from the API.
+///
+/// Holds the wire string unchanged; dereference it for `str` methods such as
+/// `parse`. Equality compares the strings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Num(String);
+
+impl Num {
+    /// The decimal string as received
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Nearest `f64`, or `NaN` if the string is not a number
+    pub fn to_f64(&self) -> f64 {
+        self.0.parse().unwrap_or(f64::NAN)
+    }
+
+    /// Parse into an `f64`, naming `field` in the
+    /// [`InvalidResponse`](crate::errors::HyperliquidError::InvalidResponse) error
+    /// if the string is not a number
+    pub(crate) fn parse_f64(
+        &self,
+        field: &str,
+    ) -> Result<f64, crate::errors::HyperliquidError> {
+        self.0.parse().map_err(|_| {
+            crate::errors::HyperliquidError::InvalidResponse(format!(
+                "invalid {}: {}",
+                field, self.0
+            ))
+        })
+    }
+
+    /// Parse into an exact [`Decimal`]
+    #[cfg(feature = "decimal")]
+    pub fn to_decimal(&self) -> Result<Decimal, crate::errors::HyperliquidError> {
+        self.0.parse()
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for Num {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Num {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Num {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Num {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl From<String> for Num {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Num {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<Num> for String {
+    fn from(value: Num) -> Self {
+        value.0
+    }
+}
+
+impl From<&Num> for String {
+    fn from(value: &Num) -> Self {
+        value.0.clone()
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl From<Decimal> for Num {
+    fn from(value: Decimal) -> Self {
+        Self(value.to_wire_string())
+    }
+}
+
+impl PartialEq<str> for Num {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Num {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Num {
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+
+impl Serialize for Num {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Num {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NumVisitor)
+    }
+}
+
+struct NumVisitor;
+
+impl de::Visitor<'_> for NumVisitor {
+    type Value = Num;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a decimal string or number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Num, E> {
+        Ok(v.into())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Num, E> {
+        Ok(v.into())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Num, E> {
+        Ok(v.to_string().into())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Num, E> {
+        Ok(v.to_string().into())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Num, E> {
+        Ok(v.to_string().into())
+    }
+}
+
+#[cfg(feature = "decimal")]
+pub use self::exact::Decimal;
+
+#[cfg(feature = "decimal")]
+mod exact {
+    use std::{
+        fmt,
+        ops::{Add, Deref, Div, Mul, Neg, Sub},
+        str::FromStr,
+    };
+
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::errors::HyperliquidError;
+
+    /// Exact decimal that round-trips through Hyperliquid's string encoding.
+    ///
+    /// Wraps [`rust_decimal::Decimal`]; dereference it for the full numeric API.
+    /// Equality and ordering are numeric, so `1.50 == 1.5`.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Decimal(rust_decimal::Decimal);
+
+    impl Decimal {
+        pub const ZERO: Self = Self(rust_decimal::Decimal::ZERO);
+
+        /// Canonical wire form: trailing zeros and a negative zero sign removed
+        pub fn to_wire_string(&self) -> String {
+            self.0.normalize().to_string()
+        }
+
+        /// Nearest `f64`, for display or statistics
+        pub fn to_f64(&self) -> f64 {
+            use rust_decimal::prelude::ToPrimitive;
+            self.0.to_f64().unwrap_or(f64::NAN)
+        }
+
+        /// The underlying `rust_decimal` value
+        pub fn into_inner(self) -> rust_decimal::Decimal {
+            self.0
+        }
+    }
+
+    impl Deref for Decimal {
+        type Target = rust_decimal::Decimal;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<rust_decimal::Decimal> for Decimal {
+        fn from(value: rust_decimal::Decimal) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Decimal> for rust_decimal::Decimal {
+        fn from(value: Decimal) -> Self {
+            value.0
+        }
+    }
+
+    impl From<Decimal> for String {
+        fn from(value: Decimal) -> Self {
+            value.to_wire_string()
+        }
+    }
+
+    impl From<u64> for Decimal {
+        fn from(value: u64) -> Self {
+            Self(value.into())
+        }
+    }
+
+    impl From<i64> for Decimal {
+        fn from(value: i64) -> Self {
+            Self(value.into())
+        }
+    }
+
+    impl FromStr for Decimal {
+        type Err = HyperliquidError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            rust_decimal::Decimal::from_str_exact(s.trim())
+                .map(Self)
+                .map_err(|e| {
+                    HyperliquidError::InvalidRequest(format!(
+                        "invalid decimal {:?}: {}",
+                        s, e
+                    ))
+                })
+        }
+    }
+
+    impl fmt::Display for Decimal {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&self.to_wire_string())
+        }
+    }
+
+    /// Numeric comparison with a decimal string; unparseable strings are unequal
+    impl PartialEq<str> for Decimal {
+        fn eq(&self, other: &str) -> bool {
+            other.parse::<Decimal>().is_ok_and(|other| *self == other)
+        }
+    }
+
+    impl PartialEq<&str> for Decimal {
+        fn eq(&self, other: &&str) -> bool {
+            *self == **other
+        }
+    }
+
+    impl PartialEq<String> for Decimal {
+        fn eq(&self, other: &String) -> bool {
+            *self == *other.as_str()
+        }
+    }
+
+    macro_rules! forward_binop {
+        ($($trait:ident $method:ident),*) => {$(
+            impl $trait for Decimal {
+                type Output = Decimal;
+
+                fn $method(self, rhs: Decimal) -> Decimal {
+                    Decimal($trait::$method(self.0, rhs.0))
+                }
+            }
+        )*};
+    }
+
+    forward_binop!(Add add, Sub sub, Mul mul, Div div);
+
+    impl Neg for Decimal {
+        type Output = Decimal;
+
+        fn neg(self) -> Decimal {
+            Decimal(-self.0)
+        }
+    }
+
+    impl Serialize for Decimal {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.to_wire_string())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Decimal {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(DecimalVisitor)
+        }
+    }
+
+    struct DecimalVisitor;
+
+    impl de::Visitor<'_> for DecimalVisitor {
+        type Value = Decimal;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a decimal string or number")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Decimal, E> {
+            v.parse().map_err(E::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Decimal, E> {
+            Ok(v.into())
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Decimal, E> {
+            Ok(v.into())
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Decimal, E> {
+            // Shortest round-trip representation, not the binary expansion
+            rust_decimal::Decimal::from_str(&v.to_string())
+                .or_else(|_| rust_decimal::Decimal::from_scientific(&format!("{:e}", v)))
+                .map(Decimal)
+                .map_err(E::custom)
+        }
+    }
+}
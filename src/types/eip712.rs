@@ -61,6 +61,49 @@ pub trait HyperliquidAction: Sized + serde::Serialize {
 
         keccak256(&buf)
     }
+
+    /// EIP-712 hash a co-signer signs to approve this action for a multi-sig user.
+    ///
+    /// The action's type gains `address payloadMultiSigUser,address outerSigner`
+    /// right after `hyperliquidChain`, which must be its first field.
+    fn multi_sig_signing_hash(
+        &self,
+        domain: &Eip712Domain,
+        multi_sig_user: Address,
+        outer_signer: Address,
+    ) -> B256 {
+        let prefix = if Self::USE_PREFIX {
+            "HyperliquidTransaction:"
+        } else {
+            ""
+        };
+        let type_string = format!(
+            "{}{}",
+            prefix,
+            Self::TYPE_STRING.replacen(
+                "string hyperliquidChain",
+                "string hyperliquidChain,address payloadMultiSigUser,address outerSigner",
+                1,
+            )
+        );
+
+        // Skip the original type hash, keep hyperliquidChain, then splice in the envelope
+        let data = self.encode_data();
+        let mut encoded = Vec::with_capacity(data.len() + 64);
+        encoded.extend_from_slice(&keccak256(type_string.as_bytes())[..]);
+        encoded.extend_from_slice(&data[32..64]);
+        encoded.extend_from_slice(&encode_value(&multi_sig_user));
+        encoded.extend_from_slice(&encode_value(&outer_signer));
+        encoded.extend_from_slice(&data[64..]);
+
+        let mut buf = Vec::with_capacity(66);
+        buf.push(0x19);
+        buf.push(0x01);
+        buf.extend_from_slice(&domain.separator()[..]);
+        buf.extend_from_slice(&keccak256(encoded)[..]);
+
+        keccak256(&buf)
+    }
 }
 
 /// Encode a value according to EIP-712 rules
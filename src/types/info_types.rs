@@ -1,20 +1,142 @@
+use std::{fmt, str::FromStr, time::Duration};
+
 use alloy::primitives::Address;
 use serde::{Deserialize, Serialize};
 
+use crate::{errors::HyperliquidError, types::decimal::Num};
+
 // ==================== Request Types ====================
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CandleSnapshotRequest {
     pub coin: String,
-    pub interval: String,
+    pub interval: CandleInterval,
     pub start_time: u64,
     pub end_time: u64,
 }
 
+/// Candle interval of `candleSnapshot` requests and `candle` subscriptions
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CandleInterval {
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "3m")]
+    ThreeMinutes,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+    #[serde(rename = "1h")]
+    OneHour,
+    #[serde(rename = "2h")]
+    TwoHours,
+    #[serde(rename = "4h")]
+    FourHours,
+    #[serde(rename = "8h")]
+    EightHours,
+    #[serde(rename = "12h")]
+    TwelveHours,
+    #[serde(rename = "1d")]
+    OneDay,
+    #[serde(rename = "3d")]
+    ThreeDays,
+    #[serde(rename = "1w")]
+    OneWeek,
+    #[serde(rename = "1M")]
+    OneMonth,
+}
+
+impl CandleInterval {
+    /// Every interval, shortest first
+    pub const ALL: [CandleInterval; 14] = [
+        Self::OneMinute,
+        Self::ThreeMinutes,
+        Self::FiveMinutes,
+        Self::FifteenMinutes,
+        Self::ThirtyMinutes,
+        Self::OneHour,
+        Self::TwoHours,
+        Self::FourHours,
+        Self::EightHours,
+        Self::TwelveHours,
+        Self::OneDay,
+        Self::ThreeDays,
+        Self::OneWeek,
+        Self::OneMonth,
+    ];
+
+    /// Wire name, e.g. `"15m"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OneMinute => "1m",
+            Self::ThreeMinutes => "3m",
+            Self::FiveMinutes => "5m",
+            Self::FifteenMinutes => "15m",
+            Self::ThirtyMinutes => "30m",
+            Self::OneHour => "1h",
+            Self::TwoHours => "2h",
+            Self::FourHours => "4h",
+            Self::EightHours => "8h",
+            Self::TwelveHours => "12h",
+            Self::OneDay => "1d",
+            Self::ThreeDays => "3d",
+            Self::OneWeek => "1w",
+            Self::OneMonth => "1M",
+        }
+    }
+
+    /// Nominal length of one candle; a month counts as 30 days
+    pub fn duration(&self) -> Duration {
+        const MINUTE: u64 = 60;
+        const HOUR: u64 = 60 * MINUTE;
+        const DAY: u64 = 24 * HOUR;
+        Duration::from_secs(match self {
+            Self::OneMinute => MINUTE,
+            Self::ThreeMinutes => 3 * MINUTE,
+            Self::FiveMinutes => 5 * MINUTE,
+            Self::FifteenMinutes => 15 * MINUTE,
+            Self::ThirtyMinutes => 30 * MINUTE,
+            Self::OneHour => HOUR,
+            Self::TwoHours => 2 * HOUR,
+            Self::FourHours => 4 * HOUR,
+            Self::EightHours => 8 * HOUR,
+            Self::TwelveHours => 12 * HOUR,
+            Self::OneDay => DAY,
+            Self::ThreeDays => 3 * DAY,
+            Self::OneWeek => 7 * DAY,
+            Self::OneMonth => 30 * DAY,
+        })
+    }
+}
+
+impl fmt::Display for CandleInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CandleInterval {
+    type Err = HyperliquidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|interval| interval.as_str() == s)
+            .ok_or_else(|| {
+                HyperliquidError::InvalidRequest(format!(
+                    "unknown candle interval: {}",
+                    s
+                ))
+            })
+    }
+}
+
 // ==================== Common Response Types ====================
 
-// Note: AllMids returns HashMap<String, String> directly, not wrapped
+// Note: AllMids returns HashMap<String, Num> directly, not wrapped
 
 // ==================== Position & Margin Types ====================
 
@@ -30,17 +152,17 @@ pub struct AssetPosition {
 pub struct BasicOrderInfo {
     pub coin: String,
     pub side: String,
-    pub limit_px: String,
-    pub sz: String,
+    pub limit_px: Num,
+    pub sz: Num,
     pub oid: u64,
     pub timestamp: u64,
     pub trigger_condition: String,
     pub is_trigger: bool,
-    pub trigger_px: String,
+    pub trigger_px: Num,
     pub is_position_tpsl: bool,
     pub reduce_only: bool,
     pub order_type: String,
-    pub orig_sz: String,
+    pub orig_sz: Num,
     pub tif: String,
     pub cloid: Option<String>,
 }
@@ -56,15 +178,15 @@ pub struct CandlesSnapshotResponse {
     #[serde(rename = "i")]
     pub candle_interval: String,
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: Num,
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: Num,
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: Num,
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: Num,
     #[serde(rename = "v")]
-    pub vlm: String,
+    pub vlm: Num,
     #[serde(rename = "n")]
     pub num_trades: u64,
 }
@@ -72,9 +194,9 @@ pub struct CandlesSnapshotResponse {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CumulativeFunding {
-    pub all_time: String,
-    pub since_open: String,
-    pub since_change: String,
+    pub all_time: Num,
+    pub since_open: Num,
+    pub since_change: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -82,8 +204,8 @@ pub struct CumulativeFunding {
 pub struct DailyUserVlm {
     pub date: String,
     pub exchange: String,
-    pub user_add: String,
-    pub user_cross: String,
+    pub user_add: Num,
+    pub user_cross: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -92,17 +214,17 @@ pub struct Delta {
     #[serde(rename = "type")]
     pub type_string: String,
     pub coin: String,
-    pub usdc: String,
-    pub szi: String,
-    pub funding_rate: String,
+    pub usdc: Num,
+    pub szi: Num,
+    pub funding_rate: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct FeeSchedule {
-    pub add: String,
-    pub cross: String,
-    pub referral_discount: String,
+    pub add: Num,
+    pub cross: Num,
+    pub referral_discount: Num,
     pub tiers: Tiers,
 }
 
@@ -110,8 +232,8 @@ pub struct FeeSchedule {
 #[serde(rename_all = "camelCase")]
 pub struct FundingHistoryResponse {
     pub coin: String,
-    pub funding_rate: String,
-    pub premium: String,
+    pub funding_rate: Num,
+    pub premium: Num,
     pub time: u64,
 }
 
@@ -127,8 +249,8 @@ pub struct L2SnapshotResponse {
 #[serde(rename_all = "camelCase")]
 pub struct Level {
     pub n: u64,
-    pub px: String,
-    pub sz: String,
+    pub px: Num,
+    pub sz: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -137,34 +259,39 @@ pub struct Leverage {
     #[serde(rename = "type")]
     pub type_string: String,
     pub value: u32,
-    pub raw_usd: Option<String>,
+    pub raw_usd: Option<Num>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MarginSummary {
-    pub account_value: String,
-    pub total_margin_used: String,
-    pub total_ntl_pos: String,
-    pub total_raw_usd: String,
+    pub account_value: Num,
+    pub total_margin_used: Num,
+    pub total_ntl_pos: Num,
+    pub total_raw_usd: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Mm {
-    pub add: String,
-    pub maker_fraction_cutoff: String,
+    pub add: Num,
+    pub maker_fraction_cutoff: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenOrdersResponse {
     pub coin: String,
-    pub limit_px: String,
+    pub limit_px: Num,
     pub oid: u64,
     pub side: String,
-    pub sz: String,
+    pub sz: Num,
     pub timestamp: u64,
+    #[serde(default)]
+    pub orig_sz: Option<Num>,
+    /// Client order id, if the order was placed with one
+    #[serde(default)]
+    pub cloid: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -187,14 +314,14 @@ pub struct OrderStatusResponse {
 #[serde(rename_all = "camelCase")]
 pub struct PositionData {
     pub coin: String,
-    pub entry_px: Option<String>,
+    pub entry_px: Option<Num>,
     pub leverage: Leverage,
-    pub liquidation_px: Option<String>,
-    pub margin_used: String,
-    pub position_value: String,
-    pub return_on_equity: String,
-    pub szi: String,
-    pub unrealized_pnl: String,
+    pub liquidation_px: Option<Num>,
+    pub margin_used: Num,
+    pub position_value: Num,
+    pub return_on_equity: Num,
+    pub szi: Num,
+    pub unrealized_pnl: Num,
     pub max_leverage: u32,
     pub cum_funding: CumulativeFunding,
 }
@@ -204,8 +331,8 @@ pub struct PositionData {
 pub struct RecentTradesResponse {
     pub coin: String,
     pub side: String,
-    pub px: String,
-    pub sz: String,
+    pub px: Num,
+    pub sz: Num,
     pub time: u64,
     pub hash: String,
 }
@@ -221,9 +348,9 @@ pub struct Referrer {
 #[serde(rename_all = "camelCase")]
 pub struct ReferralResponse {
     pub referred_by: Option<Referrer>,
-    pub cum_vlm: String,
-    pub unclaimed_rewards: String,
-    pub claimed_rewards: String,
+    pub cum_vlm: Num,
+    pub unclaimed_rewards: Num,
+    pub claimed_rewards: Num,
     pub referrer_state: ReferrerState,
 }
 
@@ -249,28 +376,28 @@ pub struct Tiers {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct UserFeesResponse {
-    pub active_referral_discount: String,
+    pub active_referral_discount: Num,
     pub daily_user_vlm: Vec<DailyUserVlm>,
     pub fee_schedule: FeeSchedule,
-    pub user_add_rate: String,
-    pub user_cross_rate: String,
+    pub user_add_rate: Num,
+    pub user_cross_rate: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct UserFillsResponse {
-    pub closed_pnl: String,
+    pub closed_pnl: Num,
     pub coin: String,
     pub crossed: bool,
     pub dir: String,
     pub hash: String,
     pub oid: u64,
-    pub px: String,
+    pub px: Num,
     pub side: String,
-    pub start_position: String,
-    pub sz: String,
+    pub start_position: Num,
+    pub sz: Num,
     pub time: u64,
-    pub fee: String,
+    pub fee: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -286,16 +413,16 @@ pub struct UserStateResponse {
     pub asset_positions: Vec<AssetPosition>,
     pub cross_margin_summary: MarginSummary,
     pub margin_summary: MarginSummary,
-    pub withdrawable: String,
+    pub withdrawable: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct UserTokenBalance {
     pub coin: String,
-    pub hold: String,
-    pub total: String,
-    pub entry_ntl: String,
+    pub hold: Num,
+    pub total: Num,
+    pub entry_ntl: Num,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -306,9 +433,9 @@ pub struct UserTokenBalanceResponse {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Vip {
-    pub add: String,
-    pub cross: String,
-    pub ntl_cutoff: String,
+    pub add: Num,
+    pub cross: Num,
+    pub ntl_cutoff: Num,
 }
 
 // ==================== Metadata Types ====================
@@ -328,9 +455,9 @@ pub struct AssetMeta {
     #[serde(default)]
     pub only_isolated: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub initial_margin_ratio: Option<String>,
+    pub initial_margin_ratio: Option<Num>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maintenance_margin_ratio: Option<String>,
+    pub maintenance_margin_ratio: Option<Num>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub margin_table_id: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -377,7 +504,7 @@ pub struct TokenMeta {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evm_contract: Option<EvmContract>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployer_trading_fee_share: Option<String>,
+    pub deployer_trading_fee_share: Option<Num>,
 }
 
 /// Response for spotMetaAndAssetCtxs - a tuple of (SpotMeta, Vec<SpotAssetContext>)
@@ -412,37 +539,37 @@ pub struct SpotAssetContext {
     /// Coin/pair name (e.g., "PURR/USDC")
     pub coin: String,
     /// Previous day price
-    pub prev_day_px: String,
+    pub prev_day_px: Num,
     /// Daily notional volume
-    pub day_ntl_vlm: String,
+    pub day_ntl_vlm: Num,
     /// Mark price
-    pub mark_px: String,
+    pub mark_px: Num,
     /// Mid price (can be null)
     #[serde(default)]
-    pub mid_px: Option<String>,
+    pub mid_px: Option<Num>,
     /// Circulating supply
     #[serde(default)]
-    pub circulating_supply: Option<String>,
+    pub circulating_supply: Option<Num>,
     /// Total supply
     #[serde(default)]
-    pub total_supply: Option<String>,
+    pub total_supply: Option<Num>,
     /// Daily base volume
     #[serde(default)]
-    pub day_base_vlm: Option<String>,
+    pub day_base_vlm: Option<Num>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetContext {
-    pub day_ntl_vlm: String,
-    pub funding: String,
-    pub impact_pxs: Vec<String>,
-    pub mark_px: String,
-    pub mid_px: String,
-    pub open_interest: String,
-    pub oracle_px: String,
-    pub premium: String,
-    pub prev_day_px: String,
+    pub day_ntl_vlm: Num,
+    pub funding: Num,
+    pub impact_pxs: Vec<Num>,
+    pub mark_px: Num,
+    pub mid_px: Num,
+    pub open_interest: Num,
+    pub oracle_px: Num,
+    pub premium: Num,
+    pub prev_day_px: Num,
 }
 
 // ==================== Phase 1 New Types ====================
@@ -459,15 +586,15 @@ pub struct MetaAndAssetCtxs {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct PerpAssetContext {
-    pub day_ntl_vlm: String,
-    pub funding: String,
-    pub impact_pxs: Option<Vec<String>>,
-    pub mark_px: String,
-    pub mid_px: Option<String>,
-    pub open_interest: String,
-    pub oracle_px: String,
-    pub premium: Option<String>,
-    pub prev_day_px: String,
+    pub day_ntl_vlm: Num,
+    pub funding: Num,
+    pub impact_pxs: Option<Vec<Num>>,
+    pub mark_px: Num,
+    pub mid_px: Option<Num>,
+    pub open_interest: Num,
+    pub oracle_px: Num,
+    pub premium: Option<Num>,
+    pub prev_day_px: Num,
 }
 
 /// Response for frontendOpenOrders - open orders with extra frontend metadata
@@ -476,18 +603,18 @@ pub struct PerpAssetContext {
 pub struct FrontendOpenOrder {
     pub coin: String,
     pub side: String,
-    pub limit_px: String,
-    pub sz: String,
+    pub limit_px: Num,
+    pub sz: Num,
     pub oid: u64,
     pub timestamp: u64,
-    pub orig_sz: String,
+    pub orig_sz: Num,
     pub cloid: Option<String>,
     pub reduce_only: bool,
     pub order_type: String,
     pub tif: String,
     pub trigger_condition: String,
     pub is_trigger: bool,
-    pub trigger_px: String,
+    pub trigger_px: Num,
     pub is_position_tpsl: bool,
     #[serde(default)]
     pub children: Option<Vec<FrontendOpenOrder>>,
@@ -497,18 +624,18 @@ pub struct FrontendOpenOrder {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct UserFillByTime {
-    pub closed_pnl: String,
+    pub closed_pnl: Num,
     pub coin: String,
     pub crossed: bool,
     pub dir: String,
     pub hash: String,
     pub oid: u64,
-    pub px: String,
+    pub px: Num,
     pub side: String,
-    pub start_position: String,
-    pub sz: String,
+    pub start_position: Num,
+    pub sz: Num,
     pub time: u64,
-    pub fee: String,
+    pub fee: Num,
     pub fee_token: String,
     pub tid: u64,
     pub cloid: Option<String>,
@@ -539,14 +666,14 @@ pub struct SubAccount {
 pub struct SubAccountClearinghouseState {
     pub margin_summary: MarginSummary,
     pub cross_margin_summary: MarginSummary,
-    pub withdrawable: String,
+    pub withdrawable: Num,
 }
 
 /// Response for userRateLimit
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct UserRateLimit {
-    pub cum_vlm: String,
+    pub cum_vlm: Num,
     pub n_request_ids: u32,
     pub n_request_weights: u32,
     pub n_request_ids_limit: u32,
@@ -558,7 +685,7 @@ pub struct UserRateLimit {
 #[serde(rename_all = "camelCase")]
 pub struct VaultEquity {
     pub vault_address: Address,
-    pub equity: String,
+    pub equity: Num,
 }
 
 // ==================== Phase 2 New Types ====================
@@ -580,7 +707,7 @@ pub struct PortfolioPeriodData {
     /// PnL history as array of [timestamp, value] pairs
     pub pnl_history: Vec<(u64, String)>,
     /// Total volume for this period
-    pub vlm: String,
+    pub vlm: Num,
 }
 
 /// Response for userNonFundingLedgerUpdates - ledger activity
@@ -707,16 +834,16 @@ pub struct TokenDetails {
     pub evm_contract: Option<EvmContract>,
     /// Deployer trading fee share
     #[serde(default)]
-    pub deployer_trading_fee_share: Option<String>,
+    pub deployer_trading_fee_share: Option<Num>,
     /// Total supply
     #[serde(default)]
-    pub total_supply: Option<String>,
+    pub total_supply: Option<Num>,
     /// Circulating supply
     #[serde(default)]
-    pub circulating_supply: Option<String>,
+    pub circulating_supply: Option<Num>,
     /// Market cap
     #[serde(default)]
-    pub market_cap: Option<String>,
+    pub market_cap: Option<Num>,
 }
 
 // ==================== Phase 3 New Types ====================
@@ -729,16 +856,16 @@ pub struct TokenDetails {
 pub struct DelegatorSummary {
     /// Total delegated amount in wei
     #[serde(default)]
-    pub delegated: Option<String>,
+    pub delegated: Option<Num>,
     /// Total undelegating amount in wei
     #[serde(default)]
-    pub undelegating: Option<String>,
+    pub undelegating: Option<Num>,
     /// Total rewards earned
     #[serde(default)]
-    pub total_rewards: Option<String>,
+    pub total_rewards: Option<Num>,
     /// Pending rewards to claim
     #[serde(default)]
-    pub pending_rewards: Option<String>,
+    pub pending_rewards: Option<Num>,
     /// Number of validators delegated to
     #[serde(default)]
     pub n_validators: Option<u32>,
@@ -751,13 +878,13 @@ pub struct Delegation {
     /// Validator address
     pub validator: Address,
     /// Delegated amount in wei
-    pub amount: String,
+    pub amount: Num,
     /// Locked until timestamp (for undelegating)
     #[serde(default)]
     pub locked_until: Option<u64>,
     /// Pending rewards
     #[serde(default)]
-    pub pending_rewards: Option<String>,
+    pub pending_rewards: Option<Num>,
 }
 
 /// Response for delegatorRewards - historic staking rewards
@@ -769,7 +896,7 @@ pub struct DelegatorReward {
     /// Validator address
     pub validator: Address,
     /// Reward amount
-    pub amount: String,
+    pub amount: Num,
     /// Transaction hash
     #[serde(default)]
     pub hash: Option<String>,
@@ -789,7 +916,7 @@ pub struct DelegatorHistoryEntry {
     pub validator: Option<Address>,
     /// Amount in wei
     #[serde(default)]
-    pub amount: Option<String>,
+    pub amount: Option<Num>,
     /// Transaction hash
     #[serde(default)]
     pub hash: Option<String>,
@@ -821,10 +948,10 @@ pub struct PerpDeployAuction {
     pub coin: Option<String>,
     /// Starting price
     #[serde(default)]
-    pub start_px: Option<String>,
+    pub start_px: Option<Num>,
     /// Current bid
     #[serde(default)]
-    pub current_bid: Option<String>,
+    pub current_bid: Option<Num>,
     /// End time
     #[serde(default)]
     pub end_time: Option<u64>,
@@ -861,7 +988,7 @@ pub struct SpotTokenDeployState {
 pub struct SpotGenesisInfo {
     /// Max supply
     #[serde(default)]
-    pub max_supply: Option<String>,
+    pub max_supply: Option<Num>,
     /// Hyperliquidity enabled
     #[serde(default)]
     pub hyperliquidity: Option<bool>,
@@ -894,7 +1021,7 @@ pub struct SpotPairDeployAuctionStatus {
     pub state: Option<String>,
     /// Current bid
     #[serde(default)]
-    pub current_bid: Option<String>,
+    pub current_bid: Option<Num>,
     /// End time
     #[serde(default)]
     pub end_time: Option<u64>,
@@ -902,14 +1029,24 @@ pub struct SpotPairDeployAuctionStatus {
 
 // --- Other Types ---
 
-/// Response for perpDexs - available perpetual DEXs
+/// Entry of the perpDexs response - a builder-deployed perpetual DEX
+///
+/// The response lists DEXs by index, with `null` standing in for the main DEX at
+/// index 0.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PerpDex {
-    /// DEX identifier
+    /// DEX identifier, when included in the response
+    #[serde(default)]
     pub dex: u32,
-    /// DEX name
+    /// DEX name, used as the `dex` parameter of `meta` and as the coin prefix
     pub name: String,
+    /// Human-readable DEX name
+    #[serde(default)]
+    pub full_name: Option<String>,
+    /// Deployer address
+    #[serde(default)]
+    pub deployer: Option<String>,
     /// Coins listed on this DEX
     #[serde(default)]
     pub coins: Option<Vec<String>>,
@@ -930,7 +1067,7 @@ pub struct UserDexAbstraction {
 }
 
 /// Response for userToMultiSigSigners
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct MultiSigSignerInfo {
     /// Signer address
@@ -960,9 +1097,9 @@ pub struct TwapSliceFill {
     /// Coin name
     pub coin: String,
     /// Fill price
-    pub px: String,
+    pub px: Num,
     /// Fill size
-    pub sz: String,
+    pub sz: Num,
     /// Side (buy/sell)
     pub side: String,
     /// Fill time
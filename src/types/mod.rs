@@ -1,4 +1,5 @@
 pub mod actions;
+pub mod decimal;
 pub mod eip712;
 pub mod info_types;
 pub mod requests;
@@ -9,6 +10,9 @@ pub mod ws;
 
 // Re-export commonly used types
 pub use actions::*;
+#[cfg(feature = "decimal")]
+pub use decimal::Decimal;
+pub use decimal::Num;
 pub use eip712::{encode_value, EncodeEip712, HyperliquidAction};
 pub use info_types::*;
 pub use requests::*;
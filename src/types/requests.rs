@@ -48,6 +48,20 @@ pub struct Trigger {
     pub tpsl: String, // "tp" or "sl"
 }
 
+/// How the orders of a bulk order request relate to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Grouping {
+    /// Independent orders
+    #[default]
+    Na,
+    /// The first order is an entry and the following trigger orders are its
+    /// take-profit / stop-loss, sized to the entry's fill
+    NormalTpsl,
+    /// Take-profit / stop-loss trigger orders attached to the existing position
+    PositionTpsl,
+}
+
 // ==================== Cancel Types ====================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,21 +1,32 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{HyperliquidError, RejectReason},
+    types::decimal::Num,
+};
 
 // ==================== Order Status Types ====================
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RestingOrder {
     pub oid: u64,
+    /// Client order id, if the order was placed with one
+    #[serde(default)]
+    pub cloid: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FilledOrder {
-    pub total_sz: String,
-    pub avg_px: String,
+    pub total_sz: Num,
+    pub avg_px: Num,
     pub oid: u64,
+    /// Client order id, if the order was placed with one
+    #[serde(default)]
+    pub cloid: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ExchangeDataStatus {
     Success,
@@ -28,19 +39,19 @@ pub enum ExchangeDataStatus {
 
 // ==================== Exchange Response Types ====================
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExchangeDataStatuses {
     pub statuses: Vec<ExchangeDataStatus>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExchangeResponse {
     #[serde(rename = "type")]
     pub response_type: String,
     pub data: Option<ExchangeDataStatuses>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "status", content = "response")]
 pub enum ExchangeResponseStatus {
@@ -64,6 +75,11 @@ impl ExchangeResponseStatus {
         }
     }
 
+    /// Classified reason if the whole request was rejected
+    pub fn reject_reason(&self) -> Option<RejectReason> {
+        self.error().map(RejectReason::from_message)
+    }
+
     /// Get the inner response if successful
     pub fn into_result(self) -> Result<ExchangeResponse, String> {
         match self {
@@ -71,6 +87,43 @@ impl ExchangeResponseStatus {
             Self::Err(msg) => Err(msg),
         }
     }
+
+    /// Per-order results of an order request, in the order the orders were sent.
+    ///
+    /// Fails with [`HyperliquidError::Rejected`] if the whole request was
+    /// rejected, or if the response carries no statuses; rejections of single
+    /// orders are [`OrderOutcome::Rejected`]. Use [`OrderOutcomes::into_result`]
+    /// to fail on those too.
+    /// `RawExchangeProvider::bulk_order_outcomes` sends orders and also checks
+    /// that there is one outcome per order.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let outcomes = provider.bulk_orders(orders.clone()).await?.order_outcomes()?;
+    /// for (order, outcome) in orders.iter().zip(outcomes.iter()) {
+    ///     println!("{} -> {:?}", order.limit_px, outcome);
+    /// }
+    /// ```
+    pub fn order_outcomes(&self) -> Result<OrderOutcomes, HyperliquidError> {
+        let response = match self {
+            Self::Ok(response) => response,
+            Self::Err(msg) => return Err(HyperliquidError::rejected(msg.clone())),
+        };
+        let statuses = response.data.as_ref().ok_or_else(|| {
+            HyperliquidError::InvalidResponse(format!(
+                "{} response has no order statuses",
+                response.response_type
+            ))
+        })?;
+        Ok(OrderOutcomes(
+            statuses
+                .statuses
+                .iter()
+                .cloned()
+                .map(OrderOutcome::from)
+                .collect(),
+        ))
+    }
 }
 
 impl ExchangeDataStatus {
@@ -79,6 +132,14 @@ impl ExchangeDataStatus {
         matches!(self, Self::Success | Self::Resting(_) | Self::Filled(_))
     }
 
+    /// Classified reason if this order was rejected
+    pub fn reject_reason(&self) -> Option<RejectReason> {
+        match self {
+            Self::Error(msg) => Some(RejectReason::from_message(msg)),
+            _ => None,
+        }
+    }
+
     /// Get order ID if available
     pub fn order_id(&self) -> Option<u64> {
         match self {
@@ -88,3 +149,162 @@ impl ExchangeDataStatus {
         }
     }
 }
+
+// ==================== Order Outcomes ====================
+
+/// Result of a single order within an order request.
+#[derive(Debug, Clone)]
+pub enum OrderOutcome {
+    /// Resting on the book
+    Resting { oid: u64, cloid: Option<String> },
+    /// Filled immediately, fully or partially
+    Filled {
+        oid: u64,
+        cloid: Option<String>,
+        avg_px: Num,
+        total_sz: Num,
+    },
+    /// Take-profit / stop-loss waiting for its entry to fill
+    WaitingForFill,
+    /// Trigger order waiting for its trigger price
+    WaitingForTrigger,
+    /// Accepted without order details
+    Accepted,
+    /// Rejected by the exchange; the rest of the request may have succeeded
+    Rejected {
+        reason: RejectReason,
+        message: String,
+    },
+}
+
+impl From<ExchangeDataStatus> for OrderOutcome {
+    fn from(status: ExchangeDataStatus) -> Self {
+        match status {
+            ExchangeDataStatus::Resting(order) => Self::Resting {
+                oid: order.oid,
+                cloid: order.cloid,
+            },
+            ExchangeDataStatus::Filled(order) => Self::Filled {
+                oid: order.oid,
+                cloid: order.cloid,
+                avg_px: order.avg_px,
+                total_sz: order.total_sz,
+            },
+            ExchangeDataStatus::WaitingForFill => Self::WaitingForFill,
+            ExchangeDataStatus::WaitingForTrigger => Self::WaitingForTrigger,
+            ExchangeDataStatus::Success => Self::Accepted,
+            ExchangeDataStatus::Error(message) => Self::Rejected {
+                reason: RejectReason::from_message(&message),
+                message,
+            },
+        }
+    }
+}
+
+impl OrderOutcome {
+    /// Whether the exchange rejected this order
+    pub fn is_rejected(&self) -> bool {
+        matches!(self, Self::Rejected { .. })
+    }
+
+    /// Order id, for resting and filled orders
+    pub fn oid(&self) -> Option<u64> {
+        match self {
+            Self::Resting { oid, .. } | Self::Filled { oid, .. } => Some(*oid),
+            _ => None,
+        }
+    }
+
+    /// Client order id echoed by the exchange
+    pub fn cloid(&self) -> Option<&str> {
+        match self {
+            Self::Resting { cloid, .. } | Self::Filled { cloid, .. } => cloid.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Average fill price and filled size, for filled orders
+    pub fn fill(&self) -> Option<(&Num, &Num)> {
+        match self {
+            Self::Filled {
+                avg_px, total_sz, ..
+            } => Some((avg_px, total_sz)),
+            _ => None,
+        }
+    }
+
+    /// Rejection message, for rejected orders
+    pub fn rejection(&self) -> Option<&str> {
+        match self {
+            Self::Rejected { message, .. } => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Classified rejection reason, for rejected orders
+    pub fn reject_reason(&self) -> Option<RejectReason> {
+        match self {
+            Self::Rejected { reason, .. } => Some(*reason),
+            _ => None,
+        }
+    }
+}
+
+/// Per-order results of an order request, aligned with the submitted orders.
+#[derive(Debug, Clone, Default)]
+pub struct OrderOutcomes(pub Vec<OrderOutcome>);
+
+impl OrderOutcomes {
+    /// Whether no order was rejected
+    pub fn all_accepted(&self) -> bool {
+        !self.0.iter().any(OrderOutcome::is_rejected)
+    }
+
+    /// Rejected orders with their index in the request
+    pub fn rejected(&self) -> impl Iterator<Item = (usize, &OrderOutcome)> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, outcome)| outcome.is_rejected())
+    }
+
+    /// Fail if any order was rejected, otherwise return the outcomes.
+    ///
+    /// The error is [`HyperliquidError::Rejected`] with the reason of the first
+    /// rejected order and a message naming every rejected order by its index in
+    /// the request.
+    pub fn into_result(self) -> Result<Vec<OrderOutcome>, HyperliquidError> {
+        let Some(reason) = self.0.iter().find_map(OrderOutcome::reject_reason) else {
+            return Ok(self.0);
+        };
+        let message = self
+            .rejected()
+            .map(|(index, outcome)| {
+                format!(
+                    "order {}: {}",
+                    index,
+                    outcome.rejection().unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        Err(HyperliquidError::Rejected { reason, message })
+    }
+}
+
+impl std::ops::Deref for OrderOutcomes {
+    type Target = [OrderOutcome];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for OrderOutcomes {
+    type Item = OrderOutcome;
+    type IntoIter = std::vec::IntoIter<OrderOutcome>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
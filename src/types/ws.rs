@@ -5,32 +5,77 @@ use std::collections::HashMap;
 use alloy::primitives::Address;
 use serde::{Deserialize, Serialize};
 
+use crate::types::{
+    decimal::Num,
+    info_types::{CandleInterval, Leverage},
+};
+
 // Subscription types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum Subscription {
     AllMids,
-    Notification { user: Address },
-    WebData2 { user: Address },
-    Candle { coin: String, interval: String },
-    L2Book { coin: String },
-    Trades { coin: String },
-    OrderUpdates { user: Address },
-    UserEvents { user: Address },
-    UserFills { user: Address },
-    UserFundings { user: Address },
-    UserNonFundingLedgerUpdates { user: Address },
+    Notification {
+        user: Address,
+    },
+    WebData2 {
+        user: Address,
+    },
+    Candle {
+        coin: String,
+        interval: CandleInterval,
+    },
+    L2Book {
+        coin: String,
+    },
+    Trades {
+        coin: String,
+    },
+    OrderUpdates {
+        user: Address,
+    },
+    UserEvents {
+        user: Address,
+    },
+    UserFills {
+        user: Address,
+    },
+    UserFundings {
+        user: Address,
+    },
+    UserNonFundingLedgerUpdates {
+        user: Address,
+    },
     // Phase 1 new subscriptions
-    Bbo { coin: String },
-    OpenOrders { user: Address },
-    ClearinghouseState { user: Address },
+    Bbo {
+        coin: String,
+    },
+    OpenOrders {
+        user: Address,
+    },
+    ClearinghouseState {
+        user: Address,
+    },
     // Phase 2 new subscriptions
-    WebData3 { user: Address },
-    TwapStates { user: Address },
-    ActiveAssetCtx { coin: String },
-    ActiveAssetData { user: Address, coin: String },
-    UserTwapSliceFills { user: Address },
-    UserTwapHistory { user: Address },
+    WebData3 {
+        user: Address,
+    },
+    TwapStates {
+        user: Address,
+    },
+    ActiveAssetCtx {
+        coin: String,
+    },
+    ActiveAssetData {
+        user: Address,
+        coin: String,
+    },
+    UserTwapSliceFills {
+        user: Address,
+    },
+    UserTwapHistory {
+        user: Address,
+    },
 }
 
 // Incoming message types
@@ -50,6 +95,7 @@ pub enum Message {
     User(User),
     SubscriptionResponse,
     Pong,
+    Post(PostResponse),
     // Phase 1 new message types
     Bbo(Bbo),
     OpenOrders(OpenOrdersWs),
@@ -71,7 +117,7 @@ pub struct AllMids {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct AllMidsData {
-    pub mids: HashMap<String, String>,
+    pub mids: HashMap<String, Num>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -83,8 +129,8 @@ pub struct Trades {
 pub struct Trade {
     pub coin: String,
     pub side: String,
-    pub px: String,
-    pub sz: String,
+    pub px: Num,
+    pub sz: Num,
     pub time: u64,
     pub hash: String,
     pub tid: u64,
@@ -104,8 +150,8 @@ pub struct L2BookData {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct BookLevel {
-    pub px: String,
-    pub sz: String,
+    pub px: Num,
+    pub sz: Num,
     pub n: u64,
 }
 
@@ -119,23 +165,23 @@ pub struct CandleData {
     #[serde(rename = "T")]
     pub time_close: u64,
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: Num,
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: Num,
     #[serde(rename = "i")]
     pub interval: String,
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: Num,
     #[serde(rename = "n")]
     pub num_trades: u64,
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: Num,
     #[serde(rename = "s")]
     pub coin: String,
     #[serde(rename = "t")]
     pub time_open: u64,
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: Num,
 }
 
 // User event structures
@@ -157,11 +203,11 @@ pub struct OrderUpdate {
 pub struct BasicOrder {
     pub coin: String,
     pub side: String,
-    pub limit_px: String,
-    pub sz: String,
+    pub limit_px: Num,
+    pub sz: Num,
     pub oid: u64,
     pub timestamp: u64,
-    pub orig_sz: String,
+    pub orig_sz: Num,
     pub cloid: Option<String>,
 }
 
@@ -178,22 +224,22 @@ pub struct UserFillsData {
     pub fills: Vec<TradeInfo>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TradeInfo {
     pub coin: String,
     pub side: String,
-    pub px: String,
-    pub sz: String,
+    pub px: Num,
+    pub sz: Num,
     pub time: u64,
     pub hash: String,
-    pub start_position: String,
+    pub start_position: Num,
     pub dir: String,
-    pub closed_pnl: String,
+    pub closed_pnl: Num,
     pub oid: u64,
     pub cloid: Option<String>,
     pub crossed: bool,
-    pub fee: String,
+    pub fee: Num,
     pub fee_token: String,
     pub tid: u64,
 }
@@ -216,9 +262,9 @@ pub struct UserFundingsData {
 pub struct UserFunding {
     pub time: u64,
     pub coin: String,
-    pub usdc: String,
-    pub szi: String,
-    pub funding_rate: String,
+    pub usdc: Num,
+    pub szi: Num,
+    pub funding_rate: Num,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -338,6 +384,192 @@ impl WsRequest {
     }
 }
 
+/// A request sent over the WebSocket with `method: "post"`.
+///
+/// The server answers with a [`Message::Post`] carrying the same `id`.
+#[derive(Debug, Clone, Serialize)]
+pub struct WsPostRequest {
+    pub method: &'static str,
+    pub id: u64,
+    pub request: PostRequest,
+}
+
+impl WsPostRequest {
+    pub fn new(id: u64, request: PostRequest) -> Self {
+        Self {
+            method: "post",
+            id,
+            request,
+        }
+    }
+}
+
+/// Body of a post request: the JSON that would be sent to `/info` or `/exchange`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "payload", rename_all = "camelCase")]
+pub enum PostRequest {
+    Info(serde_json::Value),
+    Action(serde_json::Value),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PostResponse {
+    pub data: PostResponseData,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PostResponseData {
+    pub id: u64,
+    pub response: PostResponsePayload,
+}
+
+/// Result of a post request.
+///
+/// `Info` holds `{"type": <info type>, "data": <HTTP response body>}`, `Action`
+/// holds the `/exchange` response body and `Error` the server's error message.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", content = "payload", rename_all = "camelCase")]
+pub enum PostResponsePayload {
+    Info(serde_json::Value),
+    Action(serde_json::Value),
+    Error(String),
+}
+
+// ==================== Message Routing ====================
+
+/// Identifies the stream a message belongs to: its channel plus the coin, user
+/// and interval the server keys that channel by.
+///
+/// A [`Message`] is delivered to a [`Subscription`] when their keys are equal.
+/// Channels whose messages do not echo the user (`orderUpdates`, `notification`,
+/// `userEvents`) are keyed by channel only.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoutingKey {
+    pub channel: &'static str,
+    pub coin: Option<String>,
+    pub user: Option<Address>,
+    pub interval: Option<String>,
+}
+
+impl RoutingKey {
+    fn channel(channel: &'static str) -> Self {
+        Self {
+            channel,
+            coin: None,
+            user: None,
+            interval: None,
+        }
+    }
+
+    fn coin(channel: &'static str, coin: &str) -> Self {
+        Self {
+            coin: Some(coin.to_string()),
+            ..Self::channel(channel)
+        }
+    }
+
+    fn user(channel: &'static str, user: Address) -> Self {
+        Self {
+            user: Some(user),
+            ..Self::channel(channel)
+        }
+    }
+}
+
+impl Subscription {
+    /// Key of the messages this subscription receives.
+    pub fn routing_key(&self) -> RoutingKey {
+        match self {
+            Self::AllMids => RoutingKey::channel("allMids"),
+            Self::Notification { .. } => RoutingKey::channel("notification"),
+            Self::WebData2 { user } => RoutingKey::user("webData2", *user),
+            Self::Candle { coin, interval } => RoutingKey {
+                interval: Some(interval.to_string()),
+                ..RoutingKey::coin("candle", coin)
+            },
+            Self::L2Book { coin } => RoutingKey::coin("l2Book", coin),
+            Self::Trades { coin } => RoutingKey::coin("trades", coin),
+            Self::OrderUpdates { .. } => RoutingKey::channel("orderUpdates"),
+            Self::UserEvents { .. } => RoutingKey::channel("user"),
+            Self::UserFills { user } => RoutingKey::user("userFills", *user),
+            Self::UserFundings { user } => RoutingKey::user("userFundings", *user),
+            Self::UserNonFundingLedgerUpdates { user } => {
+                RoutingKey::user("userNonFundingLedgerUpdates", *user)
+            }
+            Self::Bbo { coin } => RoutingKey::coin("bbo", coin),
+            Self::OpenOrders { user } => RoutingKey::user("openOrders", *user),
+            Self::ClearinghouseState { user } => {
+                RoutingKey::user("clearinghouseState", *user)
+            }
+            Self::WebData3 { user } => RoutingKey::user("webData3", *user),
+            Self::TwapStates { user } => RoutingKey::user("twapStates", *user),
+            Self::ActiveAssetCtx { coin } => RoutingKey::coin("activeAssetCtx", coin),
+            Self::ActiveAssetData { user, coin } => RoutingKey {
+                user: Some(*user),
+                ..RoutingKey::coin("activeAssetData", coin)
+            },
+            Self::UserTwapSliceFills { user } => {
+                RoutingKey::user("userTwapSliceFills", *user)
+            }
+            Self::UserTwapHistory { user } => RoutingKey::user("userTwapHistory", *user),
+        }
+    }
+}
+
+impl Message {
+    /// Key of the subscription this message belongs to.
+    ///
+    /// Returns `None` for protocol messages (`subscriptionResponse`, `pong`) and
+    /// for data messages that cannot be attributed, such as an empty trades batch.
+    pub fn routing_key(&self) -> Option<RoutingKey> {
+        let key = match self {
+            Self::AllMids(_) => RoutingKey::channel("allMids"),
+            Self::Trades(trades) => {
+                RoutingKey::coin("trades", &trades.data.first()?.coin)
+            }
+            Self::L2Book(book) => RoutingKey::coin("l2Book", &book.data.coin),
+            Self::Candle(candle) => RoutingKey {
+                interval: Some(candle.data.interval.clone()),
+                ..RoutingKey::coin("candle", &candle.data.coin)
+            },
+            Self::OrderUpdates(_) => RoutingKey::channel("orderUpdates"),
+            Self::UserFills(fills) => RoutingKey::user("userFills", fills.data.user),
+            Self::UserFundings(fundings) => {
+                RoutingKey::user("userFundings", fundings.data.user)
+            }
+            Self::UserNonFundingLedgerUpdates(updates) => {
+                RoutingKey::user("userNonFundingLedgerUpdates", updates.data.user)
+            }
+            Self::Notification(_) => RoutingKey::channel("notification"),
+            Self::WebData2(data) => RoutingKey::user("webData2", data.data.user),
+            Self::User(_) => RoutingKey::channel("user"),
+            // Post responses are matched by request id, not by subscription
+            Self::SubscriptionResponse | Self::Pong | Self::Post(_) => return None,
+            Self::Bbo(bbo) => RoutingKey::coin("bbo", &bbo.data.coin),
+            Self::OpenOrders(orders) => RoutingKey::user("openOrders", orders.data.user),
+            Self::ClearinghouseState(state) => {
+                RoutingKey::user("clearinghouseState", state.data.user)
+            }
+            Self::WebData3(data) => RoutingKey::user("webData3", data.data.user),
+            Self::TwapStates(states) => RoutingKey::user("twapStates", states.data.user),
+            Self::ActiveAssetCtx(ctx) => {
+                RoutingKey::coin("activeAssetCtx", &ctx.data.coin)
+            }
+            Self::ActiveAssetData(data) => RoutingKey {
+                user: Some(data.data.user),
+                ..RoutingKey::coin("activeAssetData", &data.data.coin)
+            },
+            Self::UserTwapSliceFills(fills) => {
+                RoutingKey::user("userTwapSliceFills", fills.data.user)
+            }
+            Self::UserTwapHistory(history) => {
+                RoutingKey::user("userTwapHistory", history.data.user)
+            }
+        };
+        Some(key)
+    }
+}
+
 // ==================== Phase 1 New Message Types ====================
 
 /// Best bid/offer update
@@ -362,8 +594,8 @@ pub struct BboLevel {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct PriceLevel {
-    pub px: String,
-    pub sz: String,
+    pub px: Num,
+    pub sz: Num,
 }
 
 /// Real-time open orders
@@ -392,17 +624,17 @@ pub struct ClearinghouseStateWsData {
     pub user: Address,
     pub margin_summary: MarginSummaryWs,
     pub cross_margin_summary: MarginSummaryWs,
-    pub withdrawable: String,
+    pub withdrawable: Num,
     pub asset_positions: Vec<AssetPositionWs>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarginSummaryWs {
-    pub account_value: String,
-    pub total_margin_used: String,
-    pub total_ntl_pos: String,
-    pub total_raw_usd: String,
+    pub account_value: Num,
+    pub total_margin_used: Num,
+    pub total_ntl_pos: Num,
+    pub total_raw_usd: Num,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -417,13 +649,15 @@ pub struct AssetPositionWs {
 #[serde(rename_all = "camelCase")]
 pub struct PositionWs {
     pub coin: String,
-    pub entry_px: Option<String>,
-    pub liquidation_px: Option<String>,
-    pub margin_used: String,
-    pub position_value: String,
-    pub return_on_equity: String,
-    pub szi: String,
-    pub unrealized_pnl: String,
+    pub entry_px: Option<Num>,
+    #[serde(default)]
+    pub leverage: Option<Leverage>,
+    pub liquidation_px: Option<Num>,
+    pub margin_used: Num,
+    pub position_value: Num,
+    pub return_on_equity: Num,
+    pub szi: Num,
+    pub unrealized_pnl: Num,
 }
 
 // ==================== Phase 2 New Message Types ====================
@@ -471,8 +705,8 @@ pub struct TwapState {
     pub twap_id: u64,
     pub coin: String,
     pub side: String,
-    pub sz: String,
-    pub sz_filled: String,
+    pub sz: Num,
+    pub sz_filled: Num,
     pub duration_minutes: u32,
     pub start_time: u64,
     pub end_time: u64,
@@ -497,15 +731,15 @@ pub struct ActiveAssetCtxData {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetCtx {
-    pub funding: String,
-    pub open_interest: String,
-    pub prev_day_px: String,
-    pub day_ntl_vlm: String,
-    pub premium: Option<String>,
-    pub oracle_px: String,
-    pub mark_px: String,
-    pub mid_px: Option<String>,
-    pub impact_pxs: Option<Vec<String>>,
+    pub funding: Num,
+    pub open_interest: Num,
+    pub prev_day_px: Num,
+    pub day_ntl_vlm: Num,
+    pub premium: Option<Num>,
+    pub oracle_px: Num,
+    pub mark_px: Num,
+    pub mid_px: Option<Num>,
+    pub impact_pxs: Option<Vec<Num>>,
 }
 
 /// Active asset data (perps only)
@@ -521,7 +755,7 @@ pub struct ActiveAssetDataData {
     pub coin: String,
     pub leverage: LeverageWs,
     #[serde(default)]
-    pub max_trade_szs: Option<Vec<String>>,
+    pub max_trade_szs: Option<Vec<Num>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -531,7 +765,7 @@ pub struct LeverageWs {
     pub leverage_type: String,
     pub value: u32,
     #[serde(default)]
-    pub raw_usd: Option<String>,
+    pub raw_usd: Option<Num>,
 }
 
 /// User TWAP slice fills
@@ -555,10 +789,10 @@ pub struct TwapSliceFill {
     pub twap_id: u64,
     pub coin: String,
     pub side: String,
-    pub px: String,
-    pub sz: String,
+    pub px: Num,
+    pub sz: Num,
     pub time: u64,
-    pub fee: String,
+    pub fee: Num,
     pub oid: u64,
     pub hash: String,
 }
@@ -584,9 +818,9 @@ pub struct TwapHistoryEntry {
     pub twap_id: u64,
     pub coin: String,
     pub side: String,
-    pub sz: String,
-    pub sz_filled: String,
-    pub avg_px: Option<String>,
+    pub sz: Num,
+    pub sz_filled: Num,
+    pub avg_px: Option<Num>,
     pub duration_minutes: u32,
     pub start_time: u64,
     pub end_time: u64,
@@ -94,3 +94,11 @@ macro_rules! l1_action {
         }
     };
 }
+
+/// Current time in milliseconds since the Unix epoch
+pub(crate) fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time before UNIX epoch")
+        .as_millis() as u64
+}
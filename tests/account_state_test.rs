@@ -0,0 +1,171 @@
+//! Tests for the account state mirror
+//!
+//! Tests cover:
+//! - Building a snapshot from clearinghouseState, openOrders and spot balance
+//!   responses, keeping the exact amounts
+//! - Applying clearinghouseState, openOrders and webData3 messages
+//! - Ignoring other users' messages and leaving the snapshot untouched on bad data
+
+use alloy::primitives::{address, Address};
+use hyperliquid_sdk_rs::{
+    providers::AccountSnapshot,
+    types::{
+        info_types::{OpenOrdersResponse, UserStateResponse, UserTokenBalanceResponse},
+        ws::Message,
+    },
+};
+use serde_json::{json, Value};
+
+const USER: Address = address!("1111111111111111111111111111111111111111");
+const OTHER: Address = address!("2222222222222222222222222222222222222222");
+
+fn margin(account_value: &str) -> Value {
+    json!({
+        "accountValue": account_value, "totalMarginUsed": "150",
+        "totalNtlPos": "1500", "totalRawUsd": "8500",
+    })
+}
+
+fn position(coin: &str, szi: &str, leverage: &str, margin_used: &str) -> Value {
+    json!({
+        "type": "oneWay",
+        "position": {
+            "coin": coin, "entryPx": "3000", "liquidationPx": null,
+            "leverage": {"type": leverage, "value": 10, "rawUsd": null},
+            "marginUsed": margin_used, "positionValue": "1500",
+            "returnOnEquity": "0", "szi": szi, "unrealizedPnl": "0",
+            "maxLeverage": 50,
+            "cumFunding": {"allTime": "0", "sinceOpen": "0", "sinceChange": "0"},
+        }
+    })
+}
+
+fn user_state() -> Value {
+    json!({
+        "assetPositions": [
+            position("ETH", "0.5", "cross", "150"),
+            position("SOL", "-10", "isolated", "40"),
+        ],
+        "crossMarginSummary": margin("9960"),
+        "marginSummary": margin("10000"),
+        "withdrawable": "9800",
+        "crossMaintenanceMarginUsed": "10",
+        "time": 1,
+    })
+}
+
+fn open_order(oid: u64, sz: &str) -> Value {
+    json!({
+        "coin": "ETH", "side": "B", "limitPx": "2900", "sz": sz, "oid": oid,
+        "timestamp": 1, "origSz": "1", "cloid": null,
+    })
+}
+
+fn spot_balances(usdc: &str) -> Value {
+    json!({"balances": [{"coin": "USDC", "hold": "0", "total": usdc, "entryNtl": "0"}]})
+}
+
+fn message(value: Value) -> Message {
+    serde_json::from_value(value).unwrap()
+}
+
+fn clearinghouse_message(user: Address, withdrawable: &str) -> Message {
+    let mut data = user_state();
+    data["user"] = json!(user);
+    data["withdrawable"] = json!(withdrawable);
+    message(json!({"channel": "clearinghouseState", "data": data}))
+}
+
+fn bootstrapped() -> AccountSnapshot {
+    let mut snapshot = AccountSnapshot::new(USER);
+    let state: UserStateResponse = serde_json::from_value(user_state()).unwrap();
+    let orders: Vec<OpenOrdersResponse> =
+        serde_json::from_value(json!([open_order(1, "1")])).unwrap();
+    let balances: UserTokenBalanceResponse =
+        serde_json::from_value(spot_balances("250")).unwrap();
+    snapshot.apply_user_state(&state).unwrap();
+    snapshot.apply_open_orders(&orders).unwrap();
+    snapshot.apply_spot_balances(&balances).unwrap();
+    snapshot
+}
+
+#[test]
+fn test_snapshot_from_info_responses() {
+    let snapshot = bootstrapped();
+    assert!(!snapshot.synced);
+    assert_eq!(snapshot.positions.len(), 2);
+    assert_eq!(snapshot.margin.account_value, "10000");
+    assert_eq!(snapshot.cross_margin.account_value, "9960");
+    assert_eq!(snapshot.withdrawable, "9800");
+    assert_eq!(snapshot.isolated_margin_used(), 40.0);
+
+    let short = snapshot.position("SOL").unwrap();
+    assert_eq!(short.szi, "-10");
+    assert_eq!(short.szi.to_f64(), -10.0);
+    assert!(!short.leverage.unwrap().is_cross);
+    assert_eq!(snapshot.open_orders[0].orig_sz, Some("1".into()));
+    assert_eq!(snapshot.spot_balance("USDC").unwrap().total, "250");
+}
+
+#[test]
+fn test_snapshot_applies_messages() {
+    let mut snapshot = bootstrapped();
+
+    assert!(snapshot
+        .apply_message(&clearinghouse_message(USER, "9000"))
+        .unwrap());
+    assert_eq!(snapshot.withdrawable, "9000");
+    assert_eq!(snapshot.position("ETH").unwrap().margin_used, "150");
+
+    let orders = message(json!({
+        "channel": "openOrders",
+        "data": {"user": USER, "orders": [open_order(2, "0.5"), open_order(3, "1")]},
+    }));
+    assert!(snapshot.apply_message(&orders).unwrap());
+    assert_eq!(snapshot.open_orders.len(), 2);
+    assert_eq!(snapshot.open_orders[0].sz, "0.5");
+
+    // webData3 carries both in one update
+    let mut state = user_state();
+    state["user"] = json!(USER);
+    state["assetPositions"] = json!([]);
+    let web_data = message(json!({
+        "channel": "webData3",
+        "data": {"user": USER, "clearinghouseState": state, "openOrders": []},
+    }));
+    assert!(snapshot.apply_message(&web_data).unwrap());
+    assert!(snapshot.positions.is_empty());
+    assert!(snapshot.open_orders.is_empty());
+    // Spot balances are not part of these feeds
+    assert_eq!(snapshot.spot_balances.len(), 1);
+}
+
+#[test]
+fn test_snapshot_ignores_other_users() {
+    let mut snapshot = bootstrapped();
+    let before = snapshot.clone();
+
+    assert!(!snapshot
+        .apply_message(&clearinghouse_message(OTHER, "1"))
+        .unwrap());
+    assert_eq!(snapshot, before);
+}
+
+#[test]
+fn test_snapshot_rejects_invalid_message_atomically() {
+    let mut snapshot = bootstrapped();
+    let before = snapshot.clone();
+
+    // A bad number in the orders half must not leave the state half applied
+    let mut state = user_state();
+    state["user"] = json!(USER);
+    state["withdrawable"] = json!("1");
+    let mut order = open_order(4, "1");
+    order["limitPx"] = json!("not a number");
+    let web_data = message(json!({
+        "channel": "webData3",
+        "data": {"user": USER, "clearinghouseState": state, "openOrders": [order]},
+    }));
+    assert!(snapshot.apply_message(&web_data).is_err());
+    assert_eq!(snapshot, before);
+}
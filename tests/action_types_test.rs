@@ -16,7 +16,7 @@ mod tests {
     };
     use hyperliquid_sdk_rs::types::eip712::HyperliquidAction;
     use hyperliquid_sdk_rs::types::requests::{
-        CancelRequest, ModifyRequest, OrderRequest,
+        CancelRequest, Grouping, ModifyRequest, OrderRequest,
     };
 
     // ==================== UsdSend Tests ====================
@@ -364,7 +364,7 @@ mod tests {
 
         let action = BulkOrder {
             orders,
-            grouping: "na".to_string(),
+            grouping: Grouping::Na,
             builder: None,
         };
 
@@ -387,7 +387,7 @@ mod tests {
 
         let action = BulkOrder {
             orders,
-            grouping: "na".to_string(),
+            grouping: Grouping::Na,
             builder: None,
         };
 
@@ -0,0 +1,201 @@
+//! Tests for agent wallet signing in ManagedExchangeProvider
+//!
+//! Tests cover:
+//! - Approving an agent on first use and signing orders with it
+//! - Rotating agents and signing with the replacement
+//! - Reusing persisted agent keys after a restart
+//! - Failed agent approvals
+
+use std::{sync::Arc, time::Duration};
+
+use alloy::signers::local::PrivateKeySigner;
+use hyperliquid_sdk_rs::{
+    providers::{
+        agent::AgentConfig, ManagedExchangeProvider, MockTransport, OrderHandle,
+        SignedAction,
+    },
+    signers::verify,
+    types::requests::OrderRequest,
+    HyperliquidError, Network,
+};
+use serde_json::{json, Value};
+
+fn transport() -> Arc<MockTransport> {
+    Arc::new(
+        MockTransport::new()
+            .with_exchange_response(
+                "approveAgent",
+                json!({"status": "ok", "response": {"type": "default"}}),
+            )
+            .with_exchange_response(
+                "order",
+                json!({"status": "ok", "response": {"type": "order", "data": {"statuses": [
+                    {"resting": {"oid": 1}}
+                ]}}}),
+            ),
+    )
+}
+
+fn order() -> OrderRequest {
+    OrderRequest::limit(0, true, "50000", "0.01", "Gtc")
+}
+
+/// Approved agent addresses and the (action type, recovered signer) of each order
+fn actions(transport: &MockTransport) -> (Vec<Value>, Vec<(Value, Value)>) {
+    let mut approvals = Vec::new();
+    let mut orders = Vec::new();
+    for request in transport.requests() {
+        let action = &request.body["action"];
+        if action["type"] == "approveAgent" {
+            approvals.push(action["agentAddress"].clone());
+        } else {
+            let signed: SignedAction =
+                serde_json::from_value(request.body.clone()).unwrap();
+            let signer = verify::recover_signer(&signed, &Network::Testnet).unwrap();
+            orders.push((action["type"].clone(), json!(format!("{:#x}", signer))));
+        }
+    }
+    (approvals, orders)
+}
+
+fn key_file(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "hyperliquid-agents-{}-{}.json",
+        name,
+        std::process::id()
+    ))
+}
+
+#[tokio::test]
+async fn test_orders_are_signed_by_approved_agent() {
+    let transport = transport();
+    let exchange = ManagedExchangeProvider::builder(PrivateKeySigner::random())
+        .with_network(Network::Testnet)
+        .with_transport(transport.clone())
+        .build()
+        .await
+        .unwrap();
+
+    for _ in 0..2 {
+        let handle = exchange.place_order(&order()).await.unwrap();
+        assert!(matches!(handle, OrderHandle::Immediate(Ok(_))));
+    }
+
+    let (approvals, orders) = actions(&transport);
+    assert_eq!(approvals.len(), 1);
+    assert_eq!(orders.len(), 2);
+    // Agents sign the plain order action themselves
+    for (action_type, signer) in orders {
+        assert_eq!(action_type, "order");
+        assert_eq!(signer, approvals[0]);
+    }
+
+    let status = exchange.get_agent_status().await.unwrap();
+    assert_eq!(status.len(), 1);
+    assert_eq!(status[0].0, "default");
+    assert_eq!(
+        format!("{:#x}", status[0].1.address),
+        approvals[0].as_str().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_expired_agent_is_replaced() {
+    let transport = transport();
+    let exchange = ManagedExchangeProvider::builder(PrivateKeySigner::random())
+        .with_network(Network::Testnet)
+        .with_transport(transport.clone())
+        .with_agent_config(AgentConfig {
+            ttl: Duration::from_millis(50),
+            proactive_rotation_buffer: Duration::ZERO,
+            ..AgentConfig::default()
+        })
+        .with_agent(Some("bot".to_string()))
+        .build()
+        .await
+        .unwrap();
+
+    exchange.place_order_immediate(&order()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    exchange.place_order_immediate(&order()).await.unwrap();
+
+    let (approvals, orders) = actions(&transport);
+    assert_eq!(approvals.len(), 2);
+    assert_ne!(approvals[0], approvals[1]);
+    assert_eq!(orders[0].1, approvals[0]);
+    assert_eq!(orders[1].1, approvals[1]);
+}
+
+#[tokio::test]
+async fn test_persisted_agent_survives_restart() {
+    let path = key_file("restart");
+    let _ = std::fs::remove_file(&path);
+    let signer = PrivateKeySigner::random();
+
+    let first = transport();
+    let exchange = ManagedExchangeProvider::builder(signer.clone())
+        .with_network(Network::Testnet)
+        .with_transport(first.clone())
+        .with_agent_key_file(&path)
+        .build()
+        .await
+        .unwrap();
+    exchange.place_order_immediate(&order()).await.unwrap();
+    exchange.shutdown().await;
+
+    let second = transport();
+    let restarted = ManagedExchangeProvider::builder(signer)
+        .with_network(Network::Testnet)
+        .with_transport(second.clone())
+        .with_agent_key_file(&path)
+        .build()
+        .await
+        .unwrap();
+    restarted.place_order_immediate(&order()).await.unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    let (approvals, _) = actions(&first);
+    let (reapprovals, orders) = actions(&second);
+    assert_eq!(approvals.len(), 1);
+    assert!(reapprovals.is_empty());
+    assert_eq!(orders[0].1, approvals[0]);
+}
+
+#[tokio::test]
+async fn test_rejected_approval_sends_no_order() {
+    let transport = Arc::new(MockTransport::new().with_exchange_response(
+        "approveAgent",
+        json!({"status": "err", "response": "User or API Wallet does not exist."}),
+    ));
+    let exchange = ManagedExchangeProvider::builder(PrivateKeySigner::random())
+        .with_network(Network::Testnet)
+        .with_transport(transport.clone())
+        .build()
+        .await
+        .unwrap();
+
+    let result = exchange.place_order(&order()).await;
+    assert!(matches!(result, Err(HyperliquidError::Rejected { .. })));
+    let (approvals, orders) = actions(&transport);
+    assert_eq!(approvals.len(), 1);
+    assert!(orders.is_empty());
+}
+
+#[tokio::test]
+async fn test_without_agents_master_signs() {
+    let transport = transport();
+    let master = PrivateKeySigner::random();
+    let exchange = ManagedExchangeProvider::builder(master.clone())
+        .with_network(Network::Testnet)
+        .with_transport(transport.clone())
+        .without_agent_rotation()
+        .build()
+        .await
+        .unwrap();
+
+    exchange.place_order_immediate(&order()).await.unwrap();
+    let (approvals, orders) = actions(&transport);
+    assert!(approvals.is_empty());
+    assert_eq!(orders[0].0, "order");
+    assert_eq!(orders[0].1, json!(format!("{:#x}", master.address())));
+}
@@ -0,0 +1,247 @@
+//! Tests for the asset registry
+//!
+//! Tests cover:
+//! - Asset ids for perps, spot pairs and builder-deployed perp DEXs
+//! - Symbol aliases and trading rules
+//! - Lazy loading, staleness and unknown-symbol refreshes
+//! - Retry cooldown after a failed reload
+//! - Fixed registries built from metadata
+//! - Order builders and info requests resolving symbols
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use alloy::signers::local::PrivateKeySigner;
+use hyperliquid_sdk_rs::{
+    providers::{AssetKind, AssetRegistry, MockTransport, RawExchangeProvider},
+    types::info_types::{Meta, SpotMeta},
+    HyperliquidError, InfoProvider, Network,
+};
+use serde_json::{json, Value};
+
+fn meta() -> Value {
+    json!({
+        "universe": [
+            {"name": "BTC", "szDecimals": 5, "maxLeverage": 50},
+            {"name": "ETH", "szDecimals": 4, "maxLeverage": 25},
+        ]
+    })
+}
+
+fn spot_meta() -> Value {
+    json!({
+        "universe": [
+            {"name": "PURR/USDC", "tokens": [1, 0], "index": 0, "isCanonical": true},
+            {"name": "@107", "tokens": [150, 0], "index": 107, "isCanonical": false},
+        ],
+        "tokens": [
+            {"name": "USDC", "szDecimals": 8, "weiDecimals": 8, "index": 0,
+             "tokenId": "0x6d1e7cde53ba9467b783cb7c530ce054", "isCanonical": true},
+            {"name": "PURR", "szDecimals": 0, "weiDecimals": 5, "index": 1,
+             "tokenId": "0xc1fb593aeffbeb02f85e0308e9956a90", "isCanonical": true},
+            {"name": "HYPE", "szDecimals": 2, "weiDecimals": 8, "index": 150,
+             "tokenId": "0x0d01dc56dcaaca66ad901c959b4011ec", "isCanonical": false},
+        ]
+    })
+}
+
+fn respond(body: &Value) -> Option<(u16, Value)> {
+    let response = match (body["type"].as_str(), body["dex"].as_str()) {
+        (Some("meta"), None) => meta(),
+        (Some("meta"), Some("xyz")) => json!({
+            "universe": [
+                {"name": "xyz:GOLD", "szDecimals": 2, "maxLeverage": 10,
+                 "onlyIsolated": true},
+            ]
+        }),
+        (Some("spotMeta"), _) => spot_meta(),
+        (Some("perpDexs"), _) => json!([
+            null,
+            {"name": "xyz", "fullName": "XYZ Markets",
+             "deployer": "0x0000000000000000000000000000000000000001"},
+        ]),
+        _ => return None,
+    };
+    Some((200, response))
+}
+
+fn transport() -> Arc<MockTransport> {
+    Arc::new(MockTransport::new().with_handler(|_, body| respond(body)))
+}
+
+fn registry(transport: Arc<MockTransport>) -> AssetRegistry {
+    AssetRegistry::new(InfoProvider::new(Network::Testnet).with_transport(transport))
+}
+
+fn fixed_registry() -> AssetRegistry {
+    let meta: Meta = serde_json::from_value(meta()).unwrap();
+    let spot_meta: SpotMeta = serde_json::from_value(spot_meta()).unwrap();
+    AssetRegistry::from_meta(&meta, &spot_meta)
+}
+
+#[tokio::test]
+async fn test_refresh_assigns_asset_ids() {
+    let registry = registry(transport());
+    registry.refresh().await.unwrap();
+
+    assert_eq!(registry.len(), 5);
+    assert_eq!(registry.get("BTC").unwrap().asset, 0);
+    assert_eq!(registry.get("ETH").unwrap().asset, 1);
+    assert_eq!(registry.get("PURR/USDC").unwrap().asset, 10_000);
+    assert_eq!(registry.get("@107").unwrap().asset, 10_107);
+
+    let gold = registry.get("xyz:GOLD").unwrap();
+    assert_eq!(gold.asset, 110_000);
+    assert_eq!(gold.dex.as_deref(), Some("xyz"));
+    assert!(gold.only_isolated);
+    assert_eq!(gold.max_leverage, Some(10));
+}
+
+#[tokio::test]
+async fn test_spot_aliases_and_rules() {
+    let registry = registry(transport());
+    registry.refresh().await.unwrap();
+
+    let hype = registry.get("HYPE/USDC").unwrap();
+    assert_eq!(hype, registry.get("@107").unwrap());
+    assert_eq!(hype.coin, "@107");
+    assert_eq!(hype.kind, AssetKind::Spot);
+    assert_eq!(hype.sz_decimals, 2);
+    assert_eq!(hype.max_leverage, None);
+    assert_eq!(hype.max_price_decimals(), 6);
+
+    assert_eq!(registry.get("@0").unwrap().coin, "PURR/USDC");
+    assert_eq!(registry.get("BTC").unwrap().max_price_decimals(), 1);
+    assert_eq!(registry.by_asset(10_107).unwrap().coin, "@107");
+    assert!(registry.by_asset(42).is_none());
+}
+
+#[tokio::test]
+async fn test_resolve_loads_lazily() {
+    let transport = transport();
+    let registry = registry(transport.clone());
+    assert!(registry.is_empty());
+    assert!(registry.get("ETH").is_none());
+
+    let eth = registry.resolve("ETH").await.unwrap();
+    assert_eq!(eth.asset, 1);
+    // meta, spotMeta, perpDexs and the builder DEX's meta
+    assert_eq!(transport.requests().len(), 4);
+
+    registry.resolve("BTC").await.unwrap();
+    assert_eq!(transport.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn test_unknown_symbol_does_not_refetch_immediately() {
+    let transport = transport();
+    let registry = registry(transport.clone());
+
+    let missing = registry.resolve("DOGE").await;
+    assert!(matches!(missing, Err(HyperliquidError::AssetNotFound(s)) if s == "DOGE"));
+    assert!(registry.resolve("DOGE").await.is_err());
+    assert_eq!(transport.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn test_stale_registry_refreshes() {
+    let transport = transport();
+    let registry =
+        registry(transport.clone()).with_refresh_interval(Some(Duration::ZERO));
+
+    registry.resolve("BTC").await.unwrap();
+    registry.resolve("BTC").await.unwrap();
+    assert_eq!(transport.requests().len(), 8);
+}
+
+#[tokio::test]
+async fn test_failed_refresh_is_not_retried_on_every_lookup() {
+    let failing = Arc::new(AtomicBool::new(false));
+    let transport = {
+        let failing = failing.clone();
+        Arc::new(MockTransport::new().with_handler(move |_, body| {
+            if failing.load(Ordering::SeqCst) {
+                return Some((500, json!("unavailable")));
+            }
+            respond(body)
+        }))
+    };
+    let registry =
+        registry(transport.clone()).with_refresh_interval(Some(Duration::ZERO));
+    registry.resolve("BTC").await.unwrap();
+    assert_eq!(transport.requests().len(), 4);
+
+    // The failed reload falls back to the cached mapping and is not retried
+    // by the following lookups
+    failing.store(true, Ordering::SeqCst);
+    assert_eq!(registry.resolve("BTC").await.unwrap().asset, 0);
+    assert!(transport.requests().len() > 4);
+    let after_failure = transport.requests().len();
+    registry.resolve("ETH").await.unwrap();
+    registry.resolve_id(0).await.unwrap();
+    assert!(registry.resolve("DOGE").await.is_err());
+    assert_eq!(transport.requests().len(), after_failure);
+}
+
+#[tokio::test]
+async fn test_fixed_registry() {
+    let registry = fixed_registry();
+
+    assert_eq!(registry.resolve("HYPE/USDC").await.unwrap().asset, 10_107);
+    assert!(registry.get("xyz:GOLD").is_none());
+    assert!(matches!(
+        registry.refresh().await,
+        Err(HyperliquidError::InvalidRequest(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_info_provider_resolves_coins() {
+    let transport = transport();
+    let info = InfoProvider::new(Network::Testnet).with_transport(transport.clone());
+    let info = info
+        .clone()
+        .with_asset_registry(Arc::new(AssetRegistry::new(info)));
+
+    // Only the request is of interest here
+    let _ = info.recent_trades("HYPE/USDC").await;
+    let _ = info.l2_book("BTC").await;
+    let coins: Vec<Value> = transport
+        .requests()
+        .into_iter()
+        .filter(|request| request.body.get("coin").is_some())
+        .map(|request| request.body["coin"].clone())
+        .collect();
+    assert_eq!(coins, vec![json!("@107"), json!("BTC")]);
+
+    assert!(matches!(
+        info.recent_trades("DOGE").await,
+        Err(HyperliquidError::AssetNotFound(_))
+    ));
+}
+
+#[test]
+fn test_order_builder_resolves_symbols() {
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random());
+    let without_registry = exchange.order_for("BTC").limit_buy("50000", "0.1").build();
+    assert!(matches!(
+        without_registry,
+        Err(HyperliquidError::InvalidRequest(_))
+    ));
+
+    let exchange = exchange.with_asset_registry(Arc::new(fixed_registry()));
+    let order = exchange
+        .order_for("HYPE/USDC")
+        .limit_sell("25", "3")
+        .build()
+        .unwrap();
+    assert_eq!(order.asset, 10_107);
+
+    let unknown = exchange.order_for("DOGE").limit_buy("1", "1").build();
+    assert!(matches!(unknown, Err(HyperliquidError::AssetNotFound(_))));
+}
@@ -0,0 +1,260 @@
+//! Tests for candle intervals and series
+//!
+//! Tests cover:
+//! - CandleInterval wire names, parsing and the candles request builder
+//! - Seeding from candleSnapshot and following candle updates
+//! - Rolling trades into custom periods, seeded from shorter candles
+//! - Closing bars once their period has passed and re-emitting late amendments
+
+use std::{sync::Arc, time::Duration};
+
+use hyperliquid_sdk_rs::{
+    providers::{BarPeriod, CandleSeries, MockTransport},
+    types::{
+        info_types::{CandleInterval, CandlesSnapshotResponse},
+        ws::{Message, Subscription},
+    },
+    InfoProvider, Network,
+};
+use serde_json::{json, Value};
+
+const MINUTE: u64 = 60_000;
+
+fn candle(interval: &str, time_open: u64, length: u64, close: &str) -> Value {
+    json!({
+        "t": time_open, "T": time_open + length - 1, "s": "BTC", "i": interval,
+        "o": "100", "c": close, "h": "110", "l": "90", "v": "2", "n": 4,
+    })
+}
+
+fn snapshot(candles: Vec<Value>) -> Vec<CandlesSnapshotResponse> {
+    serde_json::from_value(Value::Array(candles)).unwrap()
+}
+
+fn candle_message(time_open: u64, close: &str) -> Message {
+    serde_json::from_value(json!({
+        "channel": "candle",
+        "data": candle("1m", time_open, MINUTE, close),
+    }))
+    .unwrap()
+}
+
+fn trades(trades: &[(u64, &str, &str, u64)]) -> Message {
+    let data: Vec<Value> = trades
+        .iter()
+        .map(|(time, px, sz, tid)| {
+            json!({
+                "coin": "BTC", "side": "B", "px": px, "sz": sz, "time": time,
+                "hash": "0x0", "tid": tid,
+            })
+        })
+        .collect();
+    serde_json::from_value(json!({"channel": "trades", "data": data})).unwrap()
+}
+
+#[test]
+fn test_candle_interval_names() {
+    for interval in CandleInterval::ALL {
+        let wire = serde_json::to_value(interval).unwrap();
+        assert_eq!(wire, interval.as_str());
+        assert_eq!(
+            interval.as_str().parse::<CandleInterval>().unwrap(),
+            interval
+        );
+    }
+    assert_eq!(CandleInterval::OneMonth.to_string(), "1M");
+    assert_eq!(
+        CandleInterval::FifteenMinutes.duration(),
+        Duration::from_secs(900)
+    );
+    assert!("2m".parse::<CandleInterval>().is_err());
+
+    let subscription = Subscription::Candle {
+        coin: "BTC".to_string(),
+        interval: CandleInterval::OneHour,
+    };
+    assert_eq!(
+        serde_json::to_value(&subscription).unwrap(),
+        json!({"type": "candle", "coin": "BTC", "interval": "1h"})
+    );
+}
+
+#[test]
+fn test_series_follows_candle_updates() {
+    let mut series = CandleSeries::new("BTC", CandleInterval::OneMinute).with_max_bars(2);
+    series
+        .seed(&snapshot(vec![
+            candle("1m", 0, MINUTE, "101"),
+            candle("1m", MINUTE, MINUTE, "102"),
+            candle("1m", 2 * MINUTE, MINUTE, "103"),
+        ]))
+        .unwrap();
+    assert_eq!(series.len(), 2);
+    assert_eq!(series.bars()[0].close, 101.0);
+    assert_eq!(series.current().unwrap().close, 103.0);
+
+    // Updates to the forming bar replace it
+    let closed = series
+        .apply_message(&candle_message(2 * MINUTE, "104"))
+        .unwrap();
+    assert!(closed.is_empty());
+    assert_eq!(series.current().unwrap().close, 104.0);
+
+    // The next candle closes it
+    let closed = series
+        .apply_message(&candle_message(3 * MINUTE, "105"))
+        .unwrap();
+    assert_eq!(closed.len(), 1);
+    assert_eq!(closed[0].time_open, 2 * MINUTE);
+    assert_eq!(closed[0].close, 104.0);
+    assert_eq!(series.len(), 2);
+    assert_eq!(series.bars()[0].close, 102.0);
+
+    // Late updates amend the closed bar and return it again, stale ones are
+    // dropped
+    let amended = series
+        .apply_message(&candle_message(2 * MINUTE, "106"))
+        .unwrap();
+    assert_eq!(amended.len(), 1);
+    assert_eq!(amended[0].time_open, 2 * MINUTE);
+    assert_eq!(amended[0].close, 106.0);
+    assert_eq!(series.last_closed().unwrap().close, 106.0);
+    assert!(series
+        .apply_message(&candle_message(2 * MINUTE, "106"))
+        .unwrap()
+        .is_empty());
+    assert!(series
+        .apply_message(&candle_message(0, "1"))
+        .unwrap()
+        .is_empty());
+    assert_eq!(series.bars()[0].close, 102.0);
+
+    // Trades do not feed exchange candles
+    assert!(series
+        .apply_message(&trades(&[(4 * MINUTE, "1", "1", 1)]))
+        .unwrap()
+        .is_empty());
+    assert_eq!(series.current().unwrap().time_open, 3 * MINUTE);
+}
+
+#[test]
+fn test_custom_series_rolls_trades() {
+    let mut series = CandleSeries::custom("BTC", Duration::from_secs(10)).unwrap();
+    assert_eq!(series.period(), BarPeriod::Custom(Duration::from_secs(10)));
+
+    let closed = series
+        .apply_message(&trades(&[
+            (1_000, "100", "1", 1),
+            (4_000, "105", "2", 2),
+            (4_000, "105", "2", 2),
+            (9_999, "95", "1", 3),
+        ]))
+        .unwrap();
+    assert!(closed.is_empty());
+    let bar = *series.current().unwrap();
+    assert_eq!((bar.time_open, bar.time_close), (0, 9_999));
+    assert_eq!(
+        (bar.open, bar.high, bar.low, bar.close),
+        (100.0, 105.0, 95.0, 95.0)
+    );
+    assert_eq!(bar.volume, 4.0);
+    assert_eq!(bar.num_trades, 3);
+
+    let closed = series
+        .apply_message(&trades(&[(25_000, "101", "1", 4), (5_000, "1", "1", 5)]))
+        .unwrap();
+    assert_eq!(closed, vec![bar]);
+    assert_eq!(series.current().unwrap().time_open, 20_000);
+    assert_eq!(series.len(), 1);
+
+    // A quiet market still closes the bar once its period is over
+    assert!(series.close_expired(29_999).is_none());
+    assert_eq!(series.close_expired(30_000).unwrap().time_open, 20_000);
+    assert!(series.current().is_none());
+    assert!(series
+        .apply_message(&trades(&[(29_000, "1", "1", 6)]))
+        .unwrap()
+        .is_empty());
+    assert!(series.current().is_none());
+
+    assert!(CandleSeries::custom("BTC", Duration::ZERO).is_err());
+    assert!(CandleSeries::custom("BTC", Duration::from_micros(1_500)).is_err());
+}
+
+#[test]
+fn test_custom_series_seeds_from_shorter_candles() {
+    let mut series = CandleSeries::custom("BTC", Duration::from_secs(600)).unwrap();
+    let five = 5 * MINUTE;
+    series
+        .seed(&snapshot(vec![
+            candle("5m", 0, five, "101"),
+            candle("5m", five, five, "102"),
+            candle("5m", 2 * five, five, "103"),
+            candle("5m", 3 * five, five, "104"),
+        ]))
+        .unwrap();
+
+    // 0-10m is closed; 10-20m is still forming along with the 15m candle
+    assert_eq!(series.len(), 1);
+    let bar = series.bars()[0];
+    assert_eq!((bar.time_open, bar.time_close), (0, 2 * five - 1));
+    assert_eq!(
+        (bar.open, bar.close, bar.volume, bar.num_trades),
+        (100.0, 102.0, 4.0, 8)
+    );
+    let current = *series.current().unwrap();
+    assert_eq!(current.time_open, 2 * five);
+    assert_eq!(current.close, 103.0);
+
+    // Trades inside the forming candle continue the bar; older ones were counted
+    series
+        .apply_message(&trades(&[
+            (3 * five - 1, "1", "1", 1),
+            (3 * five + 1, "120", "1", 2),
+        ]))
+        .unwrap();
+    let current = series.current().unwrap();
+    assert_eq!(current.high, 120.0);
+    assert_eq!(current.volume, 3.0);
+
+    let mut mismatched = CandleSeries::custom("BTC", Duration::from_secs(420)).unwrap();
+    assert!(mismatched
+        .seed(&snapshot(vec![candle("5m", 0, five, "1")]))
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_load_history_picks_dividing_interval() {
+    let transport = Arc::new(MockTransport::new().with_info_response(
+        "candleSnapshot",
+        json!([
+            candle("30m", 0, 30 * MINUTE, "101"),
+            candle("30m", 30 * MINUTE, 30 * MINUTE, "102"),
+            candle("30m", 60 * MINUTE, 30 * MINUTE, "103"),
+        ]),
+    ));
+    let info = InfoProvider::new(Network::Testnet).with_transport(transport.clone());
+
+    let mut series = CandleSeries::custom("BTC", Duration::from_secs(90 * 60)).unwrap();
+    series
+        .load_history(&info, Duration::from_secs(86_400))
+        .await
+        .unwrap();
+    assert_eq!(series.current().unwrap().close, 102.0);
+
+    let request = &transport.requests()[0].body;
+    assert_eq!(request["req"]["interval"], "30m");
+    assert_eq!(request["req"]["coin"], "BTC");
+    let req = &request["req"];
+    assert_eq!(
+        req["endTime"].as_u64().unwrap() - req["startTime"].as_u64().unwrap(),
+        86_400_000
+    );
+
+    // Seven seconds fits no exchange interval
+    let mut series = CandleSeries::custom("BTC", Duration::from_secs(7)).unwrap();
+    assert!(series
+        .load_history(&info, Duration::from_secs(60))
+        .await
+        .is_err());
+}
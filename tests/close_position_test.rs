@@ -0,0 +1,213 @@
+//! Tests for closing positions
+//!
+//! Tests cover:
+//! - Partial and full closes of a single position
+//! - Flattening every position and cancelling open orders
+//! - Failures reported per position and per order
+//! - Looking up the account an agent key trades for
+
+mod common;
+
+use std::sync::Arc;
+
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
+use common::{exchange, position, registry, user_state, PERPS};
+use hyperliquid_sdk_rs::{
+    providers::{MockTransport, RawExchangeProvider},
+    HyperliquidError,
+};
+use serde_json::{json, Value};
+
+fn open_order(coin: &str, oid: u64) -> Value {
+    json!({"coin": coin, "limitPx": "90", "oid": oid, "side": "B", "sz": "1",
+           "timestamp": 1700000000000u64})
+}
+
+fn statuses(statuses: Value) -> Value {
+    json!({"status": "ok", "response": {"type": "order", "data": {"statuses": statuses}}})
+}
+
+fn transport(positions: Vec<Value>, orders: Vec<Value>) -> MockTransport {
+    MockTransport::new()
+        .with_info_response("clearinghouseState", user_state(positions))
+        .with_info_response("openOrders", Value::Array(orders))
+        .with_info_response(
+            "l2Book",
+            json!({"coin": "ETH", "time": 0, "levels": [
+                [{"px": "3000", "sz": "10", "n": 1}],
+                [{"px": "3001", "sz": "10", "n": 1}],
+            ]}),
+        )
+}
+
+fn actions(transport: &MockTransport, action_type: &str) -> Vec<Value> {
+    transport
+        .requests()
+        .into_iter()
+        .filter(|request| request.body["action"]["type"] == action_type)
+        .map(|request| request.body["action"].clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_partial_close_keeps_open_orders() {
+    let transport = Arc::new(
+        transport(vec![position("ETH", "1.5")], vec![open_order("ETH", 11)])
+            .with_exchange_response(
+                "order",
+                statuses(
+                    json!([{"filled": {"totalSz": "0.5", "avgPx": "3000", "oid": 1}}]),
+                ),
+            ),
+    );
+
+    let report = exchange(&transport)
+        .close_position("ETH", 1.0 / 3.0)
+        .await
+        .unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.closed.len(), 1);
+    assert_eq!(report.closed[0].sz, "0.5");
+    assert_eq!(report.closed[0].fill.avg_px, "3000");
+    assert!(report.cancelled.is_empty());
+
+    let order = &actions(&transport, "order")[0]["orders"][0];
+    assert_eq!(order["a"], 1);
+    assert_eq!(order["b"], false);
+    assert_eq!(order["r"], true);
+    assert_eq!(order["t"]["limit"]["tif"], "Ioc");
+    assert!(actions(&transport, "cancel").is_empty());
+}
+
+#[tokio::test]
+async fn test_full_close_cancels_orders_in_symbol() {
+    let transport = Arc::new(
+        transport(
+            vec![position("ETH", "-2")],
+            vec![open_order("ETH", 11), open_order("BTC", 12)],
+        )
+        .with_exchange_response(
+            "order",
+            statuses(json!([{"filled": {"totalSz": "2", "avgPx": "3001", "oid": 1}}])),
+        )
+        .with_exchange_response("cancel", statuses(json!(["success"]))),
+    );
+
+    let report = exchange(&transport)
+        .close_position("ETH", 1.0)
+        .await
+        .unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.closed[0].sz, "2");
+    assert_eq!(report.cancelled, vec![11]);
+
+    assert_eq!(actions(&transport, "order")[0]["orders"][0]["b"], true);
+    let cancels = &actions(&transport, "cancel")[0]["cancels"];
+    assert_eq!(cancels, &json!([{"a": 1, "o": 11}]));
+}
+
+#[tokio::test]
+async fn test_close_position_errors() {
+    let transport = Arc::new(transport(vec![position("BTC", "0.1")], vec![]));
+    let provider = exchange(&transport);
+
+    let flat = provider.close_position("ETH", 1.0).await;
+    assert!(
+        matches!(flat, Err(HyperliquidError::InvalidRequest(msg)) if msg.contains("ETH"))
+    );
+
+    let bad_fraction = provider.close_position("BTC", 0.0).await;
+    assert!(matches!(
+        bad_fraction,
+        Err(HyperliquidError::InvalidRequest(_))
+    ));
+    assert!(actions(&transport, "order").is_empty());
+}
+
+#[tokio::test]
+async fn test_close_all_positions_reports_each_outcome() {
+    let transport = Arc::new(
+        transport(
+            vec![
+                position("BTC", "0.1"),
+                position("ETH", "-1"),
+                position("DOGE", "100"),
+                position("SOL", "0.0"),
+            ],
+            vec![open_order("BTC", 21), open_order("ETH", 22)],
+        )
+        .with_exchange_response(
+            "order",
+            statuses(json!([
+                {"filled": {"totalSz": "0.1", "avgPx": "2999", "oid": 1}},
+                {"error": "Reduce only order would increase position."},
+            ])),
+        )
+        .with_exchange_response(
+            "cancel",
+            statuses(json!(["success", {"error": "Order was never placed, already canceled, or filled."}])),
+        ),
+    );
+
+    let report = exchange(&transport).close_all_positions().await.unwrap();
+    assert!(!report.is_complete());
+
+    assert_eq!(report.closed.len(), 1);
+    assert_eq!(report.closed[0].coin, "BTC");
+    assert_eq!(report.cancelled, vec![21]);
+
+    let failed: Vec<_> = report
+        .failed
+        .iter()
+        .map(|failure| (failure.coin.as_str(), failure.oid))
+        .collect();
+    assert_eq!(
+        failed,
+        vec![("DOGE", None), ("ETH", None), ("ETH", Some(22))]
+    );
+    assert!(report.failed[1].reason.contains("Reduce only"));
+
+    let orders = &actions(&transport, "order")[0]["orders"];
+    assert_eq!(orders.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_close_all_positions_with_rejected_request() {
+    let transport = Arc::new(
+        transport(vec![position("BTC", "0.1")], vec![]).with_exchange_response(
+            "order",
+            json!({"status": "err", "response": "User or API Wallet does not exist."}),
+        ),
+    );
+
+    let report = exchange(&transport).close_all_positions().await.unwrap();
+    assert!(report.closed.is_empty());
+    assert_eq!(report.failed.len(), 1);
+    assert!(report.failed[0].reason.contains("does not exist"));
+}
+
+#[tokio::test]
+async fn test_agent_closes_positions_of_its_account() {
+    let transport = Arc::new(
+        transport(vec![position("ETH", "2")], vec![]).with_exchange_response(
+            "order",
+            statuses(json!([{"filled": {"totalSz": "2", "avgPx": "3000", "oid": 1}}])),
+        ),
+    );
+    let master = Address::repeat_byte(0x11);
+    let agent = PrivateKeySigner::random();
+    let exchange = RawExchangeProvider::testnet(agent.clone())
+        .with_transport(transport.clone())
+        .with_asset_registry(registry(PERPS))
+        .with_account_address(master);
+    assert_eq!(exchange.account_address(), master);
+    assert_eq!(exchange.signer_address(), agent.address());
+
+    exchange.market_close("ETH", None).await.unwrap();
+    let lookup = transport
+        .requests()
+        .into_iter()
+        .find(|request| request.body["type"] == "clearinghouseState")
+        .unwrap();
+    assert_eq!(lookup.body["user"], json!(master));
+}
@@ -0,0 +1,106 @@
+//! Fixtures shared by the integration tests
+//!
+//! Each test crate uses a different subset of these helpers.
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use alloy::signers::local::PrivateKeySigner;
+use hyperliquid_sdk_rs::{
+    providers::{AssetRegistry, MockTransport, RawExchangeProvider},
+    types::{
+        info_types::{Meta, SpotMeta},
+        ws::Message,
+    },
+};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// BTC and ETH perpetuals, as asset 0 and 1
+pub const PERPS: &[(&str, u32)] = &[("BTC", 5), ("ETH", 4)];
+
+/// A fixed registry of `(coin, szDecimals)` perpetuals, numbered in order.
+pub fn registry(perps: &[(&str, u32)]) -> Arc<AssetRegistry> {
+    let universe: Vec<Value> = perps
+        .iter()
+        .map(|(name, sz_decimals)| {
+            json!({"name": name, "szDecimals": sz_decimals, "maxLeverage": 50})
+        })
+        .collect();
+    let meta: Meta = serde_json::from_value(json!({ "universe": universe })).unwrap();
+    let spot_meta: SpotMeta =
+        serde_json::from_value(json!({"universe": [], "tokens": []})).unwrap();
+    Arc::new(AssetRegistry::from_meta(&meta, &spot_meta))
+}
+
+/// A testnet provider sending through `transport`, with [`PERPS`] registered.
+pub fn exchange(transport: &Arc<MockTransport>) -> RawExchangeProvider<PrivateKeySigner> {
+    RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone())
+        .with_asset_registry(registry(PERPS))
+}
+
+/// One entry of `assetPositions` with signed size `szi`.
+pub fn position(coin: &str, szi: &str) -> Value {
+    json!({
+        "type": "oneWay",
+        "position": {
+            "coin": coin,
+            "entryPx": "100",
+            "leverage": {"type": "cross", "value": 10, "rawUsd": null},
+            "liquidationPx": null,
+            "marginUsed": "10",
+            "positionValue": "100",
+            "returnOnEquity": "0",
+            "szi": szi,
+            "unrealizedPnl": "0",
+            "maxLeverage": 25,
+            "cumFunding": {"allTime": "0", "sinceOpen": "0", "sinceChange": "0"},
+        },
+    })
+}
+
+/// A `clearinghouseState` response holding `positions`.
+pub fn user_state(positions: Vec<Value>) -> Value {
+    let summary = json!({"accountValue": "1000", "totalMarginUsed": "0",
+                         "totalNtlPos": "0", "totalRawUsd": "1000"});
+    json!({
+        "assetPositions": positions,
+        "marginSummary": summary,
+        "crossMarginSummary": summary,
+        "withdrawable": "1000",
+    })
+}
+
+/// The wire form of a client order id.
+pub fn cloid_hex(cloid: Uuid) -> String {
+    format!("0x{:032x}", cloid.as_u128())
+}
+
+/// A `userFills` message with one BTC buy fill.
+pub fn fill(cloid: Option<Uuid>, oid: u64, tid: u64, px: &str, sz: &str) -> Message {
+    let message = json!({
+        "channel": "userFills",
+        "data": {
+            "user": "0x1111111111111111111111111111111111111111",
+            "fills": [{
+                "coin": "BTC",
+                "side": "B",
+                "px": px,
+                "sz": sz,
+                "time": 3,
+                "hash": "0x0",
+                "startPosition": "0",
+                "dir": "Open Long",
+                "closedPnl": "0",
+                "oid": oid,
+                "cloid": cloid.map(cloid_hex),
+                "crossed": true,
+                "fee": "0",
+                "feeToken": "USDC",
+                "tid": tid,
+            }]
+        }
+    });
+    serde_json::from_value(message).unwrap()
+}
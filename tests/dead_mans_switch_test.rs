@@ -0,0 +1,164 @@
+//! Tests for the dead man's switch
+//!
+//! Tests cover:
+//! - Re-arming scheduleCancel on every tick
+//! - Letting the cancel fire while the health check fails, and resuming after
+//! - Backing off when the daily trigger limit is reached
+//! - Disarming on shutdown and rejecting invalid horizons
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use alloy::signers::local::PrivateKeySigner;
+use hyperliquid_sdk_rs::providers::{
+    DeadMansSwitch, DeadMansSwitchBuilder, MockTransport, RawExchangeProvider,
+    SwitchStatus,
+};
+use serde_json::{json, Value};
+
+const TICK: Duration = Duration::from_millis(40);
+
+fn exchange(
+    response: Value,
+) -> (
+    Arc<MockTransport>,
+    Arc<RawExchangeProvider<PrivateKeySigner>>,
+) {
+    let transport =
+        Arc::new(MockTransport::new().with_exchange_response("scheduleCancel", response));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone());
+    (transport, Arc::new(exchange))
+}
+
+fn ok() -> Value {
+    json!({"status": "ok", "response": {"type": "default"}})
+}
+
+fn switch(
+    exchange: Arc<RawExchangeProvider<PrivateKeySigner>>,
+) -> DeadMansSwitchBuilder<PrivateKeySigner> {
+    DeadMansSwitch::builder(exchange)
+        .with_rearm_interval(TICK)
+        .with_horizon(Duration::from_secs(5))
+}
+
+/// The `time` of each scheduleCancel sent so far
+fn scheduled(transport: &MockTransport) -> Vec<Value> {
+    transport
+        .requests()
+        .into_iter()
+        .map(|request| request.body["action"].clone())
+        .filter(|action| action["type"] == "scheduleCancel")
+        .map(|action| action["time"].clone())
+        .collect()
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+#[tokio::test]
+async fn test_rearms_every_tick() {
+    let (transport, exchange) = exchange(ok());
+    let switch = switch(exchange).start().unwrap();
+
+    tokio::time::sleep(TICK * 4).await;
+    let times = scheduled(&transport);
+    assert!(times.len() >= 3, "{:?}", times);
+    let times: Vec<u64> = times.iter().map(|time| time.as_u64().unwrap()).collect();
+    assert!(times.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(times[0] >= now_ms() - 1_000 + 4_000);
+
+    let state = switch.state();
+    assert_eq!(state.status, SwitchStatus::Armed);
+    assert_eq!(state.cancel_at, times.last().copied());
+    assert!(state.last_error.is_none());
+}
+
+#[tokio::test]
+async fn test_failed_health_check_stops_rearming() {
+    let (transport, exchange) = exchange(ok());
+    let healthy = Arc::new(AtomicBool::new(true));
+    let flag = healthy.clone();
+    let switch = switch(exchange)
+        .with_health_check(move || flag.load(Ordering::SeqCst))
+        .start()
+        .unwrap();
+
+    tokio::time::sleep(TICK * 2).await;
+    let cancel_at = switch.state().cancel_at;
+    assert!(cancel_at.is_some());
+
+    healthy.store(false, Ordering::SeqCst);
+    tokio::time::sleep(TICK * 2).await;
+    let sent = scheduled(&transport).len();
+    tokio::time::sleep(TICK * 3).await;
+    assert_eq!(scheduled(&transport).len(), sent);
+    let state = switch.state();
+    assert_eq!(state.status, SwitchStatus::Unhealthy);
+    // The pending cancel is left to fire
+    assert!(state.cancel_at.is_some());
+
+    healthy.store(true, Ordering::SeqCst);
+    tokio::time::sleep(TICK * 3).await;
+    assert!(scheduled(&transport).len() > sent);
+    assert_eq!(switch.state().status, SwitchStatus::Armed);
+}
+
+#[tokio::test]
+async fn test_trigger_limit_backs_off() {
+    let (transport, exchange) = exchange(json!({
+        "status": "err",
+        "response": "Too many scheduled cancel triggers today"
+    }));
+    let switch = switch(exchange).start().unwrap();
+
+    tokio::time::sleep(TICK * 5).await;
+    assert_eq!(scheduled(&transport).len(), 1);
+    let state = switch.state();
+    assert_eq!(state.status, SwitchStatus::TriggerLimitReached);
+    assert!(state.last_error.unwrap().contains("triggers"));
+}
+
+#[tokio::test]
+async fn test_shutdown_disarms() {
+    let (transport, exchange) = exchange(ok());
+    let switch = switch(exchange).start().unwrap();
+    tokio::time::sleep(TICK * 2).await;
+
+    switch.shutdown().await.unwrap();
+    let times = scheduled(&transport);
+    // Removing the schedule sends no time at all
+    let requests = transport.requests();
+    let last = requests.last().unwrap().body["action"].as_object().unwrap();
+    assert_eq!(last["type"], "scheduleCancel");
+    assert!(!last.contains_key("time"));
+    assert_eq!(switch.state().status, SwitchStatus::Disarmed);
+    assert_eq!(switch.state().cancel_at, None);
+
+    tokio::time::sleep(TICK * 3).await;
+    assert_eq!(scheduled(&transport).len(), times.len());
+}
+
+#[tokio::test]
+async fn test_rejects_short_horizon() {
+    let (_, exchange) = exchange(ok());
+    assert!(switch(exchange.clone())
+        .with_horizon(Duration::from_secs(1))
+        .start()
+        .is_err());
+    assert!(DeadMansSwitch::builder(exchange)
+        .with_rearm_interval(Duration::from_secs(30))
+        .with_horizon(Duration::from_secs(10))
+        .start()
+        .is_err());
+}
@@ -0,0 +1,108 @@
+//! Tests for the `decimal` feature
+//!
+//! Tests cover:
+//! - Parsing and canonical string output
+//! - Serde round-trips for strings and JSON numbers
+//! - Numeric comparison with strings
+//! - Converting response fields to decimals
+//! - Passing decimals into order requests
+
+#[cfg(test)]
+mod tests {
+    use hyperliquid_sdk_rs::{
+        constants::TIF_GTC,
+        types::{
+            requests::OrderRequest,
+            ws::{BookLevel, L2BookData},
+            Decimal, Num,
+        },
+    };
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_canonical_string() {
+        assert_eq!(dec("50000.10").to_string(), "50000.1");
+        assert_eq!(dec("1.000").to_string(), "1");
+        assert_eq!(dec("-0.0").to_string(), "0");
+        assert_eq!(dec("0.00001234").to_string(), "0.00001234");
+        assert_eq!(String::from(dec("42.50")), "42.5");
+    }
+
+    #[test]
+    fn test_invalid_input_rejected() {
+        assert!("abc".parse::<Decimal>().is_err());
+        assert!("".parse::<Decimal>().is_err());
+        assert!("1e5".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let value: Decimal = serde_json::from_str("\"0.30\"").unwrap();
+        assert_eq!(value, dec("0.3"));
+        assert_eq!(serde_json::to_string(&value).unwrap(), "\"0.3\"");
+
+        let from_int: Decimal = serde_json::from_str("12").unwrap();
+        let from_float: Decimal = serde_json::from_str("0.1").unwrap();
+        assert_eq!(from_int, dec("12"));
+        assert_eq!(from_float, dec("0.1"));
+    }
+
+    #[test]
+    fn test_exact_arithmetic() {
+        assert_eq!(dec("0.1") + dec("0.2"), dec("0.3"));
+        assert_eq!(dec("1.5") * dec("2"), dec("3"));
+        assert_eq!(-dec("1.25"), dec("-1.25"));
+        assert_eq!(dec("2").to_f64(), 2.0);
+    }
+
+    #[test]
+    fn test_compares_numerically_with_strings() {
+        let px = dec("100.50");
+
+        assert!(px == "100.5");
+        assert!(px == "100.500");
+        assert!(px != "100.51");
+        assert!(px != "not a number");
+    }
+
+    #[test]
+    fn test_response_fields_convert_to_decimal() {
+        let json = r#"{
+            "coin": "BTC",
+            "time": 1700000000000,
+            "levels": [[{"px": "50000.0", "sz": "1.50", "n": 2}], []]
+        }"#;
+        let book: L2BookData = serde_json::from_str(json).unwrap();
+
+        // Fields keep the wire string; the exact value is one call away
+        let level: &BookLevel = &book.levels[0][0];
+        assert_eq!(level.px, "50000.0");
+        let (px, sz) = (
+            level.px.to_decimal().unwrap(),
+            level.sz.to_decimal().unwrap(),
+        );
+        assert_eq!(px * sz, dec("75000"));
+        assert_eq!(Num::from(px * sz), "75000");
+
+        let out = serde_json::to_value([&level.px, &level.sz]).unwrap();
+        assert_eq!(out, serde_json::json!(["50000.0", "1.50"]));
+    }
+
+    #[test]
+    fn test_malformed_response_number_rejected_on_conversion() {
+        let json = r#"{"px": "abc", "sz": "1", "n": 1}"#;
+        let level: BookLevel = serde_json::from_str(json).unwrap();
+        assert!(level.px.to_decimal().is_err());
+    }
+
+    #[test]
+    fn test_decimal_in_order_request() {
+        let order = OrderRequest::limit(0, true, dec("50000.10"), dec("0.010"), TIF_GTC);
+
+        assert_eq!(order.limit_px, "50000.1");
+        assert_eq!(order.sz, "0.01");
+    }
+}
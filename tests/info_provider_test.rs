@@ -8,7 +8,7 @@
 //! To run live tests:
 //! HYPERLIQUID_PRIVATE_KEY=0x... HYPERLIQUID_MAINNET=false cargo test --test info_provider_test
 
-use hyperliquid_sdk_rs::{InfoProvider, Network};
+use hyperliquid_sdk_rs::{types::CandleInterval, InfoProvider, Network};
 use std::sync::Once;
 
 static INIT: Once = Once::new();
@@ -194,10 +194,10 @@ mod live_tests {
         // Should have at least BTC
         assert!(!mids.is_empty(), "all_mids returned empty");
 
-        // Verify format - should be string price values
+        // Verify format - should be numeric price values
         for (symbol, price) in &mids {
             assert!(!symbol.is_empty(), "Empty symbol found");
-            assert!(!price.is_empty(), "Empty price for {}", symbol);
+            assert!(price.parse::<f64>().is_ok(), "Invalid price for {}", symbol);
         }
     }
 
@@ -288,7 +288,7 @@ mod live_tests {
 
         let result = provider
             .candles("BTC")
-            .interval("1h")
+            .interval(CandleInterval::OneHour)
             .time_range(one_hour_ago, now)
             .send()
             .await;
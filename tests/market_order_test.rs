@@ -0,0 +1,210 @@
+//! Tests for market orders
+//!
+//! Tests cover:
+//! - Slippage-bounded IOC prices from the book and from mids
+//! - Fill parsing for market_open and market_close
+//! - Closing long and short positions
+//! - Rejected and unfilled market orders
+
+mod common;
+
+use std::sync::Arc;
+
+use alloy::signers::local::PrivateKeySigner;
+use common::{exchange, position, user_state};
+use hyperliquid_sdk_rs::{
+    providers::{MockTransport, RawExchangeProvider},
+    HyperliquidError, RejectReason,
+};
+use serde_json::{json, Value};
+
+fn book(bid: &str, ask: &str) -> Value {
+    json!({
+        "coin": "ETH",
+        "time": 1700000000000u64,
+        "levels": [
+            [{"px": bid, "sz": "10", "n": 1}],
+            [{"px": ask, "sz": "10", "n": 1}],
+        ]
+    })
+}
+
+fn filled(avg_px: &str, total_sz: &str) -> Value {
+    json!({
+        "status": "ok",
+        "response": {"type": "order", "data": {"statuses": [
+            {"filled": {"totalSz": total_sz, "avgPx": avg_px, "oid": 77}}
+        ]}}
+    })
+}
+
+fn sent_order(transport: &MockTransport) -> Value {
+    transport
+        .requests()
+        .into_iter()
+        .find(|request| request.body["action"]["type"] == "order")
+        .expect("no order sent")
+        .body["action"]["orders"][0]
+        .clone()
+}
+
+#[tokio::test]
+async fn test_market_open_buy_prices_off_best_ask() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_info_response("l2Book", book("2999", "3001.5"))
+            .with_exchange_response("order", filled("3001.7", "0.5")),
+    );
+
+    let fill = exchange(&transport)
+        .market_open("ETH", true, "0.5", Some(0.01))
+        .await
+        .unwrap();
+    assert_eq!(fill.avg_px, "3001.7");
+    assert_eq!(fill.total_sz, "0.5");
+    assert_eq!(fill.oid, 77);
+
+    let order = sent_order(&transport);
+    assert_eq!(order["a"], 1);
+    assert_eq!(order["b"], true);
+    // 3001.5 * 1.01 = 3031.515, rounded down to 5 significant figures
+    assert_eq!(order["p"], "3031.5");
+    assert_eq!(order["s"], "0.5");
+    assert_eq!(order["r"], false);
+    assert_eq!(order["t"]["limit"]["tif"], "Ioc");
+}
+
+#[tokio::test]
+async fn test_market_sell_uses_default_slippage() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_info_response("l2Book", book("2999", "3001"))
+            .with_exchange_response("order", filled("2998", "1")),
+    );
+
+    exchange(&transport)
+        .market_open("ETH", false, "1", None)
+        .await
+        .unwrap();
+
+    // 2999 * 0.95 = 2849.05, rounded up to stay inside the bound
+    assert_eq!(sent_order(&transport)["p"], "2849.1");
+}
+
+#[tokio::test]
+async fn test_empty_book_side_falls_back_to_mid() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_info_response(
+                "l2Book",
+                json!({"coin": "BTC", "time": 0, "levels": [[], []]}),
+            )
+            .with_info_response("allMids", json!({"BTC": "50000"}))
+            .with_exchange_response("order", filled("50010", "0.01")),
+    );
+
+    let provider = exchange(&transport);
+    let response = provider
+        .order_for("BTC")
+        .market_buy("0.01", 0.02)
+        .send()
+        .await
+        .unwrap();
+    assert!(response.is_ok());
+    assert_eq!(sent_order(&transport)["p"], "51000");
+}
+
+#[tokio::test]
+async fn test_market_close_reverses_position() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_info_response(
+                "clearinghouseState",
+                user_state(vec![position("ETH", "-1.25")]),
+            )
+            .with_info_response("l2Book", book("2999", "3001"))
+            .with_exchange_response("order", filled("3001", "1.25")),
+    );
+
+    let fill = exchange(&transport)
+        .market_close("ETH", Some(0.01))
+        .await
+        .unwrap();
+    assert_eq!(fill.total_sz, "1.25");
+
+    let order = sent_order(&transport);
+    assert_eq!(order["b"], true);
+    assert_eq!(order["s"], "1.25");
+    assert_eq!(order["r"], true);
+}
+
+#[tokio::test]
+async fn test_market_close_without_position() {
+    let transport = Arc::new(MockTransport::new().with_info_response(
+        "clearinghouseState",
+        user_state(vec![position("BTC", "0.1")]),
+    ));
+
+    let result = exchange(&transport).market_close("ETH", None).await;
+    assert!(
+        matches!(result, Err(HyperliquidError::InvalidRequest(msg)) if msg.contains("ETH"))
+    );
+}
+
+#[tokio::test]
+async fn test_unfilled_market_order_is_an_error() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_info_response("l2Book", book("2999", "3001"))
+            .with_exchange_response(
+                "order",
+                json!({
+                    "status": "ok",
+                    "response": {"type": "order", "data": {"statuses": [
+                        {"error": "Order could not immediately match against any resting orders."}
+                    ]}}
+                }),
+            ),
+    );
+
+    let result = exchange(&transport)
+        .market_open("ETH", true, "1", None)
+        .await;
+    match result {
+        Err(err @ HyperliquidError::Rejected { reason, .. }) => {
+            assert_eq!(reason, RejectReason::IocCouldNotMatch);
+            assert!(err.is_retryable());
+            assert!(err.to_string().contains("immediately match"));
+        }
+        other => panic!("expected rejection, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_market_order_validation() {
+    let transport = Arc::new(MockTransport::new());
+    let provider = exchange(&transport);
+
+    let bad_slippage = provider.market_open("ETH", true, "1", Some(1.5)).await;
+    assert!(matches!(
+        bad_slippage,
+        Err(HyperliquidError::InvalidRequest(_))
+    ));
+
+    let unpriced = provider.order_for("ETH").market_buy("1", 0.01).build();
+    assert!(
+        matches!(unpriced, Err(HyperliquidError::InvalidRequest(msg)) if msg.contains("send()"))
+    );
+
+    let without_registry = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone())
+        .order(1)
+        .market_sell("1", 0.01)
+        .send()
+        .await;
+    assert!(matches!(
+        without_registry,
+        Err(HyperliquidError::InvalidRequest(_))
+    ));
+    assert!(transport.requests().is_empty());
+}
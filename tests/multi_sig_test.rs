@@ -0,0 +1,216 @@
+//! Tests for multi-sig signature collection
+//!
+//! Tests cover:
+//! - Collecting signatures for L1 and user actions up to the threshold
+//! - Passing sessions between signers as JSON files
+//! - Rejecting unauthorized signers and mismatched signatures
+//! - Submitting the multiSig action signed by the outer signer
+
+use std::sync::Arc;
+
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
+use hyperliquid_sdk_rs::{
+    providers::{MockTransport, MultiSigSession, RawExchangeProvider, SignedAction},
+    signers::{verify, HyperliquidSigner},
+    types::{
+        actions::{UpdateLeverage, UsdSend},
+        eip712::HyperliquidAction,
+    },
+    HyperliquidError, Network,
+};
+use serde_json::{json, Value};
+
+const MULTI_SIG_USER: Address = Address::new([9u8; 20]);
+
+struct Signers {
+    outer: PrivateKeySigner,
+    second: PrivateKeySigner,
+    heavy: PrivateKeySigner,
+}
+
+fn signers() -> Signers {
+    Signers {
+        outer: PrivateKeySigner::random(),
+        second: PrivateKeySigner::random(),
+        heavy: PrivateKeySigner::random(),
+    }
+}
+
+fn transport(signers: &Signers) -> Arc<MockTransport> {
+    Arc::new(
+        MockTransport::new()
+            .with_info_response(
+                "userToMultiSigSigners",
+                json!({"threshold": 2, "signers": [
+                    {"address": signers.outer.address(), "weight": 1},
+                    {"address": signers.second.address(), "weight": 1},
+                    {"address": signers.heavy.address(), "weight": 2},
+                ]}),
+            )
+            .with_exchange_response(
+                "multiSig",
+                json!({"status": "ok", "response": {"type": "default"}}),
+            ),
+    )
+}
+
+fn leverage() -> UpdateLeverage {
+    UpdateLeverage {
+        asset: 0,
+        is_cross: true,
+        leverage: 3,
+    }
+}
+
+fn session_file(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "hyperliquid-multisig-{}-{}.json",
+        name,
+        std::process::id()
+    ))
+}
+
+#[tokio::test]
+async fn test_collect_and_submit_l1_action() {
+    let signers = signers();
+    let transport = transport(&signers);
+    let exchange = RawExchangeProvider::testnet(signers.outer.clone())
+        .with_transport(transport.clone());
+
+    let mut session = exchange
+        .multi_sig_session(MULTI_SIG_USER, "updateLeverage", &leverage())
+        .await
+        .unwrap();
+    assert_eq!(session.action_type(), Some("updateLeverage"));
+    assert_eq!(session.threshold, 2);
+    session.sign(&signers.outer).await.unwrap();
+    assert_eq!(session.signed_weight(), 1);
+    assert!(!session.is_ready());
+
+    // Hand the session to the second signer through a file
+    let path = session_file("l1");
+    session.save(&path).unwrap();
+    let mut loaded = MultiSigSession::load(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(loaded, session);
+    loaded.sign(&signers.second).await.unwrap();
+    assert!(loaded.is_ready());
+
+    let response = exchange.submit_multi_sig(&loaded).await.unwrap();
+    assert!(response.is_ok());
+
+    let requests = transport.requests();
+    let body = &requests.last().unwrap().body;
+    let action = &body["action"];
+    assert_eq!(action["type"], "multiSig");
+    assert_eq!(action["signatures"].as_array().unwrap().len(), 2);
+    assert_eq!(
+        action["payload"]["multiSigUser"],
+        json!(format!("{:#x}", MULTI_SIG_USER))
+    );
+    assert_eq!(action["payload"]["action"], loaded.action);
+    assert_eq!(body["nonce"], json!(loaded.nonce));
+
+    // The posted payload is signed by the outer signer
+    let posted: SignedAction = serde_json::from_value(body.clone()).unwrap();
+    assert_eq!(
+        verify::recover_signer(&posted, &Network::Testnet).unwrap(),
+        signers.outer.address()
+    );
+}
+
+#[tokio::test]
+async fn test_user_action_session_uses_weights() {
+    let signers = signers();
+    let exchange = RawExchangeProvider::testnet(signers.outer.clone())
+        .with_transport(transport(&signers));
+    let action = UsdSend {
+        signature_chain_id: 421614,
+        hyperliquid_chain: "Testnet".to_string(),
+        destination: format!("{:#x}", Address::new([7u8; 20])),
+        amount: "100".to_string(),
+        time: exchange.next_nonce(),
+    };
+
+    let mut session = exchange
+        .multi_sig_user_session(MULTI_SIG_USER, &action)
+        .await
+        .unwrap();
+    assert_eq!(session.action_type(), Some("usdSend"));
+    assert_eq!(session.nonce, action.time);
+    // Co-signers sign the action bound to the multi-sig user, not the plain transfer
+    assert_ne!(
+        session.signing_hash().unwrap(),
+        action.eip712_signing_hash(&action.domain())
+    );
+
+    session.sign(&signers.heavy).await.unwrap();
+    assert_eq!(session.signed_weight(), 2);
+    assert!(session.is_ready());
+
+    // Signing again replaces the earlier signature
+    session.sign(&signers.heavy).await.unwrap();
+    assert_eq!(session.signatures.len(), 1);
+}
+
+#[tokio::test]
+async fn test_rejects_unauthorized_and_mismatched_signatures() {
+    let signers = signers();
+    let exchange = RawExchangeProvider::testnet(signers.outer.clone())
+        .with_transport(transport(&signers));
+    let mut session = exchange
+        .multi_sig_session(MULTI_SIG_USER, "updateLeverage", &leverage())
+        .await
+        .unwrap();
+
+    let outsider = PrivateKeySigner::random();
+    assert!(matches!(
+        session.sign(&outsider).await,
+        Err(HyperliquidError::InvalidRequest(_))
+    ));
+
+    // A signature claimed for one signer but made by another
+    let hash = session.signing_hash().unwrap();
+    let signature = signers.second.sign_hash(hash).await.unwrap();
+    assert!(matches!(
+        session.add_signature(signers.heavy.address(), signature.into()),
+        Err(HyperliquidError::Signer(_))
+    ));
+    assert!(session.signatures.is_empty());
+
+    // Only authorized users can be the outer signer
+    let stranger = RawExchangeProvider::testnet(outsider.clone())
+        .with_transport(transport(&signers));
+    assert!(stranger
+        .multi_sig_session(MULTI_SIG_USER, "updateLeverage", &leverage())
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_submit_requires_threshold_and_outer_signer() {
+    let signers = signers();
+    let transport = transport(&signers);
+    let exchange = RawExchangeProvider::testnet(signers.outer.clone())
+        .with_transport(transport.clone());
+    let mut session = exchange
+        .multi_sig_session(MULTI_SIG_USER, "updateLeverage", &leverage())
+        .await
+        .unwrap();
+    session.sign(&signers.second).await.unwrap();
+
+    assert!(exchange.submit_multi_sig(&session).await.is_err());
+
+    session.sign(&signers.heavy).await.unwrap();
+    let other = RawExchangeProvider::testnet(signers.second.clone())
+        .with_transport(transport.clone());
+    assert!(other.submit_multi_sig(&session).await.is_err());
+
+    let posted: Vec<Value> = transport
+        .requests()
+        .into_iter()
+        .map(|request| request.body)
+        .filter(|body| body["action"]["type"] == "multiSig")
+        .collect();
+    assert!(posted.is_empty());
+}
@@ -0,0 +1,87 @@
+//! Tests for network endpoint configuration
+//!
+//! Tests cover:
+//! - Built-in network URLs and signing parameters
+//! - Custom networks (local node, proxy, mock server)
+//! - Providers accepting custom networks
+
+use alloy::signers::local::PrivateKeySigner;
+use hyperliquid_sdk_rs::{
+    constants::{
+        AGENT_SOURCE_MAINNET, AGENT_SOURCE_TESTNET, CHAIN_ID_MAINNET, CHAIN_ID_TESTNET,
+    },
+    providers::RawExchangeProvider,
+    InfoProvider, Network,
+};
+
+#[test]
+fn test_builtin_network_urls() {
+    assert_eq!(
+        Network::Mainnet.info_url(),
+        "https://api.hyperliquid.xyz/info"
+    );
+    assert_eq!(
+        Network::Testnet.exchange_url(),
+        "https://api.hyperliquid-testnet.xyz/exchange"
+    );
+    assert_eq!(Network::Mainnet.ws_url(), "wss://api.hyperliquid.xyz/ws");
+}
+
+#[test]
+fn test_builtin_network_signing_params() {
+    assert_eq!(Network::Mainnet.chain_id(), CHAIN_ID_MAINNET);
+    assert_eq!(Network::Mainnet.agent_source(), AGENT_SOURCE_MAINNET);
+    assert_eq!(Network::Mainnet.hyperliquid_chain(), "Mainnet");
+
+    assert_eq!(Network::Testnet.chain_id(), CHAIN_ID_TESTNET);
+    assert_eq!(Network::Testnet.agent_source(), AGENT_SOURCE_TESTNET);
+    assert_eq!(Network::Testnet.hyperliquid_chain(), "Testnet");
+}
+
+#[test]
+fn test_custom_network_explicit_fields() {
+    let network = Network::Custom {
+        api_url: "http://127.0.0.1:3001".to_string(),
+        ws_url: "ws://127.0.0.1:3001/ws".to_string(),
+        chain_id: CHAIN_ID_TESTNET,
+        agent_source: AGENT_SOURCE_TESTNET.to_string(),
+    };
+
+    assert_eq!(network.info_url(), "http://127.0.0.1:3001/info");
+    assert_eq!(network.exchange_url(), "http://127.0.0.1:3001/exchange");
+    assert_eq!(network.ws_url(), "ws://127.0.0.1:3001/ws");
+    assert!(!network.is_mainnet());
+}
+
+#[test]
+fn test_custom_network_does_not_guess_from_hostname() {
+    // A proxy whose hostname contains "testnet" still signs for mainnet
+    let network = Network::custom(
+        "https://testnet-proxy.internal/",
+        "wss://testnet-proxy.internal/ws",
+        &Network::Mainnet,
+    );
+
+    assert_eq!(network.api_url(), "https://testnet-proxy.internal");
+    assert_eq!(network.chain_id(), CHAIN_ID_MAINNET);
+    assert_eq!(network.agent_source(), AGENT_SOURCE_MAINNET);
+    assert_eq!(network.hyperliquid_chain(), "Mainnet");
+}
+
+#[test]
+fn test_providers_accept_custom_network() {
+    rustls::crypto::CryptoProvider::install_default(
+        rustls::crypto::aws_lc_rs::default_provider(),
+    )
+    .ok();
+
+    let network = Network::custom(
+        "http://localhost:3001",
+        "ws://localhost:3001/ws",
+        &Network::Testnet,
+    );
+
+    let _info = InfoProvider::new(network.clone());
+    let exchange = RawExchangeProvider::new(PrivateKeySigner::random(), network.clone());
+    assert_eq!(exchange.network(), &network);
+}
@@ -7,6 +7,8 @@
 //! - Nonce validity time bounds
 //! - Concurrent access safety
 //! - Counter monitoring
+//! - Strictly increasing nonces across bursts and resets
+//! - Nonces used when signing exchange actions
 
 #[cfg(test)]
 mod tests {
@@ -16,6 +18,13 @@ mod tests {
     use std::sync::Arc;
     use std::time::{SystemTime, UNIX_EPOCH};
 
+    use alloy::signers::local::PrivateKeySigner;
+    use hyperliquid_sdk_rs::{
+        providers::{MockTransport, RawExchangeProvider},
+        types::requests::OrderRequest,
+    };
+    use serde_json::json;
+
     // ==================== Basic Creation Tests ====================
 
     #[test]
@@ -419,8 +428,8 @@ mod tests {
     fn test_submillisecond_uniqueness() {
         let manager = NonceManager::new(false);
 
-        // The NonceManager adds counter % 1000 to ensure uniqueness
-        // within the same millisecond
+        // Nonces issued within the same millisecond are bumped past the
+        // last one to stay unique
         let mut nonces = Vec::new();
         for _ in 0..1000 {
             nonces.push(manager.next_nonce(None));
@@ -489,4 +498,128 @@ mod tests {
         // The check is nonce < (now + 1 day), so exactly 1 day future is invalid
         assert!(!NonceManager::is_valid_nonce(exactly_one_day_future));
     }
+
+    // ==================== Strict Ordering Tests ====================
+
+    #[test]
+    fn test_nonces_increase_past_one_thousand_per_millisecond() {
+        let manager = NonceManager::new(false);
+
+        let mut prev = manager.next_nonce(None);
+        for _ in 0..2500 {
+            let current = manager.next_nonce(None);
+            assert!(current > prev, "Nonces should be strictly increasing");
+            prev = current;
+        }
+        assert_eq!(manager.last_nonce(None), Some(prev));
+    }
+
+    #[test]
+    fn test_reset_keeps_nonces_increasing() {
+        let manager = NonceManager::new(true);
+        let addr = Address::new([1u8; 20]);
+
+        assert_eq!(manager.last_nonce(Some(addr)), None);
+        let before = manager.next_nonce(Some(addr));
+        manager.reset_address(addr);
+
+        assert!(manager.next_nonce(Some(addr)) > before);
+        assert_eq!(manager.get_counter(Some(addr)), 1);
+    }
+
+    // ==================== Exchange Signing Tests ====================
+
+    fn order_response() -> serde_json::Value {
+        json!({"status": "ok", "response": {"type": "order", "data": {"statuses": [
+            {"resting": {"oid": 1}}
+        ]}}})
+    }
+
+    #[tokio::test]
+    async fn test_exchange_actions_use_managed_nonces() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_exchange_response("order", order_response())
+                .with_exchange_response(
+                    "usdSend",
+                    json!({"status": "ok", "response": {"type": "default"}}),
+                ),
+        );
+        let signer = PrivateKeySigner::random();
+        let signer_address = signer.address();
+        let manager = Arc::new(NonceManager::new(true));
+        let exchange = Arc::new(
+            RawExchangeProvider::testnet(signer)
+                .with_transport(transport.clone())
+                .with_nonce_manager(manager.clone()),
+        );
+
+        let mut handles = Vec::new();
+        for _ in 0..20 {
+            let exchange = exchange.clone();
+            handles.push(tokio::spawn(async move {
+                let order = OrderRequest::limit(0, true, "50000", "0.01", "Gtc");
+                exchange.place_order(&order).await.unwrap();
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        exchange
+            .usd_transfer(Address::new([9u8; 20]), "1")
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        let nonces: HashSet<u64> = requests
+            .iter()
+            .map(|request| request.body["nonce"].as_u64().unwrap())
+            .collect();
+        assert_eq!(nonces.len(), 21);
+        assert_eq!(manager.get_counter(Some(signer_address)), 21);
+
+        // User actions sign the nonce in the action and send the same one
+        let transfer = &requests[20].body;
+        assert_eq!(transfer["action"]["time"], transfer["nonce"]);
+        assert_eq!(
+            transfer["nonce"].as_u64(),
+            manager.last_nonce(Some(signer_address))
+        );
+        assert!(nonces
+            .iter()
+            .all(|nonce| *nonce <= transfer["nonce"].as_u64().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_shared_manager_across_vault_providers() {
+        let transport = Arc::new(
+            MockTransport::new().with_exchange_response("order", order_response()),
+        );
+        let signer = PrivateKeySigner::random();
+        let manager = Arc::new(NonceManager::new(true));
+        let providers: Vec<_> = [[1u8; 20], [2u8; 20]]
+            .into_iter()
+            .map(|vault| {
+                RawExchangeProvider::testnet_vault(signer.clone(), Address::new(vault))
+                    .with_transport(transport.clone())
+                    .with_nonce_manager(manager.clone())
+            })
+            .collect();
+
+        let order = OrderRequest::limit(0, true, "50000", "0.01", "Gtc");
+        for _ in 0..5 {
+            for provider in &providers {
+                provider.place_order(&order).await.unwrap();
+            }
+        }
+
+        // Both vaults are signed by one key, so they share one sequence
+        let nonces: Vec<u64> = transport
+            .requests()
+            .iter()
+            .map(|request| request.body["nonce"].as_u64().unwrap())
+            .collect();
+        assert_eq!(nonces.len(), 10);
+        assert!(nonces.windows(2).all(|pair| pair[1] > pair[0]));
+    }
 }
@@ -0,0 +1,140 @@
+//! Tests for the locally maintained order book
+//!
+//! Tests cover:
+//! - Building from `l2Book` snapshots and sorting
+//! - Top-of-book queries (best bid/ask, mid, spread)
+//! - Depth, notional, VWAP and imbalance calculations
+//! - Reset and parse failures
+
+#[cfg(test)]
+mod tests {
+    use hyperliquid_sdk_rs::{
+        providers::{OrderBook, PriceLevel},
+        types::ws::{BookLevel, L2BookData},
+    };
+
+    fn level(px: &str, sz: &str) -> BookLevel {
+        BookLevel {
+            px: px.parse().unwrap(),
+            sz: sz.parse().unwrap(),
+            n: 1,
+        }
+    }
+
+    fn snapshot() -> L2BookData {
+        L2BookData {
+            coin: "BTC".to_string(),
+            time: 1_700_000_000_000,
+            levels: vec![
+                vec![level("99", "2"), level("100", "1"), level("98", "3")],
+                vec![level("102", "2"), level("101", "1"), level("103", "4")],
+            ],
+        }
+    }
+
+    #[test]
+    fn test_from_snapshot_sorts_levels() {
+        let book = OrderBook::from_snapshot(&snapshot()).unwrap();
+
+        assert_eq!(book.coin(), "BTC");
+        assert_eq!(book.time(), 1_700_000_000_000);
+        let bids: Vec<f64> = book.bids().iter().map(|l| l.px).collect();
+        let asks: Vec<f64> = book.asks().iter().map(|l| l.px).collect();
+        assert_eq!(bids, vec![100.0, 99.0, 98.0]);
+        assert_eq!(asks, vec![101.0, 102.0, 103.0]);
+    }
+
+    #[test]
+    fn test_top_of_book() {
+        let book = OrderBook::from_snapshot(&snapshot()).unwrap();
+
+        assert_eq!(
+            book.best_bid(),
+            Some(&PriceLevel {
+                px: 100.0,
+                sz: 1.0,
+                n: 1
+            })
+        );
+        assert_eq!(book.best_ask().unwrap().px, 101.0);
+        assert_eq!(book.mid(), Some(100.5));
+        assert_eq!(book.spread(), Some(1.0));
+    }
+
+    #[test]
+    fn test_empty_book() {
+        let book = OrderBook::new("ETH");
+
+        assert!(book.is_empty());
+        assert_eq!(book.mid(), None);
+        assert_eq!(book.spread(), None);
+        assert_eq!(book.imbalance(5), None);
+        assert_eq!(book.vwap(true, 1.0), None);
+        assert_eq!(book.size_for_notional(true, 100.0), None);
+    }
+
+    #[test]
+    fn test_depth_at_price() {
+        let book = OrderBook::from_snapshot(&snapshot()).unwrap();
+
+        assert_eq!(book.depth_at_price(99.0), 2.0);
+        assert_eq!(book.depth_at_price(103.0), 4.0);
+        assert_eq!(book.depth_at_price(100.5), 0.0);
+    }
+
+    #[test]
+    fn test_size_for_notional() {
+        let book = OrderBook::from_snapshot(&snapshot()).unwrap();
+
+        // 101 * 1 + 102 * 0.5
+        assert_eq!(book.size_for_notional(true, 152.0), Some(1.5));
+        // 100 * 1 + 99 * 2, then 98 * 1
+        assert_eq!(book.size_for_notional(false, 396.0), Some(4.0));
+        assert_eq!(book.size_for_notional(true, 1_000_000.0), None);
+    }
+
+    #[test]
+    fn test_vwap() {
+        let book = OrderBook::from_snapshot(&snapshot()).unwrap();
+
+        assert_eq!(book.vwap(true, 1.0), Some(101.0));
+        // (101 * 1 + 102 * 2) / 3
+        let vwap = book.vwap(true, 3.0).unwrap();
+        assert!((vwap - 305.0 / 3.0).abs() < 1e-9);
+        assert_eq!(book.vwap(false, 3.0), Some((100.0 + 198.0) / 3.0));
+        assert_eq!(book.vwap(true, 8.0), None);
+        assert_eq!(book.vwap(true, 0.0), None);
+    }
+
+    #[test]
+    fn test_imbalance() {
+        let book = OrderBook::from_snapshot(&snapshot()).unwrap();
+
+        assert_eq!(book.imbalance(1), Some(0.0));
+        // bids 1 + 2 + 3 = 6, asks 1 + 2 + 4 = 7
+        assert_eq!(book.imbalance(10), Some(-1.0 / 13.0));
+    }
+
+    #[test]
+    fn test_clear_and_reapply() {
+        let mut book = OrderBook::from_snapshot(&snapshot()).unwrap();
+        book.clear();
+
+        assert!(book.is_empty());
+        assert_eq!(book.coin(), "BTC");
+        assert_eq!(book.time(), 0);
+
+        book.apply_snapshot(&snapshot()).unwrap();
+        assert_eq!(book.bids().len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_level_leaves_book_untouched() {
+        let mut book = OrderBook::from_snapshot(&snapshot()).unwrap();
+        let mut bad = snapshot();
+        bad.levels[1][0].sz = "abc".into();
+
+        assert!(book.apply_snapshot(&bad).is_err());
+        assert_eq!(book, OrderBook::from_snapshot(&snapshot()).unwrap());
+    }
+}
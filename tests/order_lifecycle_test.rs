@@ -0,0 +1,189 @@
+//! Tests for following tracked orders through WebSocket updates
+//!
+//! Tests cover:
+//! - Moving orders through Resting, PartiallyFilled and Filled from orderUpdates and fills
+//! - Correlating updates by cloid and by oid
+//! - Average fill price and deduplication of replayed fills
+//! - Canceled, Rejected and Triggered statuses
+//! - Exchange responses not overriding WebSocket state
+//! - Awaiting terminal states and streaming per-order transitions
+
+mod common;
+
+use std::time::Duration;
+
+use common::{cloid_hex, fill};
+use hyperliquid_sdk_rs::{
+    providers::{OrderStatus, OrderTracker},
+    types::{requests::OrderRequest, responses::ExchangeResponseStatus, ws::Message},
+};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+fn tracker_with_order(sz: &str) -> (OrderTracker, Uuid) {
+    let tracker = OrderTracker::new();
+    let cloid = Uuid::new_v4();
+    let order = OrderRequest::limit(0, true, "50000", sz, "Gtc").with_cloid(Some(cloid));
+    tracker.track_order(cloid, order, 0);
+    (tracker, cloid)
+}
+
+fn order_update(cloid: Option<Uuid>, oid: u64, sz: &str, status: &str) -> Message {
+    let message = json!({
+        "channel": "orderUpdates",
+        "data": [{
+            "order": {
+                "coin": "BTC",
+                "side": "B",
+                "limitPx": "50000",
+                "sz": sz,
+                "oid": oid,
+                "timestamp": 1,
+                "origSz": "1",
+                "cloid": cloid.map(cloid_hex),
+            },
+            "status": status,
+            "statusTimestamp": 2,
+        }]
+    });
+    serde_json::from_value(message).unwrap()
+}
+
+fn response(status: Value) -> ExchangeResponseStatus {
+    serde_json::from_value(json!({
+        "status": "ok",
+        "response": {"type": "order", "data": {"statuses": [status]}}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_resting_partial_then_filled() {
+    let (tracker, cloid) = tracker_with_order("1");
+
+    tracker.apply_message(&order_update(Some(cloid), 7, "1", "open"));
+    let order = tracker.get_order(&cloid).unwrap();
+    assert_eq!(order.status, OrderStatus::Resting);
+    assert_eq!(order.oid, Some(7));
+
+    tracker.apply_message(&fill(Some(cloid), 7, 1, "50000", "0.25"));
+    tracker.apply_message(&order_update(Some(cloid), 7, "0.75", "open"));
+    let order = tracker.get_order(&cloid).unwrap();
+    assert_eq!(order.status, OrderStatus::PartiallyFilled);
+    assert_eq!(order.filled_sz, 0.25);
+
+    tracker.apply_message(&fill(Some(cloid), 7, 2, "49000", "0.75"));
+    let order = tracker.get_order(&cloid).unwrap();
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(order.filled_sz, 1.0);
+    assert_eq!(order.avg_px, Some(49250.0));
+    assert!(tracker.get_live_orders().is_empty());
+}
+
+#[test]
+fn test_correlates_by_oid_and_dedupes_fills() {
+    let (tracker, cloid) = tracker_with_order("1");
+    tracker.record_response(&cloid, response(json!({"resting": {"oid": 42}})));
+    assert_eq!(
+        tracker.get_order(&cloid).unwrap().status,
+        OrderStatus::Resting
+    );
+    assert_eq!(tracker.get_order_by_oid(42).unwrap().cloid, cloid);
+    assert!(tracker.get_order_by_oid(43).is_none());
+
+    // Updates without a cloid are matched by oid
+    tracker.apply_message(&fill(None, 42, 1, "50000", "0.5"));
+    tracker.apply_message(&fill(None, 42, 1, "50000", "0.5"));
+    let order = tracker.get_order(&cloid).unwrap();
+    assert_eq!(order.fills.len(), 1);
+    assert_eq!(order.filled_sz, 0.5);
+    assert_eq!(order.status, OrderStatus::PartiallyFilled);
+
+    // Updates for untracked orders are ignored
+    tracker.apply_message(&order_update(Some(Uuid::new_v4()), 99, "1", "canceled"));
+    assert_eq!(tracker.len(), 1);
+}
+
+#[test]
+fn test_terminal_statuses() {
+    let (tracker, cloid) = tracker_with_order("1");
+    tracker.apply_message(&order_update(Some(cloid), 1, "1", "marginCanceled"));
+    assert_eq!(
+        tracker.get_order(&cloid).unwrap().status,
+        OrderStatus::Canceled("marginCanceled".to_string())
+    );
+    // Terminal orders stay terminal
+    tracker.apply_message(&order_update(Some(cloid), 1, "1", "open"));
+    assert!(tracker.get_order(&cloid).unwrap().status.is_terminal());
+
+    let (tracker, cloid) = tracker_with_order("1");
+    tracker.apply_message(&order_update(Some(cloid), 2, "1", "triggered"));
+    assert_eq!(
+        tracker.get_order(&cloid).unwrap().status,
+        OrderStatus::Triggered
+    );
+    tracker.apply_message(&order_update(Some(cloid), 2, "1", "perpMarginRejected"));
+    assert_eq!(
+        tracker.get_order(&cloid).unwrap().status,
+        OrderStatus::Rejected("perpMarginRejected".to_string())
+    );
+
+    let (tracker, cloid) = tracker_with_order("1");
+    tracker.record_response(&cloid, response(json!({"error": "Insufficient margin"})));
+    assert_eq!(
+        tracker.get_order(&cloid).unwrap().status,
+        OrderStatus::Rejected("Insufficient margin".to_string())
+    );
+}
+
+#[test]
+fn test_response_does_not_override_websocket() {
+    let (tracker, cloid) = tracker_with_order("1");
+    tracker.apply_message(&fill(Some(cloid), 5, 1, "50000", "1"));
+    tracker.record_response(&cloid, response(json!({"resting": {"oid": 5}})));
+    let order = tracker.get_order(&cloid).unwrap();
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert!(order.response.is_some());
+
+    // An IOC that filled partly leaves nothing resting
+    let (tracker, cloid) = tracker_with_order("1");
+    tracker.record_response(
+        &cloid,
+        response(json!({"filled": {"totalSz": "0.4", "avgPx": "50100", "oid": 6}})),
+    );
+    let order = tracker.get_order(&cloid).unwrap();
+    assert_eq!(order.status, OrderStatus::Canceled("canceled".to_string()));
+    assert_eq!(order.filled_sz, 0.4);
+    assert_eq!(order.avg_px, Some(50100.0));
+}
+
+#[tokio::test]
+async fn test_wait_for_terminal_and_transitions() {
+    let (tracker, cloid) = tracker_with_order("1");
+    let (other, _) = tracker_with_order("1");
+    assert!(other.wait_for_terminal(&Uuid::new_v4()).await.is_none());
+
+    let mut transitions = tracker.transitions(cloid);
+    let waiter = {
+        let tracker = tracker.clone();
+        tokio::spawn(async move { tracker.wait_for_terminal(&cloid).await })
+    };
+
+    tracker.apply_message(&order_update(Some(cloid), 3, "1", "open"));
+    tracker.apply_message(&fill(Some(cloid), 3, 1, "50000", "1"));
+
+    let first = transitions.next().await.unwrap();
+    assert_eq!(first.from, OrderStatus::Pending);
+    assert_eq!(first.order.status, OrderStatus::Resting);
+    let second = transitions.next().await.unwrap();
+    assert_eq!(second.from, OrderStatus::Resting);
+    assert_eq!(second.order.status, OrderStatus::Filled);
+
+    let done = tokio::time::timeout(Duration::from_secs(1), waiter)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(done.status, OrderStatus::Filled);
+    assert_eq!(done.filled_sz, 1.0);
+}
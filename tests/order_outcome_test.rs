@@ -0,0 +1,236 @@
+//! Tests for per-order outcomes
+//!
+//! Tests cover:
+//! - Mapping response statuses to outcomes in request order
+//! - Client order ids, fills and rejections
+//! - Failing on rejected legs and rejected requests
+//! - Classifying rejection messages and retryability
+//! - Outcomes of a sent bulk order
+//! - place_order_outcome and bulk_order_outcomes aligned with the sent orders
+
+use std::sync::Arc;
+
+use alloy::signers::local::PrivateKeySigner;
+use hyperliquid_sdk_rs::{
+    providers::{MockTransport, RawExchangeProvider},
+    types::{
+        requests::OrderRequest,
+        responses::{ExchangeResponseStatus, OrderOutcome},
+    },
+    HyperliquidError, RejectReason,
+};
+use serde_json::{json, Value};
+
+fn response(statuses: Value) -> Value {
+    json!({"status": "ok", "response": {"type": "order", "data": {"statuses": statuses}}})
+}
+
+fn parse(value: Value) -> ExchangeResponseStatus {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_outcomes_follow_request_order() {
+    let status = parse(response(json!([
+        {"resting": {"oid": 10, "cloid": "0x00000000000000000000000000000001"}},
+        {"filled": {"totalSz": "0.02", "avgPx": "1891.4", "oid": 11}},
+        "waitingForFill",
+        "waitingForTrigger",
+        {"error": "Order must have minimum value of $10."},
+    ])));
+
+    let outcomes = status.order_outcomes().unwrap();
+    assert_eq!(outcomes.len(), 5);
+
+    assert_eq!(outcomes[0].oid(), Some(10));
+    assert_eq!(
+        outcomes[0].cloid(),
+        Some("0x00000000000000000000000000000001")
+    );
+
+    let (avg_px, total_sz) = outcomes[1].fill().unwrap();
+    assert_eq!(*avg_px, "1891.4");
+    assert_eq!(*total_sz, "0.02");
+    assert_eq!(outcomes[1].cloid(), None);
+
+    assert!(matches!(outcomes[2], OrderOutcome::WaitingForFill));
+    assert!(matches!(outcomes[3], OrderOutcome::WaitingForTrigger));
+    assert_eq!(outcomes[3].oid(), None);
+
+    assert!(outcomes[4].is_rejected());
+    assert_eq!(outcomes[4].reject_reason(), Some(RejectReason::MinNotional));
+    assert_eq!(
+        outcomes[4].rejection(),
+        Some("Order must have minimum value of $10.")
+    );
+}
+
+#[test]
+fn test_into_result_fails_on_any_rejected_leg() {
+    let status = parse(response(json!([
+        {"resting": {"oid": 1}},
+        {"error": "Insufficient margin to place order."},
+        {"resting": {"oid": 2}},
+    ])));
+
+    let outcomes = status.order_outcomes().unwrap();
+    assert!(!outcomes.all_accepted());
+    let rejected: Vec<usize> = outcomes.rejected().map(|(index, _)| index).collect();
+    assert_eq!(rejected, vec![1]);
+
+    match outcomes.into_result() {
+        Err(HyperliquidError::Rejected { reason, message }) => {
+            assert_eq!(reason, RejectReason::InsufficientMargin);
+            assert!(
+                message.contains("order 1: Insufficient margin"),
+                "{}",
+                message
+            )
+        }
+        other => panic!("expected rejection, got {:?}", other),
+    }
+
+    let accepted = parse(response(json!([{"resting": {"oid": 1}}])));
+    let outcomes = accepted.order_outcomes().unwrap();
+    assert!(outcomes.all_accepted());
+    assert_eq!(outcomes.into_result().unwrap().len(), 1);
+}
+
+#[test]
+fn test_rejected_request_is_an_error() {
+    let status =
+        parse(json!({"status": "err", "response": "User or API Wallet does not exist."}));
+    assert!(matches!(
+        status.order_outcomes(),
+        Err(HyperliquidError::Rejected { reason: RejectReason::UnknownAccount, message })
+            if message.contains("does not exist")
+    ));
+
+    let no_data = parse(json!({"status": "ok", "response": {"type": "default"}}));
+    assert!(matches!(
+        no_data.order_outcomes(),
+        Err(HyperliquidError::InvalidResponse(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_bulk_order_outcomes() {
+    let transport = Arc::new(MockTransport::new().with_exchange_response(
+        "order",
+        response(json!([
+            {"filled": {"totalSz": "0.1", "avgPx": "50000", "oid": 7}},
+            {"error": "Post only order would have immediately matched."},
+        ])),
+    ));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport);
+
+    let outcomes = exchange
+        .bulk_orders(vec![
+            OrderRequest::limit(0, true, "50000", "0.1", "Ioc"),
+            OrderRequest::limit(0, true, "49000", "0.1", "Alo"),
+        ])
+        .await
+        .unwrap()
+        .order_outcomes()
+        .unwrap();
+
+    assert_eq!(outcomes[0].oid(), Some(7));
+    assert!(outcomes[1].is_rejected());
+    assert!(outcomes.into_result().is_err());
+}
+
+#[tokio::test]
+async fn test_bulk_order_outcomes_align_with_orders() {
+    let orders = vec![
+        OrderRequest::limit(0, true, "49000", "0.1", "Gtc"),
+        OrderRequest::limit(0, true, "50000", "0.1", "Ioc"),
+        OrderRequest::limit(0, true, "1", "0.1", "Gtc"),
+    ];
+    let transport = Arc::new(MockTransport::new().with_exchange_response(
+        "order",
+        response(json!([
+            {"resting": {"oid": 5}},
+            {"filled": {"totalSz": "0.1", "avgPx": "50000", "oid": 6}},
+            {"error": "Order must have minimum value of $10."},
+        ])),
+    ));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport);
+
+    let outcomes = exchange.bulk_order_outcomes(orders.clone()).await.unwrap();
+    assert_eq!(outcomes.len(), orders.len());
+    assert!(matches!(outcomes[0], OrderOutcome::Resting { oid: 5, .. }));
+    assert!(matches!(outcomes[1], OrderOutcome::Filled { oid: 6, .. }));
+    assert_eq!(outcomes[2].reject_reason(), Some(RejectReason::MinNotional));
+    assert_eq!(outcomes.rejected().map(|(i, _)| i).collect::<Vec<_>>(), [2]);
+
+    // A single order gets its own outcome; a status count mismatch is an error
+    let outcome = exchange.place_order_outcome(&orders[0]).await;
+    assert!(matches!(outcome, Err(HyperliquidError::InvalidResponse(_))));
+    let short = exchange.bulk_order_outcomes(orders[..2].to_vec()).await;
+    assert!(matches!(short, Err(HyperliquidError::InvalidResponse(_))));
+}
+
+#[tokio::test]
+async fn test_place_order_outcome() {
+    let transport = Arc::new(MockTransport::new().with_exchange_response(
+        "order",
+        response(json!([{"error": "Post only order would have immediately matched."}])),
+    ));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport);
+
+    let outcome = exchange
+        .place_order_outcome(&OrderRequest::limit(0, true, "50000", "0.1", "Alo"))
+        .await
+        .unwrap();
+    assert!(outcome.is_rejected());
+}
+
+#[test]
+fn test_reject_reason_classification() {
+    let cases = [
+        ("Insufficient margin to place order. asset=0", RejectReason::InsufficientMargin),
+        ("Insufficient spot balance asset=10107", RejectReason::InsufficientMargin),
+        (
+            "Post only order would have immediately matched, bbo was 1.0@2.0. asset=1",
+            RejectReason::PostOnlyWouldCross,
+        ),
+        (
+            "Reduce only order would increase position. asset=0",
+            RejectReason::ReduceOnlyWouldIncrease,
+        ),
+        (
+            "Order could not immediately match against any resting orders. asset=0",
+            RejectReason::IocCouldNotMatch,
+        ),
+        ("Price must be divisible by tick size. asset=0", RejectReason::InvalidPrice),
+        ("Order has invalid price.", RejectReason::InvalidPrice),
+        ("Order must have minimum value of $10. asset=0", RejectReason::MinNotional),
+        ("Too many open orders. asset=0", RejectReason::TooManyOrders),
+        (
+            "Too many cumulative requests sent (10001) compared to cumulative volume traded",
+            RejectReason::RateLimited,
+        ),
+        ("Invalid nonce: duplicate nonce 1700000000000", RejectReason::InvalidNonce),
+        ("Unknown asset 9999", RejectReason::UnknownAsset),
+        ("Vault not registered: 0x1234", RejectReason::VaultNotFound),
+        ("User or API Wallet 0xabc does not exist.", RejectReason::UnknownAccount),
+        (
+            "Too many scheduled cancel triggers today",
+            RejectReason::ScheduleCancelLimit,
+        ),
+        ("Something new", RejectReason::Other),
+    ];
+    for (message, expected) in cases {
+        assert_eq!(RejectReason::from_message(message), expected, "{}", message);
+    }
+
+    assert!(RejectReason::RateLimited.is_retryable());
+    assert!(RejectReason::InvalidNonce.is_retryable());
+    assert!(RejectReason::IocCouldNotMatch.is_retryable());
+    assert!(!RejectReason::InsufficientMargin.is_retryable());
+    assert!(!RejectReason::MinNotional.is_retryable());
+    assert!(!HyperliquidError::rejected("Order has invalid price.").is_retryable());
+}
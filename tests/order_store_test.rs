@@ -0,0 +1,226 @@
+//! Tests for persistent order tracking
+//!
+//! Tests cover:
+//! - Restoring tracked orders, oids and fills from a JSON Lines store
+//! - Dropping a record cut short by a crash, compacting and clearing the file
+//! - Writing in the background without the exchange response, and flushing
+//! - Reconciling in-flight orders with openOrders, historicalOrders and orderStatus
+//! - The SQLite store (with the `sqlite` feature)
+
+mod common;
+
+use std::{path::PathBuf, sync::Arc};
+
+use alloy::signers::local::PrivateKeySigner;
+use common::{cloid_hex, fill};
+use hyperliquid_sdk_rs::{
+    providers::{
+        JsonlOrderStore, MockTransport, OrderStatus, OrderStore, OrderTracker,
+        RawExchangeProvider,
+    },
+    types::requests::OrderRequest,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+fn store_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "hyperliquid-orders-{}-{}.jsonl",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn order(sz: &str) -> OrderRequest {
+    OrderRequest::limit(0, true, "50000", sz, "Gtc")
+}
+
+fn basic_order(cloid: Uuid, oid: u64, sz: &str) -> serde_json::Value {
+    json!({
+        "coin": "BTC", "side": "B", "limitPx": "50000", "sz": sz, "oid": oid,
+        "timestamp": 1, "triggerCondition": "N/A", "isTrigger": false,
+        "triggerPx": "0", "isPositionTpsl": false, "reduceOnly": false,
+        "orderType": "Limit", "origSz": "1", "tif": "Gtc", "cloid": cloid_hex(cloid),
+    })
+}
+
+#[test]
+fn test_jsonl_store_restores_orders() {
+    let path = store_path("restore");
+    let cloid = Uuid::new_v4();
+    {
+        let store = Arc::new(JsonlOrderStore::open(&path).unwrap());
+        let tracker = OrderTracker::with_store(store).unwrap();
+        tracker.track_order(cloid, order("1"), 1);
+        tracker.track_order(Uuid::new_v4(), order("2"), 2);
+        tracker.apply_message(&fill(Some(cloid), 77, 1, "50000", "0.4"));
+    }
+
+    let store = Arc::new(JsonlOrderStore::open(&path).unwrap());
+    let tracker = OrderTracker::with_store(store).unwrap();
+    assert_eq!(tracker.len(), 2);
+    let restored = tracker.get_order_by_oid(77).unwrap();
+    assert_eq!(restored.cloid, cloid);
+    assert_eq!(restored.status, OrderStatus::PartiallyFilled);
+    assert_eq!(restored.filled_sz, 0.4);
+    assert_eq!(restored.fills.len(), 1);
+
+    // Replayed fills are still recognized after the restart
+    tracker.apply_message(&fill(Some(cloid), 77, 1, "50000", "0.4"));
+    assert_eq!(tracker.get_order(&cloid).unwrap().filled_sz, 0.4);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_store_skips_responses() {
+    let path = store_path("responses");
+    let store = Arc::new(JsonlOrderStore::open(&path).unwrap());
+    let tracker = OrderTracker::with_store(store.clone()).unwrap();
+    let cloid = Uuid::new_v4();
+    tracker.track_order(cloid, order("1"), 1);
+    tracker.record_response(
+        &cloid,
+        serde_json::from_value(json!({"status": "ok", "response": {
+            "type": "order", "data": {"statuses": [{"resting": {"oid": 9}}]},
+        }}))
+        .unwrap(),
+    );
+    assert!(tracker.get_order(&cloid).unwrap().response.is_some());
+
+    // Writes happen off the tracker's lock; flush waits for them
+    tracker.flush();
+    let saved = store.load().unwrap();
+    assert_eq!(saved[0].status, OrderStatus::Resting);
+    assert_eq!(saved[0].oid, Some(9));
+    assert!(saved[0].response.is_none());
+    assert!(!std::fs::read_to_string(&path).unwrap().contains("statuses"));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_jsonl_store_recovers_and_compacts() {
+    let path = store_path("compact");
+    let cloid = Uuid::new_v4();
+    {
+        let store = Arc::new(JsonlOrderStore::open(&path).unwrap());
+        let tracker = OrderTracker::with_store(store).unwrap();
+        tracker.track_order(cloid, order("1"), 1);
+        tracker.update_order_status(&cloid, OrderStatus::Submitted, None);
+    }
+    // A write cut short by a crash
+    let mut contents = std::fs::read(&path).unwrap();
+    contents.extend_from_slice(br#"{"cloid":"#);
+    std::fs::write(&path, contents).unwrap();
+
+    let store = JsonlOrderStore::open(&path).unwrap();
+    let orders = store.load().unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].status, OrderStatus::Submitted);
+    let lines = |path: &PathBuf| std::fs::read_to_string(path).unwrap().lines().count();
+    assert_eq!(lines(&path), 2);
+
+    store.compact().unwrap();
+    assert_eq!(lines(&path), 1);
+    store.save(&orders[0]).unwrap();
+    assert_eq!(lines(&path), 2);
+
+    store.clear().unwrap();
+    assert!(store.load().unwrap().is_empty());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn test_reconcile_settles_in_flight_orders() {
+    let resting = Uuid::new_v4();
+    let filled = Uuid::new_v4();
+    let lost = Uuid::new_v4();
+    let done = Uuid::new_v4();
+
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_info_response(
+                "openOrders",
+                json!([{
+                    "coin": "BTC", "limitPx": "50000", "oid": 10, "side": "B",
+                    "sz": "0.75", "timestamp": 1, "origSz": "1",
+                    "cloid": cloid_hex(resting),
+                }]),
+            )
+            .with_info_response(
+                "historicalOrders",
+                json!([
+                    {"order": basic_order(filled, 11, "1"), "status": "open",
+                     "statusTimestamp": 1},
+                    {"order": basic_order(filled, 11, "0"), "status": "filled",
+                     "statusTimestamp": 2},
+                ]),
+            )
+            .with_info_response("orderStatus", json!({"status": "unknownOid"})),
+    );
+
+    let tracker = OrderTracker::new();
+    for cloid in [resting, filled, lost, done] {
+        tracker.track_order(cloid, order("1"), 1);
+    }
+    tracker.update_order_status(&done, OrderStatus::Filled, None);
+
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone())
+        .with_order_tracker(tracker.clone());
+    assert_eq!(exchange.reconcile_orders().await.unwrap(), 3);
+
+    let resting = tracker.get_order(&resting).unwrap();
+    assert_eq!(resting.status, OrderStatus::PartiallyFilled);
+    assert_eq!(resting.oid, Some(10));
+    assert_eq!(resting.filled_sz, 0.25);
+
+    let filled = tracker.get_order(&filled).unwrap();
+    assert_eq!(filled.status, OrderStatus::Filled);
+    assert_eq!(filled.filled_sz, 1.0);
+
+    assert!(matches!(
+        tracker.get_order(&lost).unwrap().status,
+        OrderStatus::Failed(_)
+    ));
+
+    // Only the order found nowhere else is looked up on its own, by cloid
+    let lookups: Vec<_> = transport
+        .requests()
+        .into_iter()
+        .filter(|request| request.body["type"] == "orderStatus")
+        .collect();
+    assert_eq!(lookups.len(), 1);
+    assert_eq!(lookups[0].body["oid"], json!(cloid_hex(lost)));
+
+    // Reconciling again changes nothing
+    assert_eq!(exchange.reconcile_orders().await.unwrap(), 0);
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn test_sqlite_store_keeps_latest_state() {
+    use hyperliquid_sdk_rs::providers::SqliteOrderStore;
+
+    let store = Arc::new(SqliteOrderStore::open_in_memory().unwrap());
+    let tracker = OrderTracker::with_store(store.clone()).unwrap();
+    let cloid = Uuid::new_v4();
+    tracker.track_order(cloid, order("1"), 1);
+    tracker.apply_message(&fill(Some(cloid), 5, 1, "50000", "1"));
+    tracker.flush();
+
+    let orders = store.load().unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].status, OrderStatus::Filled);
+    assert_eq!(orders[0].oid, Some(5));
+
+    let restored = OrderTracker::with_store(store.clone()).unwrap();
+    assert_eq!(
+        restored.get_order(&cloid).unwrap().status,
+        OrderStatus::Filled
+    );
+    restored.clear();
+    restored.flush();
+    assert!(store.load().unwrap().is_empty());
+}
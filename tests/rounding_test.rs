@@ -0,0 +1,270 @@
+//! Tests for price and size rounding
+//!
+//! Tests cover:
+//! - Significant-figure and decimal limits for perp and spot prices
+//! - Nearest, passive and strict modes
+//! - Size lot rules
+//! - Rounding in OrderBuilder, bulk_orders and place_order
+//! - Trigger orders without a limit price
+
+mod common;
+
+use std::sync::Arc;
+
+use alloy::signers::local::PrivateKeySigner;
+use common::{registry, PERPS};
+use hyperliquid_sdk_rs::{
+    providers::{
+        exchange::{round_price, round_size},
+        AssetInfo, AssetKind, MockTransport, RawExchangeProvider, RoundingMode,
+    },
+    types::requests::{OrderRequest, OrderType, Trigger},
+    HyperliquidError,
+};
+use serde_json::json;
+
+fn asset(kind: AssetKind, sz_decimals: u32) -> AssetInfo {
+    AssetInfo {
+        asset: 0,
+        coin: "TEST".to_string(),
+        kind,
+        sz_decimals,
+        max_leverage: None,
+        only_isolated: false,
+        dex: None,
+    }
+}
+
+fn invalid_request(result: Result<String, HyperliquidError>) -> String {
+    match result {
+        Err(HyperliquidError::InvalidRequest(msg)) => msg,
+        other => panic!("expected InvalidRequest, got {:?}", other),
+    }
+}
+
+fn order_response() -> serde_json::Value {
+    json!({
+        "status": "ok",
+        "response": {"type": "order", "data": {"statuses": [{"resting": {"oid": 1}}]}}
+    })
+}
+
+#[test]
+fn test_valid_prices_pass_unchanged() {
+    let btc = asset(AssetKind::Perp, 5);
+    let strict = RoundingMode::Strict;
+
+    assert_eq!(round_price("50000", true, &btc, strict).unwrap(), "50000");
+    // Integer prices are allowed regardless of significant figures
+    assert_eq!(round_price("123456", true, &btc, strict).unwrap(), "123456");
+    assert_eq!(round_price("50000.0", true, &btc, strict).unwrap(), "50000");
+    assert_eq!(round_price("1234.5", true, &btc, strict).unwrap(), "1234.5");
+
+    let spot = asset(AssetKind::Spot, 0);
+    assert_eq!(
+        round_price("0.00012345", true, &spot, strict).unwrap(),
+        "0.00012345"
+    );
+}
+
+#[test]
+fn test_strict_names_the_violated_rule() {
+    let btc = asset(AssetKind::Perp, 5);
+    let sol = asset(AssetKind::Perp, 2);
+
+    let sig_figs =
+        invalid_request(round_price("123.456", true, &sol, RoundingMode::Strict));
+    assert!(sig_figs.contains("5 significant figures"), "{}", sig_figs);
+
+    let eth = asset(AssetKind::Perp, 4);
+    let decimals =
+        invalid_request(round_price("1.2345", true, &eth, RoundingMode::Strict));
+    assert!(
+        decimals.contains("more than 2 decimals (MAX_DECIMALS 6 - szDecimals 4)"),
+        "{}",
+        decimals
+    );
+
+    let size = invalid_request(round_size("0.123456", &btc, RoundingMode::Strict));
+    assert!(
+        size.contains("more than 5 decimals (szDecimals)"),
+        "{}",
+        size
+    );
+
+    let garbage = invalid_request(round_price("abc", true, &btc, RoundingMode::Strict));
+    assert!(
+        garbage.contains("not a non-negative decimal"),
+        "{}",
+        garbage
+    );
+}
+
+#[test]
+fn test_nearest_rounding() {
+    let btc = asset(AssetKind::Perp, 5);
+    let nearest = RoundingMode::Nearest;
+
+    assert_eq!(
+        round_price("1234.56", true, &btc, nearest).unwrap(),
+        "1234.6"
+    );
+    assert_eq!(
+        round_price("1234.54", false, &btc, nearest).unwrap(),
+        "1234.5"
+    );
+    assert_eq!(
+        round_price("123456.7", true, &btc, nearest).unwrap(),
+        "123457"
+    );
+    // Carrying into a new integer digit drops the remaining decimal
+    assert_eq!(
+        round_price("9999.96", true, &btc, nearest).unwrap(),
+        "10000"
+    );
+
+    let spot = asset(AssetKind::Spot, 2);
+    assert_eq!(
+        round_price("0.001234567", true, &spot, nearest).unwrap(),
+        "0.001235"
+    );
+    assert_eq!(round_size("0.125", &spot, nearest).unwrap(), "0.13");
+}
+
+#[test]
+fn test_passive_rounding() {
+    let btc = asset(AssetKind::Perp, 5);
+    let passive = RoundingMode::Passive;
+
+    assert_eq!(
+        round_price("1234.56", true, &btc, passive).unwrap(),
+        "1234.5"
+    );
+    assert_eq!(
+        round_price("1234.51", false, &btc, passive).unwrap(),
+        "1234.6"
+    );
+    assert_eq!(round_size("0.1234569", &btc, passive).unwrap(), "0.12345");
+
+    let zero = invalid_request(round_size("0.000001", &btc, passive));
+    assert!(zero.contains("rounds to zero"), "{}", zero);
+}
+
+#[test]
+fn test_builder_applies_provider_and_override_modes() {
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_asset_registry(registry(PERPS))
+        .with_rounding(RoundingMode::Passive);
+
+    let order = exchange
+        .order(0)
+        .limit_buy("50000.55", "0.1234567")
+        .build()
+        .unwrap();
+    assert_eq!(order.limit_px, "50000");
+    assert_eq!(order.sz, "0.12345");
+
+    // Caller strings are rounded as given; through f64 this buy would become 5000
+    let exact = exchange
+        .order(0)
+        .limit_buy("4999.999999996", "0.1")
+        .build()
+        .unwrap();
+    assert_eq!(exact.limit_px, "4999.9");
+
+    let nearest = exchange
+        .order(0)
+        .limit_buy("50000.55", "0.1")
+        .rounding(RoundingMode::Nearest)
+        .build()
+        .unwrap();
+    assert_eq!(nearest.limit_px, "50001");
+
+    let strict = exchange
+        .order(0)
+        .limit_buy("50000.55", "0.1")
+        .rounding(RoundingMode::Strict)
+        .build();
+    assert!(matches!(strict, Err(HyperliquidError::InvalidRequest(_))));
+
+    let trigger = exchange
+        .order(0)
+        .trigger_sell("43210.98", "0.5", "sl")
+        .build()
+        .unwrap();
+    match trigger.order_type {
+        OrderType::Trigger(Trigger { trigger_px, .. }) => assert_eq!(trigger_px, "43211"),
+        _ => panic!("expected trigger order"),
+    }
+}
+
+#[test]
+fn test_builder_without_registry_is_unchecked() {
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random());
+    let order = exchange
+        .order(0)
+        .limit_buy("50000.55", "0.1234567")
+        .build()
+        .unwrap();
+    assert_eq!(order.limit_px, "50000.55");
+}
+
+#[tokio::test]
+async fn test_bulk_orders_are_rounded_before_signing() {
+    let transport =
+        Arc::new(MockTransport::new().with_exchange_response("order", order_response()));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone())
+        .with_asset_registry(registry(PERPS))
+        .with_rounding(RoundingMode::Nearest);
+
+    exchange
+        .bulk_orders(vec![
+            OrderRequest::limit(0, true, "4999.94", "0.123456", "Gtc"),
+            OrderRequest::limit(0, false, "51000.0", "1", "Gtc"),
+        ])
+        .await
+        .unwrap();
+
+    let orders = &transport.requests()[0].body["action"]["orders"];
+    assert_eq!(orders[0]["p"], "4999.9");
+    assert_eq!(orders[0]["s"], "0.12346");
+    assert_eq!(orders[1]["p"], "51000");
+}
+
+#[tokio::test]
+async fn test_place_order_rejects_in_strict_mode() {
+    let transport =
+        Arc::new(MockTransport::new().with_exchange_response("order", order_response()));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone())
+        .with_asset_registry(registry(PERPS));
+
+    let order = OrderRequest::limit(0, true, "50000", "0.0000001", "Gtc");
+    let err = exchange.place_order(&order).await.unwrap_err();
+    assert!(
+        matches!(err, HyperliquidError::InvalidRequest(msg) if msg.contains("szDecimals"))
+    );
+    assert!(transport.requests().is_empty());
+
+    let unknown = OrderRequest::limit(7, true, "1", "1", "Gtc");
+    let err = exchange.place_order(&unknown).await.unwrap_err();
+    assert!(matches!(err, HyperliquidError::AssetNotFound(_)));
+}
+
+#[tokio::test]
+async fn test_place_order_accepts_trigger_without_limit_price() {
+    let transport =
+        Arc::new(MockTransport::new().with_exchange_response("order", order_response()));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone())
+        .with_asset_registry(registry(PERPS))
+        .with_rounding(RoundingMode::Nearest);
+
+    let order = OrderRequest::trigger(0, false, "43210.98", "0.5", "sl", true);
+    exchange.place_order(&order).await.unwrap();
+
+    let placed = &transport.requests()[0].body["action"]["orders"][0];
+    assert_eq!(placed["p"], "0");
+    assert_eq!(placed["t"]["trigger"]["triggerPx"], "43211");
+}
@@ -0,0 +1,149 @@
+//! Tests for offline signing
+//!
+//! Tests cover:
+//! - Signing orders, cancels and user actions without sending them
+//! - Payloads matching what the sending methods post
+//! - JSON round trips and submitting pre-signed payloads
+//! - Vault and agent payloads
+
+use std::sync::Arc;
+
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
+use hyperliquid_sdk_rs::{
+    providers::{MockTransport, RawExchangeProvider, SignedAction},
+    types::{
+        actions::UpdateLeverage,
+        requests::{CancelRequest, OrderRequest},
+    },
+};
+use serde_json::{json, Value};
+
+fn order() -> OrderRequest {
+    OrderRequest::limit(0, true, "50000", "0.01", "Gtc")
+}
+
+fn transport() -> Arc<MockTransport> {
+    Arc::new(MockTransport::new().with_exchange_response(
+        "order",
+        json!({"status": "ok", "response": {"type": "order", "data": {"statuses": [
+            {"resting": {"oid": 1}}
+        ]}}}),
+    ))
+}
+
+fn assert_signature_format(signed: &SignedAction) {
+    for part in [&signed.signature.r, &signed.signature.s] {
+        assert!(part.starts_with("0x"));
+        assert_eq!(part.len(), 66);
+    }
+    assert!(signed.signature.v == 27 || signed.signature.v == 28);
+}
+
+#[tokio::test]
+async fn test_sign_orders_matches_sent_payload() {
+    let transport = transport();
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone());
+
+    let signed = exchange.sign_orders(vec![order()]).await.unwrap();
+    assert!(transport.requests().is_empty());
+    assert_eq!(signed.action_type(), Some("order"));
+    assert_signature_format(&signed);
+
+    exchange.bulk_orders(vec![order()]).await.unwrap();
+    let sent = &transport.requests()[0].body;
+    let signed_value = serde_json::to_value(&signed).unwrap();
+
+    // Same shape and action; only the nonce and signature differ
+    let keys = |value: &Value| {
+        value
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(keys(&signed_value), keys(sent));
+    assert_eq!(signed_value["action"], sent["action"]);
+    assert!(sent["nonce"].as_u64().unwrap() > signed.nonce);
+}
+
+#[tokio::test]
+async fn test_round_trip_and_submit_signed() {
+    let signer_host = RawExchangeProvider::testnet(PrivateKeySigner::random());
+    let signed = signer_host
+        .sign_cancels(vec![CancelRequest { asset: 0, oid: 42 }])
+        .await
+        .unwrap();
+    assert_eq!(
+        signed.action,
+        json!({"type": "cancel", "cancels": [{"a": 0, "o": 42}]})
+    );
+
+    let wire = serde_json::to_string(&signed).unwrap();
+    let parsed: SignedAction = serde_json::from_str(&wire).unwrap();
+    assert_eq!(parsed, signed);
+
+    // The broadcaster holds a different key; the payload is posted unchanged
+    let transport = Arc::new(MockTransport::new().with_exchange_response(
+        "cancel",
+        json!({"status": "ok", "response": {"type": "cancel", "data": {"statuses": ["success"]}}}),
+    ));
+    let broadcaster = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone());
+    let response = broadcaster.submit_signed(&parsed).await.unwrap();
+    assert!(response.is_ok());
+    assert_eq!(
+        transport.requests()[0].body,
+        serde_json::from_str::<Value>(&wire).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_sign_user_actions() {
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random());
+    let destination = Address::new([7u8; 20]);
+
+    let transfer = exchange.sign_usd_transfer(destination, "5").await.unwrap();
+    assert_eq!(transfer.action_type(), Some("usdSend"));
+    assert_eq!(transfer.action["time"], json!(transfer.nonce));
+    assert_eq!(transfer.action["amount"], "5");
+    assert_eq!(transfer.action["hyperliquidChain"], "Testnet");
+    assert_signature_format(&transfer);
+
+    let withdrawal = exchange.sign_withdraw(destination, "10").await.unwrap();
+    assert_eq!(withdrawal.action_type(), Some("withdraw3"));
+    assert!(withdrawal.nonce > transfer.nonce);
+}
+
+#[tokio::test]
+async fn test_sign_any_l1_action_for_vault_and_agent() {
+    let vault = Address::new([1u8; 20]);
+    let exchange = RawExchangeProvider::testnet_vault(PrivateKeySigner::random(), vault);
+    let leverage = UpdateLeverage {
+        asset: 0,
+        is_cross: true,
+        leverage: 5,
+    };
+
+    let signed = exchange
+        .sign_l1_action("updateLeverage", &leverage)
+        .await
+        .unwrap();
+    assert_eq!(signed.action_type(), Some("updateLeverage"));
+    assert_eq!(signed.vault_address, Some(vault));
+    assert_eq!(
+        serde_json::to_value(&signed).unwrap()["vaultAddress"],
+        json!(format!("{:#x}", vault))
+    );
+
+    let agent = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_account_address(Address::new([2u8; 20]));
+    let signed = agent.sign_orders(vec![order()]).await.unwrap();
+    assert_eq!(signed.action_type(), Some("order"));
+    assert!(signed.action.get("agentAction").is_none());
+    assert_eq!(
+        serde_json::to_value(&signed).unwrap()["vaultAddress"],
+        Value::Null
+    );
+}
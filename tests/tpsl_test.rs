@@ -0,0 +1,184 @@
+//! Tests for take-profit / stop-loss grouping
+//!
+//! Tests cover:
+//! - Grouping serialization (JSON and MessagePack)
+//! - Entry orders with attached take-profit and stop-loss (normalTpsl)
+//! - Take-profit and stop-loss on an existing position (positionTpsl)
+//! - Sending grouped orders
+
+mod common;
+
+use std::sync::Arc;
+
+use alloy::signers::local::PrivateKeySigner;
+use common::registry;
+use hyperliquid_sdk_rs::{
+    providers::{MockTransport, RawExchangeProvider, RoundingMode},
+    types::requests::{Grouping, OrderType},
+    HyperliquidError,
+};
+use serde_json::json;
+
+fn tpsl(order_type: &OrderType) -> (&str, &str) {
+    match order_type {
+        OrderType::Trigger(trigger) => {
+            assert!(trigger.is_market);
+            (trigger.tpsl.as_str(), trigger.trigger_px.as_str())
+        }
+        OrderType::Limit(_) => panic!("expected trigger order"),
+    }
+}
+
+#[test]
+fn test_grouping_wire_format() {
+    for (grouping, wire) in [
+        (Grouping::Na, "na"),
+        (Grouping::NormalTpsl, "normalTpsl"),
+        (Grouping::PositionTpsl, "positionTpsl"),
+    ] {
+        assert_eq!(serde_json::to_value(grouping).unwrap(), json!(wire));
+        // The action hash is computed over MessagePack
+        assert_eq!(
+            rmp_serde::to_vec_named(&grouping).unwrap(),
+            rmp_serde::to_vec_named(wire).unwrap()
+        );
+    }
+    assert_eq!(Grouping::default(), Grouping::Na);
+}
+
+#[test]
+fn test_entry_with_take_profit_and_stop_loss() {
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random());
+    let (orders, grouping) = exchange
+        .order(1)
+        .limit_buy("3000", "0.5")
+        .with_take_profit("3300")
+        .with_stop_loss("2850")
+        .build_grouped()
+        .unwrap();
+
+    assert_eq!(grouping, Grouping::NormalTpsl);
+    assert_eq!(orders.len(), 3);
+    assert!(orders[0].is_buy);
+    assert!(!orders[0].reduce_only);
+
+    for (order, expected) in orders[1..].iter().zip([("tp", "3300"), ("sl", "2850")]) {
+        assert_eq!(order.asset, 1);
+        assert!(!order.is_buy);
+        assert!(order.reduce_only);
+        assert_eq!(order.sz, "0.5");
+        assert_eq!(order.limit_px, expected.1);
+        assert_eq!(tpsl(&order.order_type), expected);
+    }
+}
+
+#[test]
+fn test_position_tpsl_has_no_entry() {
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random());
+    let (orders, grouping) = exchange
+        .order(1)
+        .sell()
+        .size("2")
+        .position_tpsl()
+        .with_stop_loss("3100")
+        .build_grouped()
+        .unwrap();
+
+    assert_eq!(grouping, Grouping::PositionTpsl);
+    assert_eq!(orders.len(), 1);
+    // Stop-loss on a short buys back
+    assert!(orders[0].is_buy);
+    assert_eq!(tpsl(&orders[0].order_type), ("sl", "3100"));
+
+    let empty = exchange
+        .order(1)
+        .sell()
+        .size("2")
+        .position_tpsl()
+        .build_grouped();
+    assert!(matches!(empty, Err(HyperliquidError::InvalidRequest(_))));
+}
+
+#[test]
+fn test_build_requires_build_grouped_for_children() {
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random());
+    let result = exchange
+        .order(1)
+        .limit_buy("3000", "0.5")
+        .with_take_profit("3300")
+        .build();
+    assert!(
+        matches!(result, Err(HyperliquidError::InvalidRequest(msg)) if msg.contains("build_grouped"))
+    );
+
+    let (orders, grouping) = exchange
+        .order(1)
+        .limit_buy("3000", "0.5")
+        .build_grouped()
+        .unwrap();
+    assert_eq!(grouping, Grouping::Na);
+    assert_eq!(orders.len(), 1);
+}
+
+#[tokio::test]
+async fn test_send_bracket_order() {
+    let transport = Arc::new(MockTransport::new().with_exchange_response(
+        "order",
+        json!({"status": "ok", "response": {"type": "order", "data": {"statuses": [
+            {"resting": {"oid": 1}}, "waitingForTrigger", "waitingForTrigger"
+        ]}}}),
+    ));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone())
+        .with_asset_registry(registry(&[("ETH", 4)]));
+
+    let response = exchange
+        .order_for("ETH")
+        .limit_buy("3000", "0.5")
+        .with_take_profit("3300.25")
+        .with_stop_loss("2850")
+        .rounding(RoundingMode::Nearest)
+        .send()
+        .await
+        .unwrap();
+    assert!(response.is_ok());
+
+    let action = &transport.requests()[0].body["action"];
+    assert_eq!(action["grouping"], "normalTpsl");
+    let orders = action["orders"].as_array().unwrap();
+    assert_eq!(orders.len(), 3);
+    // Trigger prices follow the tick rules like limit prices
+    assert_eq!(orders[1]["t"]["trigger"]["triggerPx"], "3300.3");
+    assert_eq!(orders[1]["t"]["trigger"]["tpsl"], "tp");
+    assert_eq!(orders[2]["t"]["trigger"]["tpsl"], "sl");
+}
+
+#[tokio::test]
+async fn test_bulk_orders_with_grouping() {
+    let transport = Arc::new(MockTransport::new().with_exchange_response(
+        "order",
+        json!({"status": "ok", "response": {"type": "order", "data": {"statuses": [
+            "waitingForTrigger"
+        ]}}}),
+    ));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone());
+
+    let (orders, grouping) = exchange
+        .order(1)
+        .buy()
+        .size("1")
+        .position_tpsl()
+        .with_take_profit("3500")
+        .build_grouped()
+        .unwrap();
+    exchange
+        .bulk_orders_with_grouping(orders, grouping)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        transport.requests()[0].body["action"]["grouping"],
+        "positionTpsl"
+    );
+}
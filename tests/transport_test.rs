@@ -0,0 +1,112 @@
+//! Tests for the pluggable HTTP transport
+//!
+//! Tests cover:
+//! - InfoProvider against canned `/info` responses
+//! - RawExchangeProvider against canned `/exchange` responses
+//! - Request recording and fallback handlers
+
+use std::sync::Arc;
+
+use alloy::signers::local::PrivateKeySigner;
+use hyperliquid_sdk_rs::{
+    providers::{MockTransport, RawExchangeProvider},
+    types::{requests::OrderRequest, responses::ExchangeResponseStatus},
+    HyperliquidError, InfoProvider, Network,
+};
+use serde_json::json;
+
+fn init_crypto() {
+    rustls::crypto::CryptoProvider::install_default(
+        rustls::crypto::aws_lc_rs::default_provider(),
+    )
+    .ok();
+}
+
+#[tokio::test]
+async fn test_info_provider_uses_mock_transport() {
+    init_crypto();
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_info_response("allMids", json!({"BTC": "50000", "ETH": "3000.5"})),
+    );
+    let info = InfoProvider::new(Network::Testnet).with_transport(transport.clone());
+
+    let mids = info.all_mids().await.unwrap();
+    assert!(mids.get("BTC").is_some_and(|px| px == "50000"));
+    assert!(mids.get("ETH").is_some_and(|px| px == "3000.5"));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url, "https://api.hyperliquid-testnet.xyz/info");
+    assert_eq!(requests[0].body["type"], "allMids");
+}
+
+#[tokio::test]
+async fn test_info_provider_unmatched_request_is_http_error() {
+    init_crypto();
+    let info = InfoProvider::new(Network::Testnet)
+        .with_transport(Arc::new(MockTransport::new()));
+
+    let err = info.meta().await.unwrap_err();
+    assert!(matches!(err, HyperliquidError::Http { status: 404, .. }));
+}
+
+#[tokio::test]
+async fn test_exchange_provider_uses_mock_transport() {
+    init_crypto();
+    let transport = Arc::new(MockTransport::new().with_exchange_response(
+        "order",
+        json!({
+            "status": "ok",
+            "response": {
+                "type": "order",
+                "data": {"statuses": [{"resting": {"oid": 77}}]}
+            }
+        }),
+    ));
+    let exchange = RawExchangeProvider::testnet(PrivateKeySigner::random())
+        .with_transport(transport.clone());
+
+    let order = OrderRequest::limit(0, true, "50000", "0.01", "Gtc");
+    let response = exchange.place_order(&order).await.unwrap();
+
+    match response {
+        ExchangeResponseStatus::Ok(resp) => {
+            let statuses = resp.data.unwrap().statuses;
+            assert_eq!(statuses[0].order_id(), Some(77));
+        }
+        ExchangeResponseStatus::Err(e) => panic!("unexpected error: {}", e),
+    }
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    let body = &requests[0].body;
+    assert_eq!(body["action"]["type"], "order");
+    assert_eq!(body["action"]["grouping"], "na");
+    assert!(body["nonce"].is_u64());
+    assert!(body["signature"]["r"].as_str().unwrap().starts_with("0x"));
+}
+
+#[tokio::test]
+async fn test_mock_transport_handler_fallback() {
+    init_crypto();
+    let transport = MockTransport::new().with_handler(|url, body| {
+        if url.ends_with("/info") && body["type"] == "l2Book" {
+            Some((
+                200,
+                json!({
+                    "coin": body["coin"],
+                    "time": 1,
+                    "levels": [[{"px": "100", "sz": "1", "n": 1}], []]
+                }),
+            ))
+        } else {
+            None
+        }
+    });
+    let info = InfoProvider::new(Network::Mainnet).with_transport(Arc::new(transport));
+
+    let book = info.l2_book("ETH").await.unwrap();
+    assert_eq!(book.coin, "ETH");
+    assert_eq!(book.levels[0][0].px, "100");
+}
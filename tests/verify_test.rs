@@ -0,0 +1,144 @@
+//! Tests for signature verification
+//!
+//! Tests cover:
+//! - Recovering the signer of L1 actions, vault actions and user actions
+//! - Agent-signed actions recovering the agent key
+//! - Payloads surviving a JSON round trip in any field order
+//! - Tampered payloads, unknown action types and mismatched networks failing
+//!   verification
+
+use alloy::{primitives::Address, signers::local::PrivateKeySigner};
+use hyperliquid_sdk_rs::{
+    providers::{RawExchangeProvider, SignedAction},
+    signers::verify,
+    types::{
+        actions::UpdateLeverage,
+        requests::{CancelRequest, OrderRequest},
+    },
+    Network,
+};
+use serde_json::json;
+
+fn order() -> OrderRequest {
+    OrderRequest::limit(0, true, "50000", "0.01", "Gtc")
+}
+
+#[tokio::test]
+async fn test_recovers_signer_of_l1_and_user_actions() {
+    let signer = PrivateKeySigner::random();
+    let exchange = RawExchangeProvider::testnet(signer.clone());
+    let destination = Address::new([7u8; 20]);
+
+    let payloads = vec![
+        exchange.sign_orders(vec![order()]).await.unwrap(),
+        exchange
+            .sign_cancels(vec![CancelRequest { asset: 0, oid: 42 }])
+            .await
+            .unwrap(),
+        exchange.sign_usd_transfer(destination, "5").await.unwrap(),
+        exchange.sign_withdraw(destination, "10").await.unwrap(),
+    ];
+    for payload in &payloads {
+        let recovered = verify::recover_signer(payload, &Network::Testnet).unwrap();
+        assert_eq!(recovered, signer.address(), "{:?}", payload.action_type());
+        assert!(
+            verify::verify_signer(payload, &Network::Testnet, signer.address()).unwrap()
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_recovers_vault_and_agent_signers() {
+    let signer = PrivateKeySigner::random();
+    let vault = Address::new([1u8; 20]);
+    let exchange = RawExchangeProvider::mainnet_vault(signer.clone(), vault);
+    let leverage = UpdateLeverage {
+        asset: 0,
+        is_cross: true,
+        leverage: 5,
+    };
+    let signed = exchange
+        .sign_l1_action("updateLeverage", &leverage)
+        .await
+        .unwrap();
+    assert_eq!(
+        verify::recover_signer(&signed, &Network::Mainnet).unwrap(),
+        signer.address()
+    );
+
+    // An agent key signs the plain action itself
+    let agent_key = PrivateKeySigner::random();
+    let agent = RawExchangeProvider::testnet(agent_key.clone())
+        .with_account_address(Address::new([2u8; 20]));
+    let signed = agent.sign_orders(vec![order()]).await.unwrap();
+    assert_eq!(signed.action_type(), Some("order"));
+    assert_eq!(
+        verify::recover_signer(&signed, &Network::Testnet).unwrap(),
+        agent_key.address()
+    );
+}
+
+#[tokio::test]
+async fn test_round_tripped_payload_verifies() {
+    let signer = PrivateKeySigner::random();
+    let exchange = RawExchangeProvider::testnet(signer.clone());
+
+    for signed in [
+        exchange.sign_orders(vec![order()]).await.unwrap(),
+        exchange
+            .sign_usd_transfer(Address::new([7u8; 20]), "5")
+            .await
+            .unwrap(),
+    ] {
+        let wire = serde_json::to_string(&signed).unwrap();
+        let parsed: SignedAction = serde_json::from_str(&wire).unwrap();
+        assert!(
+            verify::verify_signer(&parsed, &Network::Testnet, signer.address()).unwrap()
+        );
+    }
+
+    // Field order in the JSON text does not matter
+    let signed = exchange.sign_orders(vec![order()]).await.unwrap();
+    let wire = format!(
+        r#"{{"vaultAddress":null,"signature":{},"nonce":{},"action":{{"grouping":"na","type":"order","orders":{}}}}}"#,
+        serde_json::to_string(&signed.signature).unwrap(),
+        signed.nonce,
+        signed.action["orders"],
+    );
+    let parsed: SignedAction = serde_json::from_str(&wire).unwrap();
+    assert!(verify::verify_signer(&parsed, &Network::Testnet, signer.address()).unwrap());
+}
+
+#[tokio::test]
+async fn test_tampered_payload_or_wrong_network_fails() {
+    let signer = PrivateKeySigner::random();
+    let exchange = RawExchangeProvider::testnet(signer.clone());
+
+    let mut order_payload = exchange.sign_orders(vec![order()]).await.unwrap();
+    assert!(
+        !verify::verify_signer(&order_payload, &Network::Mainnet, signer.address())
+            .unwrap()
+    );
+    order_payload.nonce += 1;
+    assert!(
+        !verify::verify_signer(&order_payload, &Network::Testnet, signer.address())
+            .unwrap()
+    );
+
+    let mut transfer = exchange
+        .sign_usd_transfer(Address::new([7u8; 20]), "5")
+        .await
+        .unwrap();
+    transfer.action["amount"] = json!("500");
+    assert!(
+        !verify::verify_signer(&transfer, &Network::Testnet, signer.address()).unwrap()
+    );
+
+    // Unknown action types cannot be re-hashed
+    let mut unknown = order_payload.clone();
+    unknown.action["type"] = json!("agent");
+    assert!(verify::recover_signer(&unknown, &Network::Testnet).is_err());
+
+    transfer.signature.r = "0xnot-hex".to_string();
+    assert!(verify::recover_signer(&transfer, &Network::Testnet).is_err());
+}
@@ -3,14 +3,19 @@
 //! Tests cover:
 //! - Subscription serialization
 //! - Message deserialization
+//! - Numeric fields keeping their wire strings
 //! - Data structure validation
 
 #[cfg(test)]
 mod tests {
     use alloy::primitives::address;
-    use hyperliquid_sdk_rs::types::ws::{
-        AllMids, AllMidsData, BookLevel, Candle, CandleData, L2Book, L2BookData, Message,
-        Subscription, Trade, Trades,
+    use hyperliquid_sdk_rs::types::{
+        info_types::CandleInterval,
+        ws::{
+            AllMids, AllMidsData, BookLevel, Candle, CandleData, L2Book, L2BookData,
+            Message, PostRequest, PostResponsePayload, Subscription, Trade, Trades,
+            WsPostRequest,
+        },
     };
     use std::collections::HashMap;
 
@@ -50,7 +55,7 @@ mod tests {
     fn test_subscription_candle() {
         let sub = Subscription::Candle {
             coin: "BTC".to_string(),
-            interval: "1h".to_string(),
+            interval: CandleInterval::OneHour,
         };
         let json = serde_json::to_string(&sub).unwrap();
 
@@ -362,13 +367,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_numbers_keep_wire_string() {
+        let level: BookLevel =
+            serde_json::from_str(r#"{"px": "50000.10", "sz": "1.50", "n": 2}"#).unwrap();
+        assert_eq!(level.px, "50000.10");
+        assert_eq!(level.px.to_f64(), 50000.1);
+        assert_eq!(String::from(level.sz.clone()), "1.50");
+
+        let out = serde_json::to_value([&level.px, &level.sz]).unwrap();
+        assert_eq!(out, serde_json::json!(["50000.10", "1.50"]));
+
+        // JSON numbers are accepted too
+        let level: BookLevel =
+            serde_json::from_str(r#"{"px": 50000, "sz": 0.5, "n": 1}"#).unwrap();
+        assert_eq!((level.px.as_str(), level.sz.as_str()), ("50000", "0.5"));
+    }
+
     // ==================== Data Structure Tests ====================
 
     #[test]
     fn test_all_mids_data_structure() {
         let mut mids = HashMap::new();
-        mids.insert("BTC".to_string(), "50000".to_string());
-        mids.insert("ETH".to_string(), "3000".to_string());
+        mids.insert("BTC".to_string(), "50000".parse().unwrap());
+        mids.insert("ETH".to_string(), "3000".parse().unwrap());
 
         let data = AllMidsData { mids: mids.clone() };
         let all_mids = AllMids { data };
@@ -382,8 +404,8 @@ mod tests {
         let trade = Trade {
             coin: "BTC".to_string(),
             side: "B".to_string(),
-            px: "50000".to_string(),
-            sz: "0.01".to_string(),
+            px: "50000".parse().unwrap(),
+            sz: "0.01".parse().unwrap(),
             time: 1690393044548,
             hash: "0xabc".to_string(),
             tid: 12345,
@@ -401,8 +423,8 @@ mod tests {
                 Trade {
                     coin: "BTC".to_string(),
                     side: "B".to_string(),
-                    px: "50000".to_string(),
-                    sz: "0.01".to_string(),
+                    px: "50000".parse().unwrap(),
+                    sz: "0.01".parse().unwrap(),
                     time: 1690393044548,
                     hash: "0xabc".to_string(),
                     tid: 12345,
@@ -410,8 +432,8 @@ mod tests {
                 Trade {
                     coin: "BTC".to_string(),
                     side: "A".to_string(),
-                    px: "50010".to_string(),
-                    sz: "0.02".to_string(),
+                    px: "50010".parse().unwrap(),
+                    sz: "0.02".parse().unwrap(),
                     time: 1690393044549,
                     hash: "0xdef".to_string(),
                     tid: 12346,
@@ -427,8 +449,8 @@ mod tests {
     #[test]
     fn test_book_level_structure() {
         let level = BookLevel {
-            px: "50000".to_string(),
-            sz: "1.5".to_string(),
+            px: "50000".parse().unwrap(),
+            sz: "1.5".parse().unwrap(),
             n: 10,
         };
 
@@ -445,13 +467,13 @@ mod tests {
                 time: 1690393044548,
                 levels: vec![
                     vec![BookLevel {
-                        px: "49999".to_string(),
-                        sz: "1.0".to_string(),
+                        px: "49999".parse().unwrap(),
+                        sz: "1.0".parse().unwrap(),
                         n: 5,
                     }],
                     vec![BookLevel {
-                        px: "50001".to_string(),
-                        sz: "0.8".to_string(),
+                        px: "50001".parse().unwrap(),
+                        sz: "0.8".parse().unwrap(),
                         n: 3,
                     }],
                 ],
@@ -467,15 +489,15 @@ mod tests {
         let candle = Candle {
             data: CandleData {
                 time_close: 1690393044548,
-                close: "50100".to_string(),
-                high: "50500".to_string(),
+                close: "50100".parse().unwrap(),
+                high: "50500".parse().unwrap(),
                 interval: "1h".to_string(),
-                low: "49500".to_string(),
+                low: "49500".parse().unwrap(),
                 num_trades: 1000,
-                open: "50000".to_string(),
+                open: "50000".parse().unwrap(),
                 coin: "BTC".to_string(),
                 time_open: 1690389444548,
-                volume: "100.5".to_string(),
+                volume: "100.5".parse().unwrap(),
             },
         };
 
@@ -508,7 +530,7 @@ mod tests {
     fn test_subscription_round_trip_candle() {
         let original = Subscription::Candle {
             coin: "ETH".to_string(),
-            interval: "15m".to_string(),
+            interval: CandleInterval::FifteenMinutes,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -517,7 +539,7 @@ mod tests {
         match restored {
             Subscription::Candle { coin, interval } => {
                 assert_eq!(coin, "ETH");
-                assert_eq!(interval, "15m");
+                assert_eq!(interval, CandleInterval::FifteenMinutes);
             }
             _ => panic!("Round-trip failed"),
         }
@@ -536,4 +558,124 @@ mod tests {
             _ => panic!("Round-trip failed"),
         }
     }
+
+    // ==================== Routing Key Tests ====================
+
+    #[test]
+    fn test_routing_key_matches_by_coin() {
+        let btc_book = Subscription::L2Book {
+            coin: "BTC".to_string(),
+        };
+        let eth_book = Subscription::L2Book {
+            coin: "ETH".to_string(),
+        };
+        let json = r#"{"channel": "l2Book", "data": {"coin": "BTC", "time": 1, "levels": [[], []]}}"#;
+        let msg: Message = serde_json::from_str(json).unwrap();
+
+        let key = msg.routing_key().unwrap();
+        assert_eq!(key, btc_book.routing_key());
+        assert_ne!(key, eth_book.routing_key());
+    }
+
+    #[test]
+    fn test_routing_key_distinguishes_channels() {
+        let trades = Subscription::Trades {
+            coin: "BTC".to_string(),
+        };
+        let bbo = Subscription::Bbo {
+            coin: "BTC".to_string(),
+        };
+        let json = r#"{"channel": "trades", "data": [{"coin": "BTC", "side": "B", "px": "1", "sz": "1", "time": 1, "hash": "0x", "tid": 1}]}"#;
+        let msg: Message = serde_json::from_str(json).unwrap();
+
+        let key = msg.routing_key().unwrap();
+        assert_eq!(key, trades.routing_key());
+        assert_ne!(key, bbo.routing_key());
+    }
+
+    #[test]
+    fn test_routing_key_candle_interval() {
+        let one_min = Subscription::Candle {
+            coin: "ETH".to_string(),
+            interval: CandleInterval::OneMinute,
+        };
+        let five_min = Subscription::Candle {
+            coin: "ETH".to_string(),
+            interval: CandleInterval::FiveMinutes,
+        };
+        let json = r#"{"channel": "candle", "data": {"T": 2, "c": "1", "h": "1", "i": "5m", "l": "1", "n": 1, "o": "1", "s": "ETH", "t": 1, "v": "1"}}"#;
+        let msg: Message = serde_json::from_str(json).unwrap();
+
+        let key = msg.routing_key().unwrap();
+        assert_eq!(key, five_min.routing_key());
+        assert_ne!(key, one_min.routing_key());
+    }
+
+    #[test]
+    fn test_routing_key_matches_by_user() {
+        let alice = address!("1111111111111111111111111111111111111111");
+        let bob = address!("2222222222222222222222222222222222222222");
+        let json = r#"{"channel": "userFills", "data": {"user": "0x1111111111111111111111111111111111111111", "fills": []}}"#;
+        let msg: Message = serde_json::from_str(json).unwrap();
+
+        let key = msg.routing_key().unwrap();
+        assert_eq!(key, Subscription::UserFills { user: alice }.routing_key());
+        assert_ne!(key, Subscription::UserFills { user: bob }.routing_key());
+    }
+
+    #[test]
+    fn test_routing_key_protocol_messages() {
+        let pong: Message = serde_json::from_str(r#"{"channel": "pong"}"#).unwrap();
+        let ack: Message =
+            serde_json::from_str(r#"{"channel": "subscriptionResponse"}"#).unwrap();
+        let empty: Message =
+            serde_json::from_str(r#"{"channel": "trades", "data": []}"#).unwrap();
+
+        assert!(pong.routing_key().is_none());
+        assert!(ack.routing_key().is_none());
+        assert!(empty.routing_key().is_none());
+    }
+
+    // ==================== Post Request Tests ====================
+
+    #[test]
+    fn test_post_request_serialization() {
+        let request = WsPostRequest::new(
+            7,
+            PostRequest::Info(serde_json::json!({"type": "l2Book", "coin": "ETH"})),
+        );
+        let value = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(value["method"], "post");
+        assert_eq!(value["id"], 7);
+        assert_eq!(value["request"]["type"], "info");
+        assert_eq!(value["request"]["payload"]["coin"], "ETH");
+    }
+
+    #[test]
+    fn test_post_response_deserialization() {
+        let json = r#"{"channel": "post", "data": {"id": 7, "response": {"type": "action", "payload": {"status": "ok", "response": {"type": "default"}}}}}"#;
+        let msg: Message = serde_json::from_str(json).unwrap();
+
+        match &msg {
+            Message::Post(post) => {
+                assert_eq!(post.data.id, 7);
+                match &post.data.response {
+                    PostResponsePayload::Action(payload) => {
+                        assert_eq!(payload["status"], "ok")
+                    }
+                    other => panic!("expected action payload, got {:?}", other),
+                }
+            }
+            other => panic!("expected post message, got {:?}", other),
+        }
+        assert!(msg.routing_key().is_none());
+
+        let json = r#"{"channel": "post", "data": {"id": 8, "response": {"type": "error", "payload": "bad request"}}}"#;
+        let msg: Message = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            Message::Post(post) if matches!(&post.data.response, PostResponsePayload::Error(e) if e == "bad request")
+        ));
+    }
 }